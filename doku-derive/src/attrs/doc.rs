@@ -1,6 +1,7 @@
-/// Models the `#[doc]` attribute for doc-comments
+/// Models the `#[doc]` attribute for doc-comments and `#[doc(hidden)]`
 pub struct Doc {
     pub comment: Option<String>,
+    pub hidden: bool,
 }
 
 impl Doc {
@@ -49,12 +50,31 @@ impl Doc {
 
         let comment = comments.join("\n").trim().to_string();
 
+        let hidden = attrs.iter().any(|attr| {
+            if !attr.path.is_ident("doc") {
+                return false;
+            }
+
+            if let Ok(syn::Meta::List(list)) = attr.parse_meta() {
+                list.nested.iter().any(|nested| {
+                    matches!(
+                        nested,
+                        syn::NestedMeta::Meta(syn::Meta::Path(path))
+                            if path.is_ident("hidden")
+                    )
+                })
+            } else {
+                false
+            }
+        });
+
         Self {
             comment: if comment.is_empty() {
                 None
             } else {
                 Some(comment)
             },
+            hidden,
         }
     }
 }
@@ -126,4 +146,32 @@ mod tests {
             assert_eq!(expected, actual);
         }
     }
+
+    mod given_doc_hidden {
+        use super::*;
+
+        #[test]
+        fn returns_hidden() {
+            let doc: ItemMod = parse_quote! {
+                /// Internal stuff
+                #[doc(hidden)]
+                mod module { }
+            };
+
+            let doc = Doc::from_ast(&doc.attrs);
+
+            assert_eq!(Some("Internal stuff"), doc.comment.as_deref());
+            assert!(doc.hidden);
+        }
+
+        #[test]
+        fn ignores_other_doc_lists() {
+            let doc: ItemMod = parse_quote! {
+                #[doc(alias = "foo")]
+                mod module { }
+            };
+
+            assert!(!Doc::from_ast(&doc.attrs).hidden);
+        }
+    }
 }
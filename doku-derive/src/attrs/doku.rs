@@ -1,6 +1,7 @@
 mod container;
 mod field;
+mod fmt;
 mod metas;
 mod variant;
 
-pub use self::{container::*, field::*, metas::*, variant::*};
+pub use self::{container::*, field::*, fmt::*, metas::*, variant::*};
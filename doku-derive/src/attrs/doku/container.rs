@@ -11,12 +11,33 @@ use darling::FromMeta;
 /// ````
 #[derive(Clone, Debug, Default, FromMeta)]
 pub struct DokuContainer {
+    #[darling(default)]
+    pub bound: Option<syn::LitStr>,
+
     #[darling(default)]
     pub content: Option<syn::LitStr>,
 
+    #[darling(default)]
+    pub example_variant: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub inline: Option<bool>,
+
+    #[darling(default)]
+    pub opaque: Option<bool>,
+
     #[darling(default)]
     pub rename_all: Option<RenameRule>,
 
+    #[darling(default)]
+    pub rename_all_fields: Option<RenameRule>,
+
+    #[darling(default)]
+    pub repr: Option<bool>,
+
+    #[darling(default)]
+    pub sort: Option<SortRule>,
+
     #[darling(default)]
     pub tag: Option<syn::LitStr>,
 
@@ -28,6 +49,9 @@ pub struct DokuContainer {
 
     #[darling(default)]
     pub wrap: Option<syn::LitStr>,
+
+    #[darling(default, multiple)]
+    pub wrap_comment: Vec<syn::LitStr>,
 }
 
 impl DokuContainer {
@@ -38,12 +62,26 @@ impl DokuContainer {
 
     fn merge(self, other: Self) -> Self {
         Self {
+            bound: other.bound.or(self.bound),
             content: other.content.or(self.content),
+            example_variant: other.example_variant.or(self.example_variant),
+            inline: other.inline.or(self.inline),
+            opaque: other.opaque.or(self.opaque),
             rename_all: other.rename_all.or(self.rename_all),
+            rename_all_fields: other
+                .rename_all_fields
+                .or(self.rename_all_fields),
+            repr: other.repr.or(self.repr),
+            sort: other.sort.or(self.sort),
             tag: other.tag.or(self.tag),
             transparent: other.transparent.or(self.transparent),
             untagged: other.untagged.or(self.untagged),
             wrap: other.wrap.or(self.wrap),
+            wrap_comment: self
+                .wrap_comment
+                .into_iter()
+                .chain(other.wrap_comment)
+                .collect(),
         }
     }
 }
@@ -1,4 +1,4 @@
-use super::DokuMetas;
+use super::{DokuFmt, DokuMetas};
 use crate::prelude::*;
 use darling::FromMeta;
 
@@ -12,29 +12,113 @@ use darling::FromMeta;
 /// ````
 #[derive(Clone, Debug, Default, FromMeta)]
 pub struct DokuField {
+    #[darling(default)]
+    pub any: Option<bool>,
+
     #[darling(default, rename = "as")]
     pub as_: Option<syn::LitStr>,
 
+    #[darling(default)]
+    pub bytes: Option<bool>,
+
+    #[darling(default)]
+    pub comment: Option<syn::LitStr>,
+
+    #[darling(default, multiple)]
+    pub doc_append: Vec<syn::LitStr>,
+
+    #[darling(default)]
+    pub enum_values_comment: Option<bool>,
+
+    #[darling(default)]
+    pub duration: Option<bool>,
+
+    #[darling(default)]
+    pub env: Option<syn::LitStr>,
+
     #[darling(default, rename = "example", multiple)]
-    pub examples: Vec<syn::LitStr>,
+    pub examples: Vec<syn::Lit>,
+
+    #[darling(default)]
+    pub example_file: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub fmt: Option<DokuFmt>,
 
     #[darling(default, rename = "meta", multiple)]
     pub metas: Vec<DokuMetas>,
 
+    #[darling(default)]
+    pub inline: Option<bool>,
+
+    #[darling(default)]
+    pub key_example: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub literal: Option<bool>,
+
     #[darling(default)]
     pub literal_example: Option<syn::LitStr>,
 
     #[darling(default)]
     pub flatten: Option<bool>,
 
+    #[darling(default)]
+    pub map_entries: Option<usize>,
+
+    #[darling(default)]
+    pub max: Option<syn::Lit>,
+
+    #[darling(default)]
+    pub max_length: Option<usize>,
+
+    #[darling(default)]
+    pub min: Option<syn::Lit>,
+
+    #[darling(default)]
+    pub min_length: Option<usize>,
+
+    #[darling(default)]
+    pub multiple_of: Option<syn::Lit>,
+
+    #[darling(default, multiple)]
+    pub one_of: Vec<syn::LitStr>,
+
+    #[darling(default)]
+    pub order: Option<usize>,
+
+    #[darling(default)]
+    pub pattern: Option<syn::LitStr>,
+
     #[darling(default)]
     pub rename: Option<syn::LitStr>,
 
+    #[darling(default)]
+    pub section: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub sensitive: Option<bool>,
+
+    #[darling(default)]
+    pub since: Option<syn::LitStr>,
+
     #[darling(default)]
     pub skip: Option<bool>,
 
     #[darling(default)]
     pub tag: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub timestamp: Option<bool>,
+
+    #[darling(default)]
+    pub unit: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub until: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub value_example: Option<syn::LitStr>,
 }
 
 impl DokuField {
@@ -49,15 +133,53 @@ impl DokuField {
 
         let metas = self.metas.into_iter().chain(other.metas).collect();
 
+        let doc_append = self
+            .doc_append
+            .into_iter()
+            .chain(other.doc_append)
+            .collect();
+
+        let one_of = self.one_of.into_iter().chain(other.one_of).collect();
+
         Self {
+            any: other.any.or(self.any),
             as_: other.as_.or(self.as_),
+            bytes: other.bytes.or(self.bytes),
+            comment: other.comment.or(self.comment),
+            doc_append,
+            duration: other.duration.or(self.duration),
+            enum_values_comment: other
+                .enum_values_comment
+                .or(self.enum_values_comment),
+            env: other.env.or(self.env),
             examples,
+            example_file: other.example_file.or(self.example_file),
+            fmt: other.fmt.or(self.fmt),
             metas,
+            inline: other.inline.or(self.inline),
+            key_example: other.key_example.or(self.key_example),
+            literal: other.literal.or(self.literal),
             literal_example: other.literal_example.or(self.literal_example),
             flatten: other.flatten.or(self.flatten),
+            map_entries: other.map_entries.or(self.map_entries),
+            max: other.max.or(self.max),
+            max_length: other.max_length.or(self.max_length),
+            min: other.min.or(self.min),
+            min_length: other.min_length.or(self.min_length),
+            multiple_of: other.multiple_of.or(self.multiple_of),
+            one_of,
+            order: other.order.or(self.order),
+            pattern: other.pattern.or(self.pattern),
             rename: other.rename.or(self.rename),
+            section: other.section.or(self.section),
+            sensitive: other.sensitive.or(self.sensitive),
+            since: other.since.or(self.since),
             skip: other.skip.or(self.skip),
             tag: other.tag.or(self.tag),
+            timestamp: other.timestamp.or(self.timestamp),
+            unit: other.unit.or(self.unit),
+            until: other.until.or(self.until),
+            value_example: other.value_example.or(self.value_example),
         }
     }
 }
@@ -0,0 +1,157 @@
+use crate::prelude::*;
+use darling::FromMeta;
+
+/// Models the `#[doku(fmt(...))]` attribute, which is a convenience layer over
+/// the `fmt.*` metas:
+///
+/// ```ignore
+/// struct Foo {
+///     #[doku(fmt(doc_comments = "hidden", enums_style = "commented"))]
+///     field: Bar,
+///
+///     #[doku(fmt(inline, layout = "two-columns"))]
+///     point: Point,
+/// }
+/// ````
+#[derive(Clone, Debug, Default, FromMeta)]
+pub struct DokuFmt {
+    #[darling(default)]
+    pub doc_comments: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub durations_style: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub enums_style: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub inline: Option<bool>,
+
+    #[darling(default)]
+    pub layout: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub optionals_style: Option<syn::LitStr>,
+
+    #[darling(default)]
+    pub untagged_enums_style: Option<syn::LitStr>,
+}
+
+impl DokuFmt {
+    /// Converts this attribute into pairs of `fmt.*` metas.
+    pub fn into_metas(self) -> Result<Vec<(String, String)>> {
+        let mut metas = Vec::new();
+
+        if let Some(val) = self.doc_comments {
+            let val = parse_option(
+                &val,
+                &[("visible", r#""Visible""#), ("hidden", r#""Hidden""#)],
+            )?;
+
+            metas.push(("fmt.doc_comments".into(), val));
+        }
+
+        if let Some(val) = self.durations_style {
+            let val = parse_option(
+                &val,
+                &[("humantime", r#""Humantime""#), ("seconds", r#""Seconds""#)],
+            )?;
+
+            metas.push(("fmt.durations_style".into(), val));
+        }
+
+        if let Some(val) = self.enums_style {
+            let val = parse_option(
+                &val,
+                &[
+                    ("commented", r#""Commented""#),
+                    ("expanded", r#""Expanded""#),
+                    ("separated", r#""Separated""#),
+                ],
+            )?;
+
+            metas.push(("fmt.enums_style".into(), val));
+        }
+
+        if let Some(val) = self.inline {
+            // There's no "always inline" option, so let's use a width that no
+            // sane object reaches
+            let val = if val {
+                u32::MAX.to_string()
+            } else {
+                "null".into()
+            };
+
+            metas.push(("fmt.auto_inline_under".into(), val));
+        }
+
+        if let Some(val) = self.layout {
+            let val = parse_option(
+                &val,
+                &[
+                    ("one-column", r#""OneColumn""#),
+                    (
+                        "two-columns",
+                        r#"{ "TwoColumns": { "align": true, "spacing": 1 } }"#,
+                    ),
+                ],
+            )?;
+
+            metas.push(("fmt.layout".into(), val));
+        }
+
+        if let Some(val) = self.optionals_style {
+            let val = parse_option(
+                &val,
+                &[
+                    ("value", r#""Value""#),
+                    ("null", r#""Null""#),
+                    ("commented-out", r#""CommentedOut""#),
+                ],
+            )?;
+
+            metas.push(("fmt.optionals_style".into(), val));
+        }
+
+        if let Some(val) = self.untagged_enums_style {
+            let val = parse_option(
+                &val,
+                &[
+                    ("inherited", r#""Inherited""#),
+                    ("alternatives", r#""Alternatives""#),
+                ],
+            )?;
+
+            metas.push(("fmt.untagged_enums_style".into(), val));
+        }
+
+        Ok(metas)
+    }
+}
+
+/// Maps user-facing option (e.g. `one-column`) into its JSON representation
+/// (e.g. `"OneColumn"`).
+fn parse_option(val: &syn::LitStr, options: &[(&str, &str)]) -> Result<String> {
+    let value = val.value();
+
+    options
+        .iter()
+        .find(|(name, _)| *name == value)
+        .map(|(_, json)| json.to_string())
+        .ok_or_else(|| {
+            let expected: Vec<_> = options
+                .iter()
+                .map(|(name, _)| format!("`{}`", name))
+                .collect();
+
+            syn::Error::new_spanned(
+                val,
+                format!(
+                    "unknown option `{}`; expected one of: {}",
+                    value,
+                    expected.join(", ")
+                ),
+            )
+            .into()
+        })
+}
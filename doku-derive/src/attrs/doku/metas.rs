@@ -4,6 +4,9 @@ use std::{collections::BTreeMap, iter::FromIterator};
 const ERROR_EXPECTED_KV_LITERAL: &str =
     "Expected a key-value string literal such as: `#[doku(meta(\"key = value\"))]`";
 
+const ERROR_EXPECTED_KV_PAIR: &str =
+    "Expected a key-value pair such as: `#[doku(meta(key = \"key\", value = \"value\"))]`";
+
 #[derive(Clone, Debug, Default)]
 pub struct DokuMetas {
     pub metas: BTreeMap<String, String>,
@@ -11,39 +14,87 @@ pub struct DokuMetas {
 
 impl FromMeta for DokuMetas {
     fn from_list(items: &[syn::NestedMeta]) -> Result<Self> {
-        let metas = items
-            .iter()
-            .map(|item| match item {
+        let mut metas = BTreeMap::new();
+        let mut key = None;
+        let mut value = None;
+
+        for item in items {
+            match item {
                 syn::NestedMeta::Lit(syn::Lit::Str(lit)) => {
-                    Ok((lit, lit.value()))
+                    let (key, value) = parse_kv_literal(lit)?;
+                    metas.insert(key, value);
+                }
+
+                syn::NestedMeta::Meta(syn::Meta::NameValue(
+                    syn::MetaNameValue { path, lit, .. },
+                )) if path.is_ident("key") => {
+                    if let syn::Lit::Str(lit) = lit {
+                        key = Some(lit.value());
+                    } else {
+                        return Err(Error::custom(ERROR_EXPECTED_KV_PAIR)
+                            .with_span(lit));
+                    }
+                }
+
+                syn::NestedMeta::Meta(syn::Meta::NameValue(
+                    syn::MetaNameValue { path, lit, .. },
+                )) if path.is_ident("value") => {
+                    value = Some(lit_to_string(lit)?);
                 }
 
                 _ => {
-                    Err(Error::custom(ERROR_EXPECTED_KV_LITERAL)
-                        .with_span(item))
+                    return Err(Error::custom(ERROR_EXPECTED_KV_LITERAL)
+                        .with_span(item));
                 }
-            })
-            .map(|item| {
-                item.and_then(|(lit, lit_value)| {
-                    let mut kv = lit_value.splitn(2, '=');
+            }
+        }
 
-                    let key = kv.next().ok_or_else(|| {
-                        Error::custom(ERROR_EXPECTED_KV_LITERAL).with_span(lit)
-                    })?;
+        match (key, value) {
+            (Some(key), Some(value)) => {
+                metas.insert(key, value);
+            }
 
-                    let value = kv.next().ok_or_else(|| {
-                        Error::custom(ERROR_EXPECTED_KV_LITERAL).with_span(lit)
-                    })?;
+            (None, None) => {
+                //
+            }
 
-                    Ok((key.trim().to_owned(), value.trim().to_owned()))
-                })
-            })
-            .collect::<Result<_>>()?;
+            _ => {
+                return Err(Error::custom(ERROR_EXPECTED_KV_PAIR));
+            }
+        }
 
         Ok(Self { metas })
     }
 }
 
+/// Parses `"key = value"`.
+fn parse_kv_literal(lit: &syn::LitStr) -> Result<(String, String)> {
+    let lit_value = lit.value();
+    let mut kv = lit_value.splitn(2, '=');
+
+    let key = kv.next().ok_or_else(|| {
+        Error::custom(ERROR_EXPECTED_KV_LITERAL).with_span(lit)
+    })?;
+
+    let value = kv.next().ok_or_else(|| {
+        Error::custom(ERROR_EXPECTED_KV_LITERAL).with_span(lit)
+    })?;
+
+    Ok((key.trim().to_owned(), value.trim().to_owned()))
+}
+
+/// Converts value of `value = ...` into a string - strings are taken as-is,
+/// while the rest of literals (numbers, booleans) get stringified.
+fn lit_to_string(lit: &syn::Lit) -> Result<String> {
+    match lit {
+        syn::Lit::Str(lit) => Ok(lit.value()),
+        syn::Lit::Int(lit) => Ok(lit.base10_digits().to_owned()),
+        syn::Lit::Float(lit) => Ok(lit.base10_digits().to_owned()),
+        syn::Lit::Bool(lit) => Ok(lit.value.to_string()),
+        lit => Err(Error::custom(ERROR_EXPECTED_KV_PAIR).with_span(lit)),
+    }
+}
+
 impl FromIterator<DokuMetas> for DokuMetas {
     fn from_iter<T>(iter: T) -> Self
     where
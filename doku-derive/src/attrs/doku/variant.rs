@@ -7,18 +7,36 @@ use darling::FromMeta;
 /// enum Foo {
 ///     #[doku(rename = "BAR")]
 ///     Bar,
+///
+///     #[doku(example)]
+///     Zar,
 /// }
 /// ````
 #[derive(Clone, Debug, Default, FromMeta)]
 pub struct DokuVariant {
+    #[darling(default)]
+    pub comment: Option<syn::LitStr>,
+
+    #[darling(default, multiple)]
+    pub doc_append: Vec<syn::LitStr>,
+
+    #[darling(default)]
+    pub example: Option<bool>,
+
     #[darling(default)]
     pub rename: Option<syn::LitStr>,
 
     #[darling(default)]
     pub rename_all: Option<RenameRule>,
 
+    #[darling(default)]
+    pub since: Option<syn::LitStr>,
+
     #[darling(default)]
     pub skip: Option<bool>,
+
+    #[darling(default)]
+    pub until: Option<syn::LitStr>,
 }
 
 impl DokuVariant {
@@ -28,10 +46,21 @@ impl DokuVariant {
     }
 
     fn merge(self, other: Self) -> Self {
+        let doc_append = self
+            .doc_append
+            .into_iter()
+            .chain(other.doc_append)
+            .collect();
+
         Self {
+            comment: other.comment.or(self.comment),
+            doc_append,
+            example: other.example.or(self.example),
             rename: other.rename.or(self.rename),
             rename_all: other.rename_all.or(self.rename_all),
+            since: other.since.or(self.since),
             skip: other.skip.or(self.skip),
+            until: other.until.or(self.until),
         }
     }
 }
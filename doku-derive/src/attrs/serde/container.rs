@@ -11,6 +11,9 @@ use darling::FromMeta;
 /// ````
 #[derive(Clone, Debug, Default, FromMeta)]
 pub struct SerdeContainer {
+    #[darling(default)]
+    pub bound: Option<SerdeBound>,
+
     #[darling(default)]
     pub content: Option<syn::LitStr>,
 
@@ -22,11 +25,14 @@ pub struct SerdeContainer {
     pub default: Option<syn::Meta>,
 
     #[darling(default)]
-    pub deny_unknown_fields: Option<syn::Meta>,
+    pub deny_unknown_fields: Option<bool>,
 
     #[darling(default)]
     pub rename_all: Option<RenameRule>,
 
+    #[darling(default)]
+    pub rename_all_fields: Option<RenameRule>,
+
     #[darling(default)]
     pub tag: Option<syn::LitStr>,
 
@@ -45,11 +51,17 @@ impl SerdeContainer {
 
     pub fn merge(self, other: Self) -> Self {
         Self {
+            bound: other.bound.or(self.bound),
             content: other.content.or(self.content),
-            krate: None,               // it's a no-op for us
-            default: None,             // it's a no-op for us
-            deny_unknown_fields: None, // it's a no-op for us
+            krate: None, // it's a no-op for us
+            default: other.default.or(self.default),
+            deny_unknown_fields: other
+                .deny_unknown_fields
+                .or(self.deny_unknown_fields),
             rename_all: other.rename_all.or(self.rename_all),
+            rename_all_fields: other
+                .rename_all_fields
+                .or(self.rename_all_fields),
             tag: other.tag.or(self.tag),
             transparent: other.transparent.or(self.transparent),
             untagged: other.untagged.or(self.untagged),
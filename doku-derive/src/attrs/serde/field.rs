@@ -53,9 +53,9 @@ impl SerdeField {
 
     pub fn merge(self, other: Self) -> Self {
         Self {
-            alias: Default::default(), // it's a no-op for us
-            default: None,             // it's a no-op for us
-            deserialize_with: None,    // it's a no-op for us
+            alias: self.alias.into_iter().chain(other.alias).collect(),
+            default: other.default.or(self.default),
+            deserialize_with: None, // it's a no-op for us
             flatten: other.flatten.or(self.flatten),
             rename: other.rename.or(self.rename),
             serialize_with: None, // it's a no-op for us
@@ -18,7 +18,7 @@ pub struct SerdeVariant {
     pub deserialize_with: Option<syn::Meta>,
 
     #[darling(default)]
-    pub other: Option<syn::Meta>,
+    pub other: Option<bool>,
 
     #[darling(default)]
     pub rename: Option<syn::LitStr>,
@@ -50,9 +50,9 @@ impl SerdeVariant {
 
     pub fn merge(self, other: Self) -> Self {
         Self {
-            alias: Default::default(), // it's a no-op for us
-            deserialize_with: None,    // it's a no-op for us
-            other: None,               // it's a no-op for us
+            alias: self.alias.into_iter().chain(other.alias).collect(),
+            deserialize_with: None, // it's a no-op for us
+            other: other.other.or(self.other),
             rename: other.rename.or(self.rename),
             rename_all: other.rename_all.or(self.rename_all),
             serialize_with: None, // it's a no-op for us
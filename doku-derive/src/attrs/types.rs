@@ -1,3 +1,7 @@
 mod rename_rule;
+mod serde_bound;
+mod sort_rule;
 
 pub use rename_rule::RenameRule;
+pub use serde_bound::SerdeBound;
+pub use sort_rule::SortRule;
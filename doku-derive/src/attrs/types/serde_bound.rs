@@ -0,0 +1,39 @@
+use darling::FromMeta;
+
+/// Models `#[serde(bound = "...")]`, which can be also specified separately
+/// for serialization and deserialization:
+///
+/// ```ignore
+/// #[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize"))]
+/// ```
+///
+/// It's parsed only so that it's accepted - `impl Document` gets its bounds
+/// either inferred or from `#[doku(bound = "...")]`.
+#[derive(Clone, Debug, Default)]
+pub struct SerdeBound;
+
+impl FromMeta for SerdeBound {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        if let syn::Lit::Str(_) = value {
+            Ok(Self)
+        } else {
+            Err(darling::Error::unexpected_lit_type(value))
+        }
+    }
+
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        #[allow(dead_code)]
+        struct List {
+            #[darling(default)]
+            serialize: Option<syn::LitStr>,
+
+            #[darling(default)]
+            deserialize: Option<syn::LitStr>,
+        }
+
+        List::from_list(items)?;
+
+        Ok(Self)
+    }
+}
@@ -0,0 +1,19 @@
+use darling::FromMeta;
+
+/// The different possible ways to sort fields of a struct.
+#[derive(Copy, Clone, PartialEq, Debug, FromMeta)]
+pub enum SortRule {
+    /// Keep fields in the order they've been declared in.
+    #[darling(rename = "declaration")]
+    Declaration,
+
+    /// Sort fields alphabetically, by their (possibly renamed) names.
+    #[darling(rename = "alphabetical")]
+    Alphabetical,
+}
+
+impl Default for SortRule {
+    fn default() -> Self {
+        Self::Declaration
+    }
+}
@@ -8,10 +8,14 @@ mod expand_wrap;
 mod utils;
 
 use self::{
-    expand_enum::expand_enum, expand_field::expand_field,
-    expand_fields::expand_fields, expand_struct::expand_struct,
-    expand_variant::expand_variant, expand_variants::expand_variants,
-    expand_wrap::expand_wrap, utils::*,
+    expand_enum::expand_enum,
+    expand_field::expand_field,
+    expand_fields::expand_fields,
+    expand_struct::expand_struct,
+    expand_variant::expand_variant,
+    expand_variants::{discriminants, expand_variants},
+    expand_wrap::expand_wrap,
+    utils::*,
 };
 use crate::prelude::*;
 
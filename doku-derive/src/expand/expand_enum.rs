@@ -50,12 +50,62 @@ pub fn expand_enum(
 
         let rename_variants =
             doku.rename_all.or(serde.rename_all).unwrap_or_default();
-        let variants = expand_variants(&data.variants, rename_variants)?;
+        let rename_fields = doku.rename_all_fields.or(serde.rename_all_fields);
+
+        let variants =
+            expand_variants(&data.variants, rename_variants, rename_fields)?;
+
+        let (example_variant, example) = match (
+            &doku.example_variant,
+            find_example_variant(&data.variants)?,
+        ) {
+            (Some(name), None) => {
+                let variant = data
+                    .variants
+                    .iter()
+                    .find(|variant| variant.ident == name.value());
+
+                if variant.is_none() {
+                    return Err(syn::Error::new_spanned(
+                        name,
+                        format!("unknown variant `{}`", name.value()),
+                    )
+                    .into());
+                }
+
+                (quote! { Some(#name) }, variant)
+            }
+
+            (None, Some(variant)) => {
+                let title = variant.ident.to_string();
+
+                (quote! { Some(#title) }, Some(variant))
+            }
+
+            (Some(name), Some(_)) => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "`example_variant` cannot be combined with a variant \
+                     marked as `example`",
+                )
+                .into());
+            }
+
+            (None, None) => (quote! { None }, None),
+        };
+
+        let repr = doku.repr.unwrap_or(false);
+
+        if repr {
+            check_repr_discriminants(&data.variants, example)?;
+        }
 
         quote! {
             ::doku::TypeKind::Enum {
                 tag: #tag,
                 variants: vec![ #(#variants)* ],
+                example_variant: #example_variant,
+                repr: #repr,
             }
         }
     };
@@ -66,20 +116,90 @@ pub fn expand_enum(
         };
 
         if let Some(wrap) = doku.wrap {
-            ty = expand_wrap(wrap, ty);
+            ty = expand_wrap(wrap, doku.wrap_comment, ty)?;
         }
 
-        ty
+        let ty = expand_name(doku.inline, ty);
+
+        expand_header(&input.attrs, ty)
     };
 
-    let generics = new_generics_with_where_clause(&input.generics)?;
+    // `#[serde(bound)]` is about `Serialize` & `Deserialize`, so - contrary to
+    // `#[doku(bound)]` - it doesn't affect the bounds we infer
+    let bound = doku.bound;
+    let generics = new_generics_with_where_clause(
+        &input.generics,
+        bound,
+        data.variants.iter().flat_map(|variant| &variant.fields),
+    )?;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote! {
         impl #impl_generics ::doku::Document for #ident #ty_generics #where_clause {
             fn ty() -> ::doku::Type {
-                #ty
+                ::doku::ty_guarded::<Self, _>(|| { #ty })
             }
         }
     })
 }
+
+/// Returns the variant marked with `#[doku(example)]`, if any.
+fn find_example_variant(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+) -> Result<Option<&syn::Variant>> {
+    let mut found = None;
+
+    for variant in variants {
+        let doku = attrs::DokuVariant::from_ast(&variant.attrs)?;
+
+        if !doku.example.unwrap_or(false) {
+            continue;
+        }
+
+        if found.is_some() {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                "only one variant can be marked as `example`",
+            )
+            .into());
+        }
+
+        found = Some(variant);
+    }
+
+    Ok(found)
+}
+
+/// Makes sure that a `#[doku(repr)]` enum can be printed - i.e. that its
+/// example variant (or at least any variant, if no example has been chosen)
+/// has a discriminant we were able to determine.
+fn check_repr_discriminants(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+    example: Option<&syn::Variant>,
+) -> Result<()> {
+    let discriminants = discriminants(variants);
+
+    let missing = if let Some(example) = example {
+        variants
+            .iter()
+            .zip(&discriminants)
+            .find(|(variant, _)| variant.ident == example.ident)
+            .filter(|(_, discriminant)| discriminant.is_none())
+            .map(|(variant, _)| variant)
+    } else if discriminants.iter().all(Option::is_none) {
+        variants.first()
+    } else {
+        None
+    };
+
+    if let Some(variant) = missing {
+        return Err(syn::Error::new_spanned(
+            &variant.ident,
+            "`repr` enums require the example variant to have a discriminant \
+             that's an integer literal (e.g. `= 1`) or follows one",
+        )
+        .into());
+    }
+
+    Ok(())
+}
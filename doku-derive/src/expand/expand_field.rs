@@ -17,17 +17,37 @@ pub fn expand_field(
 
     let mut field = Field {
         name: quote! { #ident },
-        ty: quote! { #ty },
+        aliases: Vec::new(),
+        ty: quote! {
+            <#ty as ::doku::Document>::ty()
+        },
         metas: quote! { Default::default() },
-        comment: quote! { None },
+        cfg: None,
+        constraints: Vec::new(),
+        unit: quote! { None },
+        env: quote! { None },
+        since: quote! { None },
+        until: quote! { None },
+        location: expand_location(field),
+        sensitive: false,
+        has_default: false,
+        enum_values_comment: false,
+        hidden: false,
+        comment: None,
+        doc_append: Vec::new(),
         example: quote! { None },
+        map_examples: quote! {},
+        documented_ty: quote! { #ty },
+        inline: quote! { None },
         tag: quote! { None },
         serializable: true,
         deserializable: true,
         flattened: false,
+        section: quote! { None },
     };
 
     field.add_doc_attrs(attrs);
+    field.add_cfg_attrs(attrs)?;
     field.add_serde_attrs(&attrs)?;
     field.add_doku_attrs(&attrs)?;
 
@@ -36,27 +56,64 @@ pub fn expand_field(
 
 struct Field {
     name: TokenStream2,
+    aliases: Vec<syn::LitStr>,
     ty: TokenStream2,
-    comment: TokenStream2,
+    comment: Option<String>,
+    doc_append: Vec<String>,
     example: TokenStream2,
+    map_examples: TokenStream2,
+    documented_ty: TokenStream2,
+    inline: TokenStream2,
     metas: TokenStream2,
+    cfg: Option<String>,
+    constraints: Vec<TokenStream2>,
+    unit: TokenStream2,
+    env: TokenStream2,
+    since: TokenStream2,
+    until: TokenStream2,
+    location: TokenStream2,
+    sensitive: bool,
+    has_default: bool,
+    enum_values_comment: bool,
+    hidden: bool,
     tag: TokenStream2,
     serializable: bool,
     deserializable: bool,
     flattened: bool,
+    section: TokenStream2,
 }
 
 impl Field {
     fn add_doc_attrs(&mut self, attrs: &[syn::Attribute]) {
-        if let Some(val) = attrs::Doc::from_ast(attrs).comment {
-            self.comment = quote! { Some(#val) };
-        }
+        let doc = attrs::Doc::from_ast(attrs);
+
+        self.comment = doc.comment;
+        self.hidden = doc.hidden;
+    }
+
+    /// Fields disabled through `#[cfg(...)]` never reach us, but the enabled
+    /// ones do keep their attribute - so let's record it, allowing printers to
+    /// mention that the field is conditional.
+    fn add_cfg_attrs(&mut self, attrs: &[syn::Attribute]) -> Result<()> {
+        let cfgs: Vec<_> = attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("cfg"))
+            .map(|attr| attr.parse_args::<TokenStream2>())
+            .collect::<syn::Result<_>>()?;
+
+        self.cfg = match cfgs.as_slice() {
+            [] => None,
+            [cfg] => Some(cfg.to_string()),
+            cfgs => Some(quote! { all(#(#cfgs),*) }.to_string()),
+        };
+
+        Ok(())
     }
 
     fn add_serde_attrs(&mut self, attrs: &[syn::Attribute]) -> Result<()> {
         let attrs::SerdeField {
-            alias: _,
-            default: _,
+            alias,
+            default,
             deserialize_with: _,
             flatten,
             rename,
@@ -68,6 +125,12 @@ impl Field {
             with: _,
         } = attrs::SerdeField::from_ast(attrs)?;
 
+        self.aliases.extend(alias);
+
+        if default.is_some() {
+            self.has_default = true;
+        }
+
         if let Some(val) = flatten {
             self.flattened = val;
         }
@@ -94,34 +157,204 @@ impl Field {
 
     fn add_doku_attrs(&mut self, attrs: &[syn::Attribute]) -> Result<()> {
         let attrs::DokuField {
+            any,
             as_,
+            bytes,
+            comment,
+            doc_append,
+            duration,
+            enum_values_comment,
+            env,
             examples,
+            example_file,
+            fmt,
             metas,
+            inline,
+            key_example,
+            literal,
             literal_example,
             flatten,
+            map_entries,
+            max,
+            max_length,
+            min,
+            min_length,
+            multiple_of,
+            one_of,
+            order: _,
+            pattern,
             rename,
+            section,
+            sensitive,
+            since,
             skip,
             tag,
+            timestamp,
+            unit,
+            until,
+            value_example,
         } = attrs::DokuField::from_ast(&attrs)?;
 
         if let Some(val) = as_ {
             let val = string_to_path(&val)?;
-            self.ty = quote! { #val };
+
+            self.ty = quote! {
+                <#val as ::doku::Document>::ty()
+            };
+
+            self.documented_ty = quote! { #val };
+        }
+
+        if any.unwrap_or(false) {
+            self.ty = quote! {
+                ::doku::Type::from(::doku::TypeKind::Any)
+            };
+        }
+
+        // `std::time::Duration` and `std::time::SystemTime` are documented
+        // through their Serde shapes (e.g. `{ "secs": ..., "nanos": ... }`),
+        // but fields serialized through e.g. `humantime-serde` can opt into
+        // the more human-friendly ones
+        if duration.unwrap_or(false) {
+            self.ty = expand_kind(&self.ty, quote! { Duration });
+        }
+
+        if timestamp.unwrap_or(false) {
+            self.ty =
+                expand_kind(&self.ty, quote! { DateTime { offset: true } });
+        }
+
+        // Same goes for binary data, which Serde serializes as arrays of
+        // integers - unless e.g. `serde_with::base64` is used
+        if bytes.unwrap_or(false) {
+            self.ty = expand_kind(&self.ty, quote! { Bytes });
+        }
+
+        if let Some(val) = comment {
+            self.comment = Some(val.value());
+        }
+
+        self.doc_append
+            .extend(doc_append.into_iter().map(|val| val.value()));
+
+        if let Some(val) = enum_values_comment {
+            self.enum_values_comment = val;
+        }
+
+        if let Some(val) = env {
+            self.env = quote! { Some(#val) };
+        }
+
+        if let Some(val) = inline {
+            self.inline = quote! { Some(#val) };
+        }
+
+        if key_example.is_some() || value_example.is_some() {
+            let documented_ty = &self.documented_ty;
+
+            // Makes sure (at compile time) that the attributes are not ignored
+            self.map_examples.extend(quote! {
+                ::doku::assert_map::<#documented_ty>();
+
+                let map = match &mut ty.kind {
+                    ::doku::TypeKind::Optional { ty } => &mut ty.kind,
+                    kind => kind,
+                };
+            });
+        }
+
+        if let Some(val) = key_example {
+            self.map_examples.extend(quote! {
+                if let ::doku::TypeKind::Map { key, .. } = &mut *map {
+                    key.example = Some(::doku::Example::Simple(#val));
+                }
+            });
         }
 
-        if let Some(literal_example) = literal_example {
+        if let Some(val) = value_example {
+            self.map_examples.extend(quote! {
+                if let ::doku::TypeKind::Map { value, .. } = &mut *map {
+                    value.example = Some(::doku::Example::Simple(#val));
+                }
+            });
+        }
+
+        if let Some(example_file) = example_file {
+            // Paths are relative to the crate's root, similarly to Cargo's
+            // `build = "..."` and friends
+            self.example = quote! {
+                Some(::doku::Example::Literal(include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/",
+                    #example_file,
+                ))))
+            };
+        } else if let Some(literal_example) = literal_example {
             self.example = quote! {
                 Some(::doku::Example::Literal(#literal_example))
             };
+        } else if literal.unwrap_or(false) {
+            if examples.len() != 1 {
+                let span = examples
+                    .get(1)
+                    .map_or_else(Span::call_site, |example| example.span());
+
+                return Err(syn::Error::new(
+                    span,
+                    "`literal` requires exactly one `example`",
+                )
+                .into());
+            }
+
+            let literal_example = match &examples[0] {
+                syn::Lit::Str(example) => example,
+
+                example => {
+                    return Err(syn::Error::new_spanned(
+                        example,
+                        "`literal` requires a string `example`",
+                    )
+                    .into());
+                }
+            };
+
+            self.example = quote! {
+                Some(::doku::Example::Literal(#literal_example))
+            };
+        } else if examples.len() == 1 {
+            let example = expand_example(&examples[0])?;
+
+            self.example = quote! {
+                Some(#example)
+            };
         } else if !examples.is_empty() {
+            let examples = examples
+                .iter()
+                .map(example_to_string)
+                .collect::<Result<Vec<_>>>()?;
+
             self.example = quote! {
                 Some(::doku::Example::from(&[#(#examples,)*][..]))
             };
         }
 
-        if !metas.is_empty() {
-            let metas = DokuMetas::from_iter(metas);
+        let mut metas = DokuMetas::from_iter(metas);
+
+        if let Some(val) = self.cfg.take() {
+            metas.metas.entry("cfg".into()).or_insert(val);
+        }
+
+        if let Some(val) = fmt {
+            metas.metas.extend(val.into_metas()?);
+        }
+
+        if let Some(val) = map_entries {
+            metas
+                .metas
+                .insert("fmt.maps_style.entries".into(), val.to_string());
+        }
 
+        if !metas.metas.is_empty() {
             let meta_keys = metas.metas.keys();
             let meta_values = metas.metas.values();
 
@@ -135,10 +368,60 @@ impl Field {
             self.flattened = val;
         }
 
+        if min_length.is_some() || max_length.is_some() {
+            let min = expand_option(min_length);
+            let max = expand_option(max_length);
+
+            self.constraints.push(quote! {
+                ::doku::Constraint::Length { min: #min, max: #max }
+            });
+        }
+
+        if let Some(val) = pattern {
+            self.constraints.push(quote! {
+                ::doku::Constraint::Pattern(#val)
+            });
+        }
+
+        if min.is_some() || max.is_some() {
+            let min = expand_option(min.map(number_to_string).transpose()?);
+            let max = expand_option(max.map(number_to_string).transpose()?);
+
+            self.constraints.push(quote! {
+                ::doku::Constraint::Range { min: #min, max: #max }
+            });
+        }
+
+        if let Some(val) = multiple_of {
+            let val = number_to_string(val)?;
+
+            self.constraints.push(quote! {
+                ::doku::Constraint::MultipleOf(#val)
+            });
+        }
+
+        if !one_of.is_empty() {
+            self.constraints.push(quote! {
+                ::doku::Constraint::OneOf(vec![ #(#one_of),* ])
+            });
+        }
+
         if let Some(val) = rename {
             self.name = quote_spanned! { val.span() => #val };
         }
 
+        if let Some(val) = section {
+            self.section = quote! { Some(#val) };
+        }
+
+        if let Some(val) = sensitive {
+            self.sensitive = val;
+        }
+
+        if let Some(val) = since {
+            self.since = quote! { Some(#val) };
+        }
+
         if let Some(val) = skip {
             self.serializable = !val;
             self.deserializable = !val;
@@ -148,38 +431,86 @@ impl Field {
             self.tag = quote! { Some(#val) };
         }
 
+        if let Some(val) = unit {
+            self.unit = quote! { Some(#val) };
+        }
+
+        if let Some(val) = until {
+            self.until = quote! { Some(#val) };
+        }
+
         Ok(())
     }
 
     fn render(self, named: bool) -> TokenStream2 {
         let Self {
             name,
+            aliases,
             ty,
             comment,
+            doc_append,
             example,
+            map_examples,
+            documented_ty: _,
+            inline,
             metas,
+            cfg: _,
+            constraints,
+            unit,
+            env,
+            since,
+            until,
+            location,
+            sensitive,
+            has_default,
+            enum_values_comment,
+            hidden,
             tag,
             serializable,
             deserializable,
             flattened,
+            section,
         } = self;
 
         if serializable || deserializable {
+            let comment = expand_comment(comment, doc_append);
+
             let ty_kind = quote! {
-                let ty = <#ty as ::doku::Document>::ty();
+                #[allow(unused_mut)]
+                let mut ty = #ty;
+
+                #map_examples
 
                 ::doku::Field {
                     ty: ::doku::Type {
                         comment: #comment,
+                        header: ty.header,
+                        name: ty.name,
+                        inline: #inline.unwrap_or(ty.inline),
                         example: #example.or(ty.example),
                         metas: #metas,
+                        constraints: ::doku::Constraint::merge(
+                            vec![ #(#constraints),* ],
+                            ty.constraints,
+                        ),
+                        unit: #unit.or(ty.unit),
+                        env: #env,
+                        since: #since,
+                        until: #until,
+                        location: #location.or(ty.location),
+                        sensitive: #sensitive || ty.sensitive,
+                        has_default: #has_default,
+                        enum_values_comment: #enum_values_comment,
+                        hidden: #hidden,
                         tag: #tag,
                         serializable: #serializable,
                         deserializable: #deserializable,
                         kind: ty.kind,
                     },
 
+                    aliases: vec![ #(#aliases),* ],
                     flattened: #flattened,
+                    section: #section,
                 }
             };
 
@@ -199,3 +530,112 @@ impl Field {
         }
     }
 }
+
+/// Expands to the field's source location when the `provenance` feature is
+/// enabled; `file!()` and `line!()` get resolved at the field's span, so they
+/// point at the field itself instead of at the `#[derive]`.
+fn expand_location(field: &syn::Field) -> TokenStream2 {
+    if !cfg!(feature = "provenance") {
+        return quote! { None };
+    }
+
+    let span = field
+        .ident
+        .as_ref()
+        .map_or_else(|| field.ty.span(), |ident| ident.span());
+
+    quote_spanned! { span =>
+        Some(::doku::Location {
+            module: module_path!(),
+            file: file!(),
+            line: line!(),
+        })
+    }
+}
+
+/// Replaces kind of given type (or of the type inside `Option<...>`) with
+/// given `TypeKind`.
+fn expand_kind(ty: &TokenStream2, kind: TokenStream2) -> TokenStream2 {
+    quote! {{
+        let mut ty = #ty;
+
+        match &mut ty.kind {
+            ::doku::TypeKind::Optional { ty } => {
+                **ty = ::doku::Type::from(::doku::TypeKind::#kind);
+            }
+            kind => {
+                *kind = ::doku::TypeKind::#kind;
+            }
+        }
+
+        ty
+    }}
+}
+
+fn expand_option<T: quote::ToTokens>(val: Option<T>) -> TokenStream2 {
+    if let Some(val) = val {
+        quote! { Some(#val) }
+    } else {
+        quote! { None }
+    }
+}
+
+/// Converts numeric literal (e.g. of `#[doku(min = 1.5)]`) into a string, so
+/// that it can be stored verbatim.
+///
+/// Since attributes don't accept negative literals, numbers can be provided as
+/// strings, too (e.g. `#[doku(min = "-10")]`).
+fn number_to_string(lit: syn::Lit) -> Result<String> {
+    match &lit {
+        syn::Lit::Int(val) => Ok(val.base10_digits().to_owned()),
+        syn::Lit::Float(val) => Ok(val.base10_digits().to_owned()),
+
+        syn::Lit::Str(val) if val.value().parse::<f64>().is_ok() => {
+            Ok(val.value())
+        }
+
+        _ => Err(syn::Error::new_spanned(lit, "expected a number").into()),
+    }
+}
+
+/// Converts literal of `#[doku(example = ...)]` into a typed `Example`, so
+/// that e.g. `#[doku(example = 8080)]` doesn't have to be written as a string.
+fn expand_example(lit: &syn::Lit) -> Result<TokenStream2> {
+    match lit {
+        syn::Lit::Str(val) => Ok(quote! { ::doku::Example::Simple(#val) }),
+        syn::Lit::Bool(val) => Ok(quote! { ::doku::Example::Bool(#val) }),
+
+        syn::Lit::Int(val) => {
+            let val: i64 = val.base10_parse()?;
+            Ok(quote! { ::doku::Example::Int(#val) })
+        }
+
+        syn::Lit::Float(val) => {
+            let val: f64 = val.base10_parse()?;
+            Ok(quote! { ::doku::Example::Float(#val) })
+        }
+
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "expected a string, a number or a boolean",
+        )
+        .into()),
+    }
+}
+
+/// Converts literal of `#[doku(example = ...)]` into a string - used for
+/// compound examples, which are stored verbatim.
+fn example_to_string(lit: &syn::Lit) -> Result<String> {
+    match lit {
+        syn::Lit::Str(val) => Ok(val.value()),
+        syn::Lit::Bool(val) => Ok(val.value.to_string()),
+        syn::Lit::Int(val) => Ok(val.base10_digits().to_owned()),
+        syn::Lit::Float(val) => Ok(val.base10_digits().to_owned()),
+
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "expected a string, a number or a boolean",
+        )
+        .into()),
+    }
+}
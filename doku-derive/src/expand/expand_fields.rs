@@ -1,11 +1,15 @@
 use super::*;
+use std::cmp::Ordering;
 
 pub fn expand_fields(
     fields: &syn::Fields,
     rename_fields: RenameRule,
+    sort_fields: SortRule,
 ) -> Result<TokenStream2> {
     match fields {
-        syn::Fields::Named(inner) => expand_named_fields(inner, rename_fields),
+        syn::Fields::Named(inner) => {
+            expand_named_fields(inner, rename_fields, sort_fields)
+        }
         syn::Fields::Unnamed(inner) => expand_unnamed_fields(inner),
         syn::Fields::Unit => Ok(expand_unit_fields()),
     }
@@ -14,10 +18,12 @@ pub fn expand_fields(
 fn expand_named_fields(
     fields: &syn::FieldsNamed,
     rename_fields: RenameRule,
+    sort_fields: SortRule,
 ) -> Result<TokenStream2> {
+    let fields = sort_named_fields(fields, rename_fields, sort_fields)?;
+
     let fields: Vec<_> = fields
-        .named
-        .iter()
+        .into_iter()
         .map(|field| expand_field(field, true, rename_fields))
         .collect::<Result<_>>()?;
 
@@ -28,6 +34,47 @@ fn expand_named_fields(
     })
 }
 
+/// Sorts fields according to `#[doku(order = ...)]` and `#[doku(sort = ...)]`
+/// - fields with an explicit order go first, followed by the rest of them.
+fn sort_named_fields(
+    fields: &syn::FieldsNamed,
+    rename_fields: RenameRule,
+    sort_fields: SortRule,
+) -> Result<Vec<&syn::Field>> {
+    let mut fields: Vec<_> = fields
+        .named
+        .iter()
+        .map(|field| {
+            let doku = attrs::DokuField::from_ast(&field.attrs)?;
+            let serde = attrs::SerdeField::from_ast(&field.attrs)?;
+
+            let order = doku.order.map_or((1, 0), |order| (0, order));
+
+            let name = doku
+                .rename
+                .or(serde.rename)
+                .map(|name| name.value())
+                .or_else(|| {
+                    field.ident.as_ref().map(|ident| {
+                        rename_fields.apply_to_field(&ident.to_string())
+                    })
+                })
+                .unwrap_or_default();
+
+            Ok((order, name, field))
+        })
+        .collect::<Result<_>>()?;
+
+    fields.sort_by(|(order_a, name_a, _), (order_b, name_b, _)| {
+        order_a.cmp(order_b).then_with(|| match sort_fields {
+            SortRule::Declaration => Ordering::Equal,
+            SortRule::Alphabetical => name_a.cmp(name_b),
+        })
+    });
+
+    Ok(fields.into_iter().map(|(_, _, field)| field).collect())
+}
+
 fn expand_unnamed_fields(fields: &syn::FieldsUnnamed) -> Result<TokenStream2> {
     let fields: Vec<_> = fields
         .unnamed
@@ -11,7 +11,8 @@ pub fn expand_struct(
     let ty = {
         let rename_fields =
             doku.rename_all.or(serde.rename_all).unwrap_or_default();
-        let fields = expand_fields(&data.fields, rename_fields)?;
+        let sort_fields = doku.sort.unwrap_or_default();
+        let fields = expand_fields(&data.fields, rename_fields, sort_fields)?;
 
         let transparent = {
             let transparent =
@@ -19,27 +20,57 @@ pub fn expand_struct(
             quote! { #transparent }
         };
 
+        let deny_unknown_fields = serde.deny_unknown_fields.unwrap_or(false);
+        let opaque = doku.opaque.unwrap_or(false);
+
         let mut ty = quote! {
             ::doku::Type::from(::doku::TypeKind::Struct {
                 fields: #fields,
                 transparent: #transparent,
+                deny_unknown_fields: #deny_unknown_fields,
+                opaque: #opaque,
             })
         };
 
+        // `#[serde(default)]` on a struct makes all of its fields default to
+        // their values from the struct's `Default` impl
+        if serde.default.is_some() {
+            ty = quote! {{
+                let mut ty = #ty;
+
+                if let ::doku::TypeKind::Struct {
+                    fields: ::doku::Fields::Named { fields },
+                    ..
+                } = &mut ty.kind {
+                    for (_, field) in fields {
+                        field.ty.has_default = true;
+                    }
+                }
+
+                ty
+            }};
+        }
+
         if let Some(wrap) = doku.wrap {
-            ty = expand_wrap(wrap, ty);
+            ty = expand_wrap(wrap, doku.wrap_comment, ty)?;
         }
 
-        ty
+        let ty = expand_name(doku.inline, ty);
+
+        expand_header(&input.attrs, ty)
     };
 
-    let generics = new_generics_with_where_clause(&input.generics)?;
+    // `#[serde(bound)]` is about `Serialize` & `Deserialize`, so - contrary to
+    // `#[doku(bound)]` - it doesn't affect the bounds we infer
+    let bound = doku.bound;
+    let generics =
+        new_generics_with_where_clause(&input.generics, bound, &data.fields)?;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote! {
         impl #impl_generics ::doku::Document for #ident #ty_generics #where_clause {
             fn ty() -> ::doku::Type {
-                #ty
+                ::doku::ty_guarded::<Self, _>(|| { #ty })
             }
         }
     })
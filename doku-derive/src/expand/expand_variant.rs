@@ -3,6 +3,8 @@ use super::*;
 pub fn expand_variant(
     variant: &syn::Variant,
     rename_variants: RenameRule,
+    rename_fields: Option<RenameRule>,
+    discriminant: Option<i128>,
 ) -> Result<TokenStream2> {
     let syn::Variant {
         attrs,
@@ -14,16 +16,27 @@ pub fn expand_variant(
     let doku = attrs::DokuVariant::from_ast(attrs)?;
     let serde = attrs::SerdeVariant::from_ast(attrs)?;
 
-    let ident = rename_variants.apply_to_variant(&ident.to_string());
+    let title = ident.to_string();
+    let ident = rename_variants.apply_to_variant(&title);
 
-    let rename_fields =
-        doku.rename_all.or(serde.rename_all).unwrap_or_default();
+    let rename_fields = doku
+        .rename_all
+        .or(serde.rename_all)
+        .or(rename_fields)
+        .unwrap_or_default();
 
     let mut variant = Variant {
         id: quote! { #ident },
-        title: quote! { #ident },
-        comment: quote! { None },
-        fields: expand_fields(fields, rename_fields)?,
+        title: quote! { #title },
+        aliases: Vec::new(),
+        comment: None,
+        doc_append: Vec::new(),
+        since: quote! { None },
+        until: quote! { None },
+        other: false,
+        hidden: false,
+        discriminant: discriminant.map(|d| d.to_string()),
+        fields: expand_fields(fields, rename_fields, SortRule::Declaration)?,
         serializable: true,
         deserializable: true,
     };
@@ -38,7 +51,14 @@ pub fn expand_variant(
 struct Variant {
     id: TokenStream2,
     title: TokenStream2,
-    comment: TokenStream2,
+    aliases: Vec<syn::LitStr>,
+    comment: Option<String>,
+    doc_append: Vec<String>,
+    since: TokenStream2,
+    until: TokenStream2,
+    other: bool,
+    hidden: bool,
+    discriminant: Option<String>,
     fields: TokenStream2,
     serializable: bool,
     deserializable: bool,
@@ -46,16 +66,17 @@ struct Variant {
 
 impl Variant {
     fn add_doc_attrs(&mut self, attrs: &[syn::Attribute]) {
-        if let Some(comment) = attrs::Doc::from_ast(attrs).comment {
-            self.comment = quote! { Some(#comment) };
-        }
+        let doc = attrs::Doc::from_ast(attrs);
+
+        self.comment = doc.comment;
+        self.hidden = doc.hidden;
     }
 
     fn add_serde_attrs(&mut self, attrs: &[syn::Attribute]) -> Result<()> {
         let attrs::SerdeVariant {
-            alias: _,
+            alias,
             deserialize_with: _,
-            other: _,
+            other,
             rename,
             rename_all: _,
             serialize_with: _,
@@ -65,6 +86,12 @@ impl Variant {
             with: _,
         } = attrs::SerdeVariant::from_ast(attrs)?;
 
+        self.aliases.extend(alias);
+
+        if let Some(val) = other {
+            self.other = val;
+        }
+
         if let Some(val) = rename {
             self.id = quote_spanned! { val.span() => #val };
         }
@@ -87,20 +114,40 @@ impl Variant {
 
     fn add_doku_attrs(&mut self, attrs: &[syn::Attribute]) -> Result<()> {
         let attrs::DokuVariant {
+            comment,
+            doc_append,
+            example: _,
             rename,
             rename_all: _,
+            since,
             skip,
+            until,
         } = attrs::DokuVariant::from_ast(&attrs)?;
 
+        if let Some(val) = comment {
+            self.comment = Some(val.value());
+        }
+
+        self.doc_append
+            .extend(doc_append.into_iter().map(|val| val.value()));
+
         if let Some(val) = rename {
             self.id = quote_spanned! { val.span() => #val };
         }
 
+        if let Some(val) = since {
+            self.since = quote! { Some(#val) };
+        }
+
         if let Some(val) = skip {
             self.serializable = !val;
             self.deserializable = !val;
         }
 
+        if let Some(val) = until {
+            self.until = quote! { Some(#val) };
+        }
+
         Ok(())
     }
 
@@ -108,18 +155,39 @@ impl Variant {
         let Self {
             id,
             title,
+            aliases,
             comment,
+            doc_append,
+            since,
+            until,
+            other,
+            hidden,
+            discriminant,
             serializable,
             deserializable,
             fields,
         } = self;
 
         if serializable || deserializable {
+            let comment = expand_comment(comment, doc_append);
+
+            let discriminant = if let Some(discriminant) = discriminant {
+                quote! { Some(#discriminant) }
+            } else {
+                quote! { None }
+            };
+
             quote! {
                 ::doku::Variant {
                     id: #id,
                     title: #title,
+                    aliases: vec![ #(#aliases),* ],
                     comment: #comment,
+                    since: #since,
+                    until: #until,
+                    other: #other,
+                    hidden: #hidden,
+                    discriminant: #discriminant,
                     serializable: #serializable,
                     deserializable: #deserializable,
                     fields: #fields,
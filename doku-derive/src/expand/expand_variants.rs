@@ -3,9 +3,60 @@ use super::*;
 pub fn expand_variants(
     variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
     rename_variants: RenameRule,
+    rename_fields: Option<RenameRule>,
 ) -> Result<Vec<TokenStream2>> {
     variants
         .into_iter()
-        .map(|v| expand_variant(v, rename_variants))
+        .zip(discriminants(variants))
+        .map(|(v, discriminant)| {
+            expand_variant(v, rename_variants, rename_fields, discriminant)
+        })
         .collect()
 }
+
+/// Returns variants' discriminants - both explicit and implicit ones, as long
+/// as they can be determined (see: `parse_discriminant()`).
+pub fn discriminants(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+) -> Vec<Option<i128>> {
+    let mut discriminant = Some(0);
+
+    variants
+        .into_iter()
+        .map(|v| {
+            if let Some((_, expr)) = &v.discriminant {
+                discriminant = parse_discriminant(expr);
+            }
+
+            let current = discriminant;
+
+            discriminant = discriminant.and_then(|d| d.checked_add(1));
+            current
+        })
+        .collect()
+}
+
+/// Parses discriminant such as `123` or `-123`; more complex expressions (e.g.
+/// referring to constants) are not supported, since we'd have to evaluate
+/// them.
+fn parse_discriminant(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_discriminant(expr).map(|d: i128| -d),
+
+        syn::Expr::Group(syn::ExprGroup { expr, .. })
+        | syn::Expr::Paren(syn::ExprParen { expr, .. }) => {
+            parse_discriminant(expr)
+        }
+
+        _ => None,
+    }
+}
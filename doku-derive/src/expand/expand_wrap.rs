@@ -1,21 +1,64 @@
 use super::*;
 
+/// Wraps given type in a struct with a single field (think
+/// `#[doku(wrap = "field")]`).
+///
+/// Dotted paths (`#[doku(wrap = "outer.inner")]`) produce one struct per
+/// segment, while `#[doku(wrap_comment = "...")]` - specified once per segment,
+/// in the same order - provides comments for the wrapping fields.
 pub fn expand_wrap(
-    field_name: syn::LitStr,
-    field_type: TokenStream2,
-) -> TokenStream2 {
-    quote! {
-        let field = ::doku::Field {
-            ty: #field_type,
-            flattened: false,
+    path: syn::LitStr,
+    comments: Vec<syn::LitStr>,
+    mut ty: TokenStream2,
+) -> Result<TokenStream2> {
+    let path_value = path.value();
+    let segments: Vec<_> = path_value.split('.').collect();
+
+    if segments.iter().any(|segment| segment.is_empty()) {
+        return Err(syn::Error::new_spanned(
+            path,
+            "wrap path must not contain empty segments",
+        )
+        .into());
+    }
+
+    if comments.len() > segments.len() {
+        return Err(syn::Error::new_spanned(
+            &comments[segments.len()],
+            "there are more `wrap_comment`s than `wrap` segments",
+        )
+        .into());
+    }
+
+    for (segment_id, segment) in segments.into_iter().enumerate().rev() {
+        let comment = if let Some(comment) = comments.get(segment_id) {
+            quote! { Some(#comment) }
+        } else {
+            quote! { None }
         };
 
-        ::doku::Type::from(::doku::TypeKind::Struct {
-            fields: ::doku::Fields::Named {
-                fields: vec![ (#field_name, field) ],
-            },
+        ty = quote! {
+            let field = ::doku::Field {
+                ty: ::doku::Type {
+                    comment: #comment,
+                    ..{ #ty }
+                },
+                aliases: Vec::new(),
+                flattened: false,
+                section: None,
+            };
+
+            ::doku::Type::from(::doku::TypeKind::Struct {
+                fields: ::doku::Fields::Named {
+                    fields: vec![ (#segment, field) ],
+                },
 
-            transparent: false,
-        })
+                transparent: false,
+                deny_unknown_fields: false,
+                opaque: false,
+            })
+        };
     }
+
+    Ok(ty)
 }
@@ -1,21 +1,201 @@
 use crate::prelude::*;
+use syn::punctuated::Punctuated;
 
-pub fn new_generics_with_where_clause(
+/// Extends given generics with `T: Document` bounds for each type parameter,
+/// unless `bound` is specified - in which case, similarly to Serde, the bounds
+/// are taken from there instead.
+///
+/// Type parameters used by the fields only through their associated types
+/// (e.g. `opts: T::Options`) get `T::Options: Document` instead, since `T`
+/// itself doesn't have to be documentable then.
+pub fn new_generics_with_where_clause<'a>(
     generics: &syn::Generics,
+    bound: Option<syn::LitStr>,
+    fields: impl IntoIterator<Item = &'a syn::Field>,
 ) -> Result<syn::Generics> {
     let mut new_generics = generics.to_owned();
     let where_clause = new_generics.make_where_clause();
-    for generic in &generics.params {
-        match generic {
-            syn::GenericParam::Const(_) => (),
-            syn::GenericParam::Lifetime(_) => (),
-            syn::GenericParam::Type(t) => {
-                let t = &t.ident;
-                let predicate: syn::WherePredicate =
-                    syn::parse2(quote! { #t: ::doku::Document })?;
-                where_clause.predicates.push(predicate);
-            }
-        };
+
+    if let Some(bound) = bound {
+        let predicates = bound.parse_with(
+            Punctuated::<syn::WherePredicate, syn::Token![,]>::parse_terminated,
+        )?;
+
+        where_clause.predicates.extend(predicates);
+
+        return Ok(new_generics);
+    }
+
+    let mut usage = ParamsUsage {
+        params: generics.type_params().map(|param| &param.ident).collect(),
+        direct: Vec::new(),
+        associated: Vec::new(),
+    };
+
+    for field in fields {
+        usage.visit(&field.ty);
+    }
+
+    for param in &usage.params {
+        let used_only_through_associated_types = !usage.direct.contains(param)
+            && usage.associated.iter().any(|(p, _)| p == param);
+
+        if !used_only_through_associated_types {
+            let predicate: syn::WherePredicate =
+                syn::parse2(quote! { #param: ::doku::Document })?;
+            where_clause.predicates.push(predicate);
+        }
+    }
+
+    for (_, ty) in &usage.associated {
+        let predicate: syn::WherePredicate =
+            syn::parse2(quote! { #ty: ::doku::Document })?;
+        where_clause.predicates.push(predicate);
     }
+
     Ok(new_generics)
 }
+
+/// Keeps track of how type parameters are referred to by fields - either
+/// directly (`T`, `Vec<T>`) or through associated types (`T::Options`,
+/// `<T as Backend>::Options`).
+struct ParamsUsage<'a> {
+    params: Vec<&'a syn::Ident>,
+    direct: Vec<&'a syn::Ident>,
+    associated: Vec<(&'a syn::Ident, &'a syn::Type)>,
+}
+
+impl<'a> ParamsUsage<'a> {
+    fn visit(&mut self, ty: &'a syn::Type) {
+        match ty {
+            syn::Type::Array(syn::TypeArray { elem, .. })
+            | syn::Type::Group(syn::TypeGroup { elem, .. })
+            | syn::Type::Paren(syn::TypeParen { elem, .. })
+            | syn::Type::Ptr(syn::TypePtr { elem, .. })
+            | syn::Type::Reference(syn::TypeReference { elem, .. })
+            | syn::Type::Slice(syn::TypeSlice { elem, .. }) => {
+                self.visit(elem);
+            }
+
+            syn::Type::Tuple(syn::TypeTuple { elems, .. }) => {
+                for elem in elems {
+                    self.visit(elem);
+                }
+            }
+
+            syn::Type::Path(syn::TypePath { qself, path }) => {
+                if let Some(qself) = qself {
+                    if let Some(param) = self.find_param(&qself.ty) {
+                        self.add_associated(param, ty);
+                        return;
+                    }
+
+                    self.visit(&qself.ty);
+                } else if path.leading_colon.is_none() {
+                    let first = &path.segments[0].ident;
+
+                    if let Some(param) =
+                        self.params.iter().copied().find(|p| *p == first)
+                    {
+                        if path.segments.len() == 1 {
+                            self.direct.push(param);
+                        } else {
+                            self.add_associated(param, ty);
+                        }
+
+                        return;
+                    }
+                }
+
+                for segment in &path.segments {
+                    if let syn::PathArguments::AngleBracketed(args) =
+                        &segment.arguments
+                    {
+                        for arg in &args.args {
+                            match arg {
+                                syn::GenericArgument::Type(ty)
+                                | syn::GenericArgument::Binding(
+                                    syn::Binding { ty, .. },
+                                ) => {
+                                    self.visit(ty);
+                                }
+
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    /// Returns the type parameter that given type refers to, if it's just a
+    /// plain `T`.
+    fn find_param(&self, ty: &syn::Type) -> Option<&'a syn::Ident> {
+        if let syn::Type::Path(syn::TypePath { qself: None, path }) = ty {
+            let ident = path.get_ident()?;
+
+            self.params.iter().copied().find(|param| *param == ident)
+        } else {
+            None
+        }
+    }
+
+    fn add_associated(&mut self, param: &'a syn::Ident, ty: &'a syn::Type) {
+        if !self.associated.iter().any(|(_, t)| *t == ty) {
+            self.associated.push((param, ty));
+        }
+    }
+}
+
+/// Expands container's doc-comment into `Type::header`.
+pub fn expand_header(
+    attrs: &[syn::Attribute],
+    ty: TokenStream2,
+) -> TokenStream2 {
+    if let Some(header) = attrs::Doc::from_ast(attrs).comment {
+        quote! {
+            let mut ty = { #ty };
+            ty.header = Some(#header);
+            ty
+        }
+    } else {
+        ty
+    }
+}
+
+/// Expands container's name and `#[doku(inline)]` into `Type::name` and
+/// `Type::inline`.
+pub fn expand_name(inline: Option<bool>, ty: TokenStream2) -> TokenStream2 {
+    let inline = inline.unwrap_or(true);
+
+    quote! {
+        let mut ty = { #ty };
+        ty.name = Some(::std::any::type_name::<Self>());
+        ty.inline = #inline;
+        ty
+    }
+}
+
+/// Expands the final comment of a field or variant - that is: its doc-comment
+/// (or `#[doku(comment = "...")]`, if present) followed by all of the lines
+/// from `#[doku(doc_append = "...")]`.
+pub fn expand_comment(
+    comment: Option<String>,
+    doc_append: Vec<String>,
+) -> TokenStream2 {
+    let comment: Vec<_> = comment
+        .into_iter()
+        .chain(doc_append)
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+
+    if comment.is_empty() {
+        quote! { None }
+    } else {
+        let comment = comment.join("\n");
+        quote! { Some(#comment) }
+    }
+}
@@ -5,7 +5,7 @@ mod utils;
 
 mod prelude {
     pub(crate) use super::{
-        attrs::{self, RenameRule},
+        attrs::{self, RenameRule, SerdeBound, SortRule},
         error::*,
         utils::*,
     };
@@ -20,11 +20,15 @@ impl doku::Document for Paper {
                     "size",
                     doku::Field {
                         ty: String::ty(),
+                        aliases: Vec::new(),
                         flattened: false,
+                        section: None,
                     },
                 )],
             },
             transparent: false,
+            deny_unknown_fields: false,
+            opaque: false,
         })
     }
 }
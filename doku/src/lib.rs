@@ -224,14 +224,15 @@
 //! - ❌ `#[serde(rename_all(serialize = "..."))]`
 //! - ❌ `#[serde(rename_all(deserialize = "..."))]`
 //! - ❌ `#[serde(rename_all(serialize = "...", deserialize = "..."))]`
-//! - ✅ `#[serde(deny_unknown_fields)]` (no-op)
+//! - ✅ `#[serde(deny_unknown_fields)]`
 //! - ✅ `#[serde(tag = "...")]`
 //! - ✅ `#[serde(tag = "...", content = "...")]`
 //! - ✅ `#[serde(untagged)]`
-//! - ❌ `#[serde(bound = "...")]`
-//! - ❌ `#[serde(bound(serialize = "..."))]`
-//! - ❌ `#[serde(bound(deserialize = "..."))]`
-//! - ❌ `#[serde(bound(serialize = "...", deserialize = "..."))]`
+//! - ✅ `#[serde(rename_all_fields = "...")]`
+//! - ✅ `#[serde(bound = "...")]` (no-op)
+//! - ✅ `#[serde(bound(serialize = "..."))]` (no-op)
+//! - ✅ `#[serde(bound(deserialize = "..."))]` (no-op)
+//! - ✅ `#[serde(bound(serialize = "...", deserialize = "..."))]` (no-op)
 //! - ✅ `#[serde(default)]` (no-op)
 //! - ✅ `#[serde(default = "...")]` (no-op)
 //! - ❌ `#[serde(remote = "...")]`
@@ -258,7 +259,7 @@
 //! - ❌ `#[serde(bound = "...")]`
 //! - ❌ `#[serde(borrow)]`
 //! - ❌ `#[serde(borrow = "...")]`
-//! - ✅ `#[serde(other)]` (no-op)
+//! - ✅ `#[serde(other)]`
 //!
 //! `#[serde]` for [fields](https://serde.rs/field-attrs.html):
 //!
@@ -313,7 +314,9 @@
 //!                 example: Some(doku::Example::Simple("alan.turing")),
 //!                 ..String::ty()
 //!             },
+//!             aliases: Vec::new(),
 //!             flattened: false,
+//!             section: None,
 //!         };
 //!
 //!         doku::Type::from(doku::TypeKind::Struct {
@@ -323,6 +326,8 @@
 //!                 ],
 //!             },
 //!             transparent: false,
+//!             deny_unknown_fields: false,
+//!             opaque: false,
 //!         })
 //!     }
 //! }
@@ -433,6 +438,148 @@ where
         .print(&T::ty())
 }
 
+/// Generates a JSON documentation for specified type using custom formatting
+/// settings, after rewriting the type with given transformation.
+///
+/// This is useful e.g. to hide fields that are relevant only to some of the
+/// deployments, without having to maintain separate structs.
+///
+/// # Example
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Database's host
+///     db_host: String,
+///
+///     /// Tenant's identifier
+///     tenant_id: String,
+/// }
+///
+/// let fmt = doku::json::Formatting::default();
+///
+/// let doc = doku::to_json_fmt_with::<Config, _>(&fmt, |ty| {
+///     if let Some(fields) = ty.named_fields_mut() {
+///         fields.retain(|(name, _)| *name != "tenant_id");
+///     }
+/// });
+///
+/// doku::assert_doc!(r#"
+///   {
+///     // Database's host
+///     "db_host": "string"
+///   }
+/// "#, doc);
+/// ```
+///
+/// For more details, please see: [`Type::transform()`].
+pub fn to_json_fmt_with<T, F>(
+    fmt: &json::Formatting,
+    mut transform: F,
+) -> String
+where
+    T: Document,
+    F: FnMut(&mut Type),
+{
+    let mut ty = T::ty();
+
+    ty.transform(&mut transform);
+
+    json::Printer::default().with_formatting(fmt).print(&ty)
+}
+
+/// Generates a JSON documentation for just a part of specified type, addressed
+/// by given dotted path; returns `None` if there's nothing at this path.
+///
+/// The documentation starts with the addressed field's doc-comment, followed
+/// by the doc-comment of the field's type (if it's a struct or an enum).
+///
+/// # Example
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Database's configuration
+///     db: Db,
+/// }
+///
+/// /// Supports PostgreSQL only
+/// #[derive(Document)]
+/// struct Db {
+///     /// Database's host
+///     host: String,
+/// }
+///
+/// let doc = doku::to_json_at::<Config>("db").unwrap();
+///
+/// doku::assert_doc!(r#"
+///   // Supports PostgreSQL only
+///
+///   // Database's configuration
+///   {
+///     // Database's host
+///     "host": "string"
+///   }
+/// "#, doc);
+///
+/// assert!(doku::to_json_at::<Config>("db.port").is_none());
+/// ```
+///
+/// For more details, please see: [`Type::at()`].
+pub fn to_json_at<T>(path: &str) -> Option<String>
+where
+    T: Document,
+{
+    let fmt =
+        json::Formatting::default().with_doc_header(json::DocHeader::Visible);
+
+    to_json_fmt_at::<T>(&fmt, path)
+}
+
+/// Generates a JSON documentation for just a part of specified type using
+/// custom formatting settings - see: [`to_json_at()`].
+pub fn to_json_fmt_at<T>(fmt: &json::Formatting, path: &str) -> Option<String>
+where
+    T: Document,
+{
+    let ty = T::ty().at(path)?;
+
+    Some(json::Printer::default().with_formatting(fmt).print(&ty))
+}
+
+/// Returns doc-comments and hints of all the fields of specified type, without
+/// rendering the document.
+///
+/// # Example
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Database's port
+///     db_port: Option<u16>,
+/// }
+///
+/// let comments = doku::comments::<Config>();
+///
+/// assert_eq!("db_port", comments[0].0);
+/// assert_eq!(Some("Database's port"), comments[0].1.comment);
+/// assert_eq!(vec!["Optional"], comments[0].1.hints);
+/// ```
+///
+/// For more details, please see: [`json::Printer::comments()`].
+pub fn comments<T>() -> Vec<(String, json::CommentInfo)>
+where
+    T: Document,
+{
+    json::Printer::default().comments(&T::ty())
+}
+
 /// Generates a JSON documentation for specified type, extracting example values
 /// from given serializable object.
 ///
@@ -527,3 +674,233 @@ where
         .with_value(&Value::from(val))
         .print(&T::ty())
 }
+
+/// Generates a JSON documentation for specified type, extracting values from
+/// given serializable object that might be missing some of the fields (e.g.
+/// user's current configuration loaded as `serde_json::Value`).
+///
+/// Fields present in the object get printed as they are, while the missing
+/// ones get printed using their examples and marked as unset.
+///
+/// # Example
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Database's host
+///     db_host: String,
+///
+///     /// Database's port
+///     #[doku(example = "5432")]
+///     db_port: u16,
+/// }
+///
+/// let config = serde_json::json!({
+///     "db_host": "localhost",
+/// });
+///
+/// let doc = doku::to_json_partial_val::<Config, _>(&config);
+///
+/// doku::assert_doc!(r#"
+///   {
+///     // Database's host
+///     "db_host": "localhost",
+///     // Database's port; unset, default shown
+///     "db_port": 5432
+///   }
+/// "#, doc);
+/// ```
+///
+/// For more control over the output format, please see:
+/// [`to_json_fmt_partial_val()`].
+pub fn to_json_partial_val<T, V>(val: &V) -> String
+where
+    T: Document,
+    V: Serialize,
+{
+    json::Printer::default()
+        .with_partial_value(&Value::from(val))
+        .print(&T::ty())
+}
+
+/// Generates a JSON documentation for specified type using custom formatting
+/// settings, and extracting values from given serializable object that might
+/// be missing some of the fields - see: [`to_json_partial_val()`].
+pub fn to_json_fmt_partial_val<T, V>(fmt: &json::Formatting, val: &V) -> String
+where
+    T: Document,
+    V: Serialize,
+{
+    json::Printer::default()
+        .with_formatting(fmt)
+        .with_partial_value(&Value::from(val))
+        .print(&T::ty())
+}
+
+/// Generates a JSON documentation for specified type, showing differences
+/// between two versions of a value (e.g. user's configuration before and
+/// after an upgrade).
+///
+/// The document shows the new value, with fields that have been added,
+/// removed or changed marked as such; keys that don't correspond to any of the
+/// fields get listed as unknown.
+///
+/// # Example
+///
+/// ```
+/// use doku::{Document, Value};
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Database's host
+///     db_host: String,
+///
+///     /// Database's port
+///     db_port: u16,
+///
+///     /// Database's password
+///     db_password: Option<String>,
+/// }
+///
+/// let old = Value::from(&serde_json::json!({
+///     "db_host": "localhost",
+///     "db_port": 5432,
+///     "db_user": "root",
+/// }));
+///
+/// let new = Value::from(&serde_json::json!({
+///     "db_host": "db.example.com",
+///     "db_port": 5432,
+///     "db_password": "hunter2",
+///     "db_user": "root",
+/// }));
+///
+/// let doc = doku::diff::<Config>(&old, &new);
+///
+/// doku::assert_doc!(r#"
+///   {
+///     // Database's host; changed from "localhost"
+///     "db_host": "db.example.com",
+///     // Database's port
+///     "db_port": 5432,
+///     // Database's password; added; optional
+///     "db_password": "hunter2"
+///     // Unknown key: db_user
+///   }
+/// "#, doc);
+/// ```
+///
+/// For more control over the output format, please see: [`diff_fmt()`].
+pub fn diff<T>(old: &Value, new: &Value) -> String
+where
+    T: Document,
+{
+    json::Printer::default()
+        .with_value(new)
+        .with_old_value(old)
+        .print(&T::ty())
+}
+
+/// Generates a JSON documentation for specified type using custom formatting
+/// settings, showing differences between two versions of a value - see:
+/// [`diff()`].
+pub fn diff_fmt<T>(fmt: &json::Formatting, old: &Value, new: &Value) -> String
+where
+    T: Document,
+{
+    json::Printer::default()
+        .with_formatting(fmt)
+        .with_value(new)
+        .with_old_value(old)
+        .print(&T::ty())
+}
+
+/// Checks given value (e.g. a configuration file parsed into
+/// `serde_json::Value`) against specified type, reporting unknown keys,
+/// deprecated fields and type mismatches.
+///
+/// # Example
+///
+/// ```
+/// use doku::{Document, LintKind, Value};
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Database's port
+///     db_port: u16,
+/// }
+///
+/// let val = Value::from(&serde_json::json!({
+///     "db_port": true,
+/// }));
+///
+/// let lints = doku::lint::<Config>(&val);
+///
+/// assert_eq!(1, lints.len());
+/// assert_eq!("db_port", lints[0].path);
+/// assert_eq!(Some("Database's port"), lints[0].comment);
+///
+/// assert_eq!(
+///     LintKind::TypeMismatch {
+///         expected: "an integer",
+///         found: "a boolean",
+///     },
+///     lints[0].kind,
+/// );
+///
+/// assert_eq!("db_port: expected an integer, found a boolean", lints[0].to_string());
+/// ```
+///
+/// For more details, please see: [`Type::lint()`].
+pub fn lint<T>(val: &Value) -> Vec<Lint>
+where
+    T: Document,
+{
+    T::ty().lint(val)
+}
+
+/// Checks given value against constraints of its type (e.g. `#[doku(min =
+/// 1)]`), returning the violations found.
+///
+/// # Example
+///
+/// ```
+/// use doku::{Constraint, Document};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize, Document)]
+/// struct Config {
+///     /// Number of workers to spawn
+///     #[doku(min = 1, max = 16)]
+///     workers: usize,
+/// }
+///
+/// let violations = doku::validate(&Config { workers: 32 });
+///
+/// assert_eq!(1, violations.len());
+/// assert_eq!("workers", violations[0].path);
+/// assert_eq!(Some("Number of workers to spawn"), violations[0].comment);
+///
+/// assert_eq!(
+///     Constraint::Range {
+///         min: Some("1"),
+///         max: Some("16"),
+///     },
+///     violations[0].constraint,
+/// );
+///
+/// assert_eq!(
+///     "workers: must be between 1 and 16",
+///     violations[0].to_string(),
+/// );
+/// ```
+///
+/// For more details, please see: [`Type::validate()`].
+pub fn validate<T>(val: &T) -> Vec<Violation>
+where
+    T: Document + Serialize,
+{
+    T::ty().validate(&Value::from(val))
+}
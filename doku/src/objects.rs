@@ -1,15 +1,34 @@
+mod comment_block;
+mod constraint;
 mod document;
 mod example;
 mod field;
 mod fields;
+#[cfg(feature = "deserialize")]
+mod leak;
+mod lint;
+mod location;
 mod meta;
+mod optionality;
 mod tag;
 mod r#type;
+mod type_builder;
 mod type_kind;
+mod type_query;
+mod type_registry;
+mod type_transform;
+mod validate;
 mod value;
 mod variant;
+mod visitor;
+mod walker;
 
 pub use self::{
-    document::*, example::*, field::*, fields::*, meta::*, r#type::*, tag::*,
-    type_kind::*, value::*, variant::*,
+    comment_block::*, constraint::*, document::*, example::*, field::*,
+    fields::*, lint::*, location::*, meta::*, optionality::*, r#type::*,
+    tag::*, type_kind::*, type_registry::*, type_transform::*, validate::*,
+    value::*, variant::*, visitor::*, walker::*,
 };
+
+#[cfg(feature = "deserialize")]
+pub(crate) use self::leak::{from_json, leak, unresolved_reference};
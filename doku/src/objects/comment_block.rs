@@ -0,0 +1,172 @@
+use std::iter::Peekable;
+use std::str::Lines;
+
+/// A single block of a doc-comment, e.g. a paragraph or a code block.
+///
+/// Doc-comments are stored as written (see: `Type::comment`), but printers
+/// that want to render them in a more structured way can parse them into
+/// blocks:
+///
+/// ```
+/// use doku::CommentBlock;
+///
+/// let blocks = CommentBlock::parse(
+///     "Address to listen at;\nsupports:\n\n- IPv4,\n- IPv6\n\n```\n127.0.0.1\n```",
+/// );
+///
+/// assert_eq!(
+///     vec![
+///         CommentBlock::Paragraph("Address to listen at; supports:".into()),
+///         CommentBlock::List {
+///             ordered: false,
+///             items: vec!["IPv4,".into(), "IPv6".into()],
+///         },
+///         CommentBlock::Code {
+///             lang: None,
+///             code: "127.0.0.1".into(),
+///         },
+///     ],
+///     blocks,
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommentBlock {
+    /// A paragraph of text, with its lines joined with spaces
+    Paragraph(String),
+
+    /// A fenced (```` ``` ````) or indented code block, kept verbatim
+    Code {
+        /// Language of the code, e.g. `toml` for ```` ```toml ````
+        lang: Option<String>,
+        code: String,
+    },
+
+    /// A bulleted (`-`, `*`, `+`) or a numbered (`1.`) list, with each item's
+    /// lines joined with spaces
+    List { ordered: bool, items: Vec<String> },
+}
+
+impl CommentBlock {
+    pub fn parse(comment: &str) -> Vec<Self> {
+        let mut blocks = Vec::new();
+        let mut lines = comment.lines().peekable();
+
+        // Whether the last block can be continued by the following line (i.e.
+        // whether we're in the middle of a paragraph or a list)
+        let mut open = false;
+
+        while let Some(line) = lines.next() {
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() {
+                open = false;
+                continue;
+            }
+
+            if let Some(lang) = trimmed.strip_prefix("```") {
+                let code: Vec<_> = lines
+                    .by_ref()
+                    .take_while(|line| !line.trim_start().starts_with("```"))
+                    .collect();
+
+                blocks.push(Self::Code {
+                    lang: Some(lang.trim())
+                        .filter(|lang| !lang.is_empty())
+                        .map(ToOwned::to_owned),
+                    code: code.join("\n"),
+                });
+
+                open = false;
+                continue;
+            }
+
+            if !open {
+                if let Some(line) = strip_code_indent(line) {
+                    blocks.push(Self::Code {
+                        lang: None,
+                        code: parse_indented_code(line, &mut lines),
+                    });
+
+                    continue;
+                }
+            }
+
+            if let Some((ordered, item)) = parse_list_item(trimmed) {
+                match blocks.last_mut() {
+                    Some(Self::List {
+                        ordered: prev_ordered,
+                        items,
+                    }) if open && *prev_ordered == ordered => {
+                        items.push(item.to_owned());
+                    }
+
+                    _ => {
+                        blocks.push(Self::List {
+                            ordered,
+                            items: vec![item.to_owned()],
+                        });
+                    }
+                }
+
+                open = true;
+                continue;
+            }
+
+            let text = match blocks.last_mut() {
+                Some(Self::Paragraph(text)) if open => text,
+
+                Some(Self::List { items, .. }) if open => {
+                    items.last_mut().expect("lists are never empty")
+                }
+
+                _ => {
+                    blocks.push(Self::Paragraph(trimmed.to_owned()));
+                    open = true;
+                    continue;
+                }
+            };
+
+            text.push(' ');
+            text.push_str(trimmed);
+        }
+
+        blocks
+    }
+}
+
+fn strip_code_indent(line: &str) -> Option<&str> {
+    line.strip_prefix("    ")
+        .or_else(|| line.strip_prefix('\t'))
+}
+
+fn parse_indented_code(first: &str, lines: &mut Peekable<Lines<'_>>) -> String {
+    let mut code = vec![first];
+
+    while let Some(line) = lines.peek().and_then(|line| strip_code_indent(line))
+    {
+        code.push(line);
+        lines.next();
+    }
+
+    code.join("\n")
+}
+
+/// Parses `- item` / `* item` / `+ item` / `1. item`, returning whether the
+/// list is ordered and the item itself.
+fn parse_list_item(line: &str) -> Option<(bool, &str)> {
+    for bullet in &["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(bullet) {
+            return Some((false, item.trim_start()));
+        }
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+    if digits > 0 {
+        if let Some(item) = line[digits..].strip_prefix(". ") {
+            return Some((true, item.trim_start()));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "deserialize")]
+use serde::Deserialize;
+use serde::Serialize;
+use std::mem;
+
+/// A machine-readable restriction on the values a type accepts; printers
+/// render these as hints (e.g. `// Must match pattern: ...`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+pub enum Constraint {
+    /// Length of the value - number of characters for strings, number of
+    /// elements for arrays (think `#[doku(min_length = 1, max_length = 64)]`)
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+
+    /// Regular expression the value has to match (think
+    /// `#[doku(pattern = "^[a-z0-9-]+$")]`)
+    Pattern(&'static str),
+
+    /// Inclusive range of a number (think `#[doku(min = 1, max = 65535)]`);
+    /// bounds are kept verbatim, as written in the code
+    Range {
+        min: Option<&'static str>,
+        max: Option<&'static str>,
+    },
+
+    /// Number the value has to be a multiple of (think
+    /// `#[doku(multiple_of = 5)]`); kept verbatim, as written in the code
+    MultipleOf(&'static str),
+
+    /// Values the field accepts (think
+    /// `#[doku(one_of = "debug", one_of = "info")]`)
+    OneOf(Vec<&'static str>),
+}
+
+impl Constraint {
+    /// Merges two sets of constraints, with constraints from `overrides`
+    /// replacing the ones of the same kind from `constraints`.
+    ///
+    /// Used by `#[derive(Document)]` to combine constraints of a field with the
+    /// ones of its type.
+    pub fn merge(
+        overrides: Vec<Constraint>,
+        constraints: Vec<Constraint>,
+    ) -> Vec<Constraint> {
+        let mut merged = overrides;
+
+        for constraint in constraints {
+            let overridden = merged.iter().any(|c| {
+                mem::discriminant(c) == mem::discriminant(&constraint)
+            });
+
+            if !overridden {
+                merged.push(constraint);
+            }
+        }
+
+        merged
+    }
+}
@@ -1,4 +1,6 @@
 use crate::*;
+use ::std::any;
+use ::std::cell::RefCell;
 
 /// A type that's understandable by Doku.
 ///
@@ -14,6 +16,77 @@ pub trait Document {
     fn ty() -> Type;
 }
 
+/// A type that's documented as a map (e.g. `HashMap`), possibly wrapped in an
+/// `Option`; required by `#[doku(key_example)]` and `#[doku(value_example)]`:
+///
+/// ```compile_fail
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     #[doku(key_example = "my-service")]
+///     services: Vec<String>,
+/// }
+/// ```
+///
+/// Used by `#[derive(Document)]`; not a part of the public API.
+#[doc(hidden)]
+pub trait DocumentMap: Document {}
+
+/// Fails to compile unless `T` is documented as a map - see: `DocumentMap`.
+///
+/// Used by `#[derive(Document)]`; not a part of the public API.
+#[doc(hidden)]
+pub fn assert_map<T: DocumentMap + ?Sized>() {}
+
+thread_local! {
+    static TYPES_IN_PROGRESS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+/// Builds `T`'s type using given function, unless `T` is already being built
+/// further up the stack (i.e. `T` is recursive) - in which case a
+/// `TypeKind::Reference` is returned instead.
+///
+/// Used by `#[derive(Document)]`; not a part of the public API.
+#[doc(hidden)]
+pub fn ty_guarded<T, F>(ty: F) -> Type
+where
+    T: Document,
+    F: FnOnce() -> Type,
+{
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            TYPES_IN_PROGRESS.with(|types| types.borrow_mut().pop());
+        }
+    }
+
+    let name = any::type_name::<T>();
+
+    let recursive = TYPES_IN_PROGRESS.with(|types| {
+        let mut types = types.borrow_mut();
+
+        if types.contains(&name) {
+            true
+        } else {
+            types.push(name);
+            false
+        }
+    });
+
+    if recursive {
+        return Type {
+            name: Some(name),
+            ..Type::from(TypeKind::Reference { name, ty: T::ty })
+        };
+    }
+
+    let _guard = Guard;
+
+    ty()
+}
+
 macro_rules! document {
     (
         $(
@@ -37,8 +110,53 @@ macro_rules! document {
 mod lang;
 mod std;
 
+#[cfg(feature = "arrayvec-07")]
+mod arrayvec_07;
+
+#[cfg(feature = "bigdecimal-04")]
+mod bigdecimal_04;
+
+#[cfg(feature = "bytes-1")]
+mod bytes_1;
+
+#[cfg(feature = "camino-1")]
+mod camino_1;
+
 #[cfg(feature = "chrono-04")]
 mod chrono_04;
 
+#[cfg(feature = "either-1")]
+mod either_1;
+
+#[cfg(feature = "heapless-07")]
+mod heapless_07;
+
+#[cfg(feature = "indexmap-1")]
+mod indexmap_1;
+
+#[cfg(feature = "ordered-float-4")]
+mod ordered_float_4;
+
+#[cfg(feature = "rust-decimal-1")]
+mod rust_decimal_1;
+
+#[cfg(feature = "secrecy-08")]
+mod secrecy_08;
+
+#[cfg(feature = "semver-1")]
+mod semver_1;
+
+#[cfg(feature = "serde-json-1")]
+mod serde_json_1;
+
+#[cfg(feature = "smallvec-1")]
+mod smallvec_1;
+
+#[cfg(feature = "time-03")]
+mod time_03;
+
 #[cfg(feature = "url-2")]
 mod url_2;
+
+#[cfg(feature = "uuid-1")]
+mod uuid_1;
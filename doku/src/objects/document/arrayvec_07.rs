@@ -0,0 +1,18 @@
+use crate::*;
+use arrayvec::{ArrayString, ArrayVec};
+
+document! {
+    for ArrayVec<T, CAP> where (T: Document, const CAP: usize)
+        => <&[T]>::ty().constraint(Constraint::Length {
+            min: None,
+            max: Some(CAP),
+        });
+
+    // `CAP` is in bytes, so for non-ASCII strings the limit is stricter than
+    // the hint suggests - but it's still a good approximation
+    for ArrayString<CAP> where (const CAP: usize)
+        => String::ty().constraint(Constraint::Length {
+            min: None,
+            max: Some(CAP),
+        });
+}
@@ -0,0 +1,7 @@
+use crate::*;
+use bigdecimal::BigDecimal;
+
+document! {
+    for BigDecimal
+        => TypeKind::Decimal { digits: None }.into();
+}
@@ -0,0 +1,12 @@
+use crate::*;
+use bytes::{Bytes, BytesMut};
+
+document! {
+    // Serde serializes these as arrays of integers, same as `Vec<u8>` - fields
+    // encoded in other ways (e.g. as base64) can use `#[doku(bytes)]`
+    for Bytes
+        => <&[u8]>::ty();
+
+    for BytesMut
+        => <&[u8]>::ty();
+}
@@ -0,0 +1,10 @@
+use crate::*;
+use camino::{Utf8Path, Utf8PathBuf};
+
+document! {
+    for Utf8Path
+        => TypeKind::Path.into();
+
+    for Utf8PathBuf
+        => TypeKind::Path.into();
+}
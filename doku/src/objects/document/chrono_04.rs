@@ -1,10 +1,28 @@
 use crate::*;
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
 
 document! {
     for DateTime<Tz> where (Tz) { Tz: TimeZone }
         => Type {
             example: Some(Example::Simple("2018-04-05T11:44:42.621513958Z")),
-            ..String::ty()
+            ..TypeKind::DateTime { offset: true }.into()
+        };
+
+    for NaiveDateTime
+        => Type {
+            example: Some(Example::Simple("2018-04-05T11:44:42.621513958")),
+            ..TypeKind::DateTime { offset: false }.into()
+        };
+
+    for NaiveDate
+        => Type {
+            example: Some(Example::Simple("2018-04-05")),
+            ..TypeKind::Date.into()
+        };
+
+    for NaiveTime
+        => Type {
+            example: Some(Example::Simple("11:44:42.621513958")),
+            ..TypeKind::Time.into()
         };
 }
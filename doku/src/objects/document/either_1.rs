@@ -0,0 +1,20 @@
+use super::std::{newtype, variant};
+use crate::*;
+use either::Either;
+
+document! {
+    // Either's own `Serialize` is externally tagged, but since it's meant to
+    // be "one of two shapes", it's pretty much always serialized through
+    // `either::serde_untagged` - fields that use the default representation
+    // can be documented with `#[doku(as = "...")]`
+    for Either<L, R> where (L: Document, R: Document)
+        => TypeKind::Enum {
+            tag: Tag::None,
+            variants: vec![
+                variant("Left", newtype(L::ty())),
+                variant("Right", newtype(R::ty())),
+            ],
+            example_variant: None,
+            repr: false,
+        }.into();
+}
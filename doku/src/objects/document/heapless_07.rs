@@ -0,0 +1,17 @@
+use crate::*;
+
+document! {
+    for heapless::Vec<T, N> where (T: Document, const N: usize)
+        => <&[T]>::ty().constraint(Constraint::Length {
+            min: None,
+            max: Some(N),
+        });
+
+    // `N` is in bytes, so for non-ASCII strings the limit is stricter than
+    // the hint suggests - but it's still a good approximation
+    for heapless::String<N> where (const N: usize)
+        => String::ty().constraint(Constraint::Length {
+            min: None,
+            max: Some(N),
+        });
+}
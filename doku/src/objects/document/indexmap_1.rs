@@ -0,0 +1,12 @@
+use crate::*;
+use indexmap::{IndexMap, IndexSet};
+
+document! {
+    for IndexSet<T> where (T: Document)
+        => <&[T]>::ty();
+
+    for IndexMap<K, V> where (K: Document, V: Document)
+        => <::std::collections::HashMap<K, V>>::ty();
+}
+
+impl<K: Document, V: Document> DocumentMap for IndexMap<K, V> {}
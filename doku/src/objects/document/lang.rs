@@ -16,46 +16,46 @@ document! {
         => TypeKind::String.into();
 
     for f32
-        => TypeKind::Float.into();
+        => TypeKind::Float { bits: 32 }.into();
 
     for f64
-        => TypeKind::Float.into();
+        => TypeKind::Float { bits: 64 }.into();
 
     for u8
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 8, signed: false }.into();
 
     for i8
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 8, signed: true }.into();
 
     for u16
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 16, signed: false }.into();
 
     for i16
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 16, signed: true }.into();
 
     for u32
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 32, signed: false }.into();
 
     for i32
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 32, signed: true }.into();
 
     for u64
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 64, signed: false }.into();
 
     for i64
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 64, signed: true }.into();
 
     for u128
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 128, signed: false }.into();
 
     for i128
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: 128, signed: true }.into();
 
     for usize
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: pointer_bits(), signed: false }.into();
 
     for isize
-        => TypeKind::Integer.into();
+        => TypeKind::Integer { bits: pointer_bits(), signed: true }.into();
 
     /* ----- */
 
@@ -73,15 +73,13 @@ document! {
             size: Some(N),
         }.into();
 
-    for &[T] where (T: Document)
+    // Covers `&[T]`, `Box<[T]>` etc. as well, through their forwarding impls
+    for [T] where (T: Document)
         => TypeKind::Array {
             ty: Box::new(T::ty()),
             size: None,
         }.into();
 
-    for &mut [T] where (T: Document)
-        => <&[T]>::ty();
-
     /* ----- */
 
     for (A,) where (A: Document)
@@ -134,3 +132,9 @@ document! {
             fields: vec![A::ty(), B::ty(), C::ty(), D::ty(), E::ty(), F::ty(), G::ty(), H::ty(), I::ty(), J::ty()],
         }.into();
 }
+
+impl<T: DocumentMap + ?Sized> DocumentMap for &T {}
+
+fn pointer_bits() -> u8 {
+    (::std::mem::size_of::<usize>() * 8) as u8
+}
@@ -0,0 +1,10 @@
+use crate::*;
+use ordered_float::{NotNan, OrderedFloat};
+
+document! {
+    for OrderedFloat<T> where (T: Document)
+        => T::ty();
+
+    for NotNan<T> where (T: Document)
+        => T::ty();
+}
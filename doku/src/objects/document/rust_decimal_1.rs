@@ -0,0 +1,9 @@
+use crate::*;
+use rust_decimal::Decimal;
+
+document! {
+    // 96-bit mantissa, i.e. 28 digits that are always representable (29th
+    // one only sometimes)
+    for Decimal
+        => TypeKind::Decimal { digits: Some(28) }.into();
+}
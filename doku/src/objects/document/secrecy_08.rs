@@ -0,0 +1,9 @@
+use crate::*;
+use secrecy::{Secret, Zeroize};
+
+document! {
+    // Covers `SecretString`, `SecretVec` etc. as well, since they are just
+    // aliases
+    for Secret<T> where (T: Document) { T: Zeroize }
+        => T::ty().sensitive();
+}
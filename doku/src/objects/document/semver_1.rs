@@ -0,0 +1,16 @@
+use crate::*;
+use semver::{Version, VersionReq};
+
+document! {
+    for Version
+        => Type {
+            example: Some(Example::Simple("1.2.3")),
+            ..TypeKind::String.into()
+        };
+
+    for VersionReq
+        => Type {
+            example: Some(Example::Simple(">=1.2, <2")),
+            ..TypeKind::String.into()
+        };
+}
@@ -0,0 +1,17 @@
+use crate::*;
+use serde_json::{Map, Number, Value};
+
+document! {
+    for Value
+        => TypeKind::Any.into();
+
+    for Map<String, Value>
+        => <::std::collections::HashMap<String, Value>>::ty();
+
+    // Numbers without `arbitrary_precision` are stored as `f64`, `i64` or
+    // `u64`, so it's the closest match
+    for Number
+        => TypeKind::Float { bits: 64 }.into();
+}
+
+impl DocumentMap for Map<String, Value> {}
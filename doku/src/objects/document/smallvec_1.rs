@@ -0,0 +1,9 @@
+use crate::*;
+use smallvec::{Array, SmallVec};
+
+document! {
+    // Contrary to e.g. `ArrayVec`, `SmallVec` grows past its inline capacity,
+    // so there's no limit to document
+    for SmallVec<A> where (A: Array) { A::Item: Document }
+        => <&[A::Item]>::ty();
+}
@@ -1,18 +1,49 @@
 use crate::*;
+use ::std::borrow::{Cow, ToOwned};
+use ::std::cell::{Cell, RefCell};
 use ::std::collections::{
     BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque,
 };
-use ::std::net::IpAddr;
+use ::std::net::{
+    IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
+};
+use ::std::ops::Bound;
+use ::std::path::{Path, PathBuf};
 use ::std::rc::Rc;
 use ::std::sync::{Arc, Mutex, RwLock};
-use ::std::time::Duration;
+use ::std::time::{Duration, SystemTime};
 
 document! {
     for String
         => TypeKind::String.into();
 
+    /* ----- */
+
     for IpAddr
-        => Type { example: Some(Example::Simple("127.0.0.1")), ..String::ty() };
+        => TypeKind::IpAddr { version: None }.into();
+
+    for Ipv4Addr
+        => TypeKind::IpAddr { version: Some(4) }.into();
+
+    for Ipv6Addr
+        => TypeKind::IpAddr { version: Some(6) }.into();
+
+    for SocketAddr
+        => TypeKind::SocketAddr { version: None }.into();
+
+    for SocketAddrV4
+        => TypeKind::SocketAddr { version: Some(4) }.into();
+
+    for SocketAddrV6
+        => TypeKind::SocketAddr { version: Some(6) }.into();
+
+    /* ----- */
+
+    for Path
+        => TypeKind::Path.into();
+
+    for PathBuf
+        => TypeKind::Path.into();
 
     /* ----- */
 
@@ -36,6 +67,15 @@ document! {
     for Mutex<T> where (T: Document + ?Sized)
         => T::ty();
 
+    for Cell<T> where (T: Document + ?Sized)
+        => T::ty();
+
+    for RefCell<T> where (T: Document + ?Sized)
+        => T::ty();
+
+    for Cow<'a, T> where ('a, T: Document + ToOwned + ?Sized)
+        => T::ty();
+
     /* ----- */
 
     for Vec<T> where (T: Document)
@@ -69,34 +109,115 @@ document! {
 
     for Duration
         => duration();
+
+    for SystemTime
+        => system_time();
+
+    /* ----- */
+
+    for Result<T, E> where (T: Document, E: Document)
+        => TypeKind::Enum {
+            tag: Tag::External,
+            variants: vec![
+                variant("Ok", newtype(T::ty())),
+                variant("Err", newtype(E::ty())),
+            ],
+            example_variant: None,
+            repr: false,
+        }.into();
+
+    for Bound<T> where (T: Document)
+        => TypeKind::Enum {
+            tag: Tag::External,
+            variants: vec![
+                variant("Included", newtype(T::ty())),
+                variant("Excluded", newtype(T::ty())),
+                variant("Unbounded", Fields::Unit),
+            ],
+            example_variant: None,
+            repr: false,
+        }.into();
 }
 
+/// Documents `Duration` through its Serde representation; fields serialized
+/// differently (e.g. through `humantime-serde`) can use `#[doku(duration)]`.
 fn duration() -> Type {
+    time_struct(("secs", "123"), ("nanos", "456000000"))
+}
+
+/// Documents `SystemTime` through its Serde representation; fields serialized
+/// differently (e.g. through `humantime-serde`) can use `#[doku(timestamp)]`.
+fn system_time() -> Type {
+    time_struct(
+        ("secs_since_epoch", "1672531200"),
+        ("nanos_since_epoch", "0"),
+    )
+}
+
+/// Builds a struct of `(secs: u64, nanos: u32)`, with given names and
+/// examples - that's how Serde represents both `Duration` and `SystemTime`.
+fn time_struct(
+    (secs, secs_example): (&'static str, &'static str),
+    (nanos, nanos_example): (&'static str, &'static str),
+) -> Type {
+    let field = |ty: Type, example: &'static str| Field {
+        ty: Type {
+            example: Some(Example::Simple(example)),
+            ..ty
+        },
+        aliases: Vec::new(),
+        flattened: false,
+        section: None,
+    };
+
     Type::from(TypeKind::Struct {
         fields: Fields::Named {
             fields: vec![
-                (
-                    "secs",
-                    Field {
-                        ty: Type {
-                            example: Some(Example::Simple("123")),
-                            ..u64::ty()
-                        },
-                        flattened: false,
-                    },
-                ),
-                (
-                    "nanos",
-                    Field {
-                        ty: Type {
-                            example: Some(Example::Simple("456000000")),
-                            ..u32::ty()
-                        },
-                        flattened: false,
-                    },
-                ),
+                (secs, field(u64::ty(), secs_example)),
+                (nanos, field(u32::ty(), nanos_example)),
             ],
         },
         transparent: false,
+        deny_unknown_fields: false,
+        opaque: false,
     })
 }
+
+impl<K: Document, V: Document> DocumentMap for HashMap<K, V> {}
+impl<K: Document, V: Document> DocumentMap for BTreeMap<K, V> {}
+impl<T: DocumentMap> DocumentMap for Option<T> {}
+impl<T: DocumentMap + ?Sized> DocumentMap for Box<T> {}
+impl<T: DocumentMap + ?Sized> DocumentMap for Rc<T> {}
+impl<T: DocumentMap + ?Sized> DocumentMap for Arc<T> {}
+
+/// Builds a variant for one of the standard enums above - they all follow
+/// serde's default (externally tagged) representation.
+///
+/// Also used by integrations with other crates' enums (e.g. `Either`).
+pub(super) fn variant(id: &'static str, fields: Fields) -> Variant {
+    Variant {
+        id,
+        title: id,
+        aliases: Vec::new(),
+        comment: None,
+        since: None,
+        until: None,
+        other: false,
+        hidden: false,
+        discriminant: None,
+        serializable: true,
+        deserializable: true,
+        fields,
+    }
+}
+
+pub(super) fn newtype(ty: Type) -> Fields {
+    Fields::Unnamed {
+        fields: vec![Field {
+            ty,
+            aliases: Vec::new(),
+            flattened: false,
+            section: None,
+        }],
+    }
+}
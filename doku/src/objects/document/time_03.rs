@@ -0,0 +1,31 @@
+use crate::*;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+document! {
+    for OffsetDateTime
+        => Type {
+            example: Some(Example::Simple("2018-04-05T11:44:42.621513958Z")),
+            ..TypeKind::DateTime { offset: true }.into()
+        };
+
+    for PrimitiveDateTime
+        => Type {
+            example: Some(Example::Simple("2018-04-05T11:44:42.621513958")),
+            ..TypeKind::DateTime { offset: false }.into()
+        };
+
+    for Date
+        => Type {
+            example: Some(Example::Simple("2018-04-05")),
+            ..TypeKind::Date.into()
+        };
+
+    for Time
+        => Type {
+            example: Some(Example::Simple("11:44:42.621513958")),
+            ..TypeKind::Time.into()
+        };
+
+    for Duration
+        => TypeKind::Duration.into();
+}
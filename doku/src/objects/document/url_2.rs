@@ -5,6 +5,6 @@ document! {
     for Url
         => Type {
             example: Some(Example::Simple("https://www.rust-lang.org")),
-            ..String::ty()
+            ..TypeKind::Url.into()
         };
 }
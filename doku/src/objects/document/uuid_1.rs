@@ -0,0 +1,7 @@
+use crate::*;
+use uuid::Uuid;
+
+document! {
+    for Uuid
+        => TypeKind::Uuid.into();
+}
@@ -1,34 +1,74 @@
-#[derive(Clone, Copy, Debug)]
+#[cfg(feature = "deserialize")]
+use crate::leak;
+#[cfg(feature = "deserialize")]
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
 pub enum Example {
     /// `#[doku(literal_example = "foo")]`
     Literal(&'static str),
 
     /// `#[doku(example = "foo")]`
+    ///
+    /// Printed as-is - it's up to the printer to decide whether the example
+    /// should get quoted, depending on the type it documents.
     Simple(&'static str),
 
     /// `#[doku(example = "one", example = "two")]`
-    Compound(&'static [&'static str]),
+    Compound(
+        #[cfg_attr(feature = "deserialize", serde(deserialize_with = "leak"))]
+        &'static [&'static str],
+    ),
+
+    /// `#[doku(example = true)]`
+    Bool(bool),
+
+    /// `#[doku(example = 8080)]`
+    Int(i64),
+
+    /// `#[doku(example = 1.5)]`
+    Float(f64),
+
+    /// A string that should always get quoted, even when the type it
+    /// documents is printed without quotes (e.g. `TypeKind::Any`).
+    String(&'static str),
 }
 
 impl Example {
-    pub fn first(self) -> Option<&'static str> {
+    /// Returns the first example, rendered as text (without any quotes).
+    pub fn first(self) -> Option<Cow<'static, str>> {
         match self {
-            Example::Literal(example) => Some(example),
-            Example::Simple(example) => Some(example),
-            Example::Compound(examples) => examples.first().copied(),
+            Example::Literal(example)
+            | Example::Simple(example)
+            | Example::String(example) => Some(Cow::Borrowed(example)),
+            Example::Compound(examples) => {
+                examples.first().copied().map(Cow::Borrowed)
+            }
+            Example::Bool(example) => Some(Cow::Owned(example.to_string())),
+            Example::Int(example) => Some(Cow::Owned(example.to_string())),
+            Example::Float(example) => {
+                Some(Cow::Owned(format!("{:?}", example)))
+            }
         }
     }
 
-    pub fn iter(self) -> impl Iterator<Item = &'static str> {
+    /// Returns all the examples, one by one; items of `Example::Compound`
+    /// are returned as `Example::Simple`.
+    pub fn iter(self) -> impl Iterator<Item = Example> {
         let (example, examples) = match self {
-            Example::Literal(example) => (Some(example), None),
-            Example::Simple(example) => (Some(example), None),
             Example::Compound(examples) => (None, Some(examples)),
+            example => (Some(example), None),
         };
 
-        example
-            .into_iter()
-            .chain(examples.into_iter().flatten().copied())
+        example.into_iter().chain(
+            examples
+                .into_iter()
+                .flatten()
+                .map(|example| Example::Simple(example)),
+        )
     }
 }
 
@@ -47,3 +87,21 @@ impl From<&'static [&'static str]> for Example {
         }
     }
 }
+
+impl From<bool> for Example {
+    fn from(example: bool) -> Self {
+        Self::Bool(example)
+    }
+}
+
+impl From<i64> for Example {
+    fn from(example: i64) -> Self {
+        Self::Int(example)
+    }
+}
+
+impl From<f64> for Example {
+    fn from(example: f64) -> Self {
+        Self::Float(example)
+    }
+}
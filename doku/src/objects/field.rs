@@ -1,10 +1,20 @@
 use crate::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
 pub struct Field {
     /// Type of this field
     pub ty: Type,
 
+    /// Alternative keys accepted during deserialization (think
+    /// `#[serde(alias = "...")]`).
+    pub aliases: Vec<&'static str>,
+
     /// Whether this field should get flattened (i.e. `#[serde(flatten)]`)
     pub flattened: bool,
+
+    /// Name of the section this field belongs to (think
+    /// `#[doku(section = "Networking")]`); printers group fields by their
+    /// sections.
+    pub section: Option<&'static str>,
 }
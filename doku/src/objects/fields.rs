@@ -1,6 +1,11 @@
 use crate::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(
+    feature = "deserialize",
+    derive(Deserialize),
+    serde(bound(deserialize = "'de: 'static"))
+)]
 pub enum Fields {
     /// E.g.: `struct Foo { a: usize, b: String }`
     Named { fields: Vec<(&'static str, Field)> },
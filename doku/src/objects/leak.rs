@@ -0,0 +1,44 @@
+//! Deserialization of the type model (see: the `deserialize` feature).
+//!
+//! Types borrow `'static` strings (since that's what `#[derive(Document)]`
+//! generates), so they implement only `Deserialize<'static>` - to deserialize
+//! them from a regular input, the input gets parsed and leaked first, which
+//! is fine for one-off tools (e.g. comparing a cached type tree with the
+//! current one), but not something to do over and over in a long-running
+//! program.
+
+use crate::*;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Deserializes given JSON, leaking it so that the deserialized value can
+/// borrow `'static` strings from it.
+pub(crate) fn from_json<T>(json: &str) -> serde_json::Result<T>
+where
+    T: Deserialize<'static>,
+{
+    let json: &'static Value = Box::leak(Box::new(serde_json::from_str(json)?));
+
+    T::deserialize(json)
+}
+
+/// Deserializes a slice of strings, leaking it; used through
+/// `#[serde(deserialize_with = "...")]`, since slices can't be borrowed
+/// directly.
+pub(crate) fn leak<'de: 'static, D>(
+    deserializer: D,
+) -> Result<&'static [&'static str], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let strs = Vec::<&'static str>::deserialize(deserializer)?;
+
+    Ok(Box::leak(strs.into_boxed_slice()))
+}
+
+/// Returns the type referenced by a deserialized `TypeKind::Reference` - since
+/// functions can't be deserialized, the actual type is not known anymore (it
+/// can be still looked up by name, e.g. through `TypeRegistry::get()`).
+pub(crate) fn unresolved_reference() -> fn() -> Type {
+    || TypeKind::Any.into()
+}
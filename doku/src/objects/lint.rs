@@ -0,0 +1,774 @@
+use crate::*;
+use std::fmt;
+
+/// A problem found when checking a value against a type - see:
+/// `Type::lint()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint {
+    /// Path leading to the offending value, e.g. `server.tls.cert` or
+    /// `upstreams[0].url`; empty for the root value.
+    pub path: String,
+
+    pub kind: LintKind,
+
+    /// Doc comment of the field the offending value belongs to.
+    pub comment: Option<&'static str>,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LintKind {
+    /// Key that doesn't correspond to any of the fields.
+    UnknownKey,
+
+    /// Field or variant that's been removed in given version (think
+    /// `#[doku(until = "2.0")]`).
+    Deprecated { until: &'static str },
+
+    /// Value of a different type than expected, e.g. a string where a number
+    /// should be.
+    TypeMismatch {
+        expected: &'static str,
+        found: &'static str,
+    },
+
+    /// Array of a different length than expected, e.g. a tuple with a
+    /// missing element.
+    LengthMismatch { expected: usize, found: usize },
+
+    /// Enum variant that doesn't exist.
+    UnknownVariant {
+        variant: String,
+        expected: Vec<&'static str>,
+    },
+}
+
+impl fmt::Display for LintKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownKey => write!(f, "unknown key"),
+
+            Self::Deprecated { until } => {
+                write!(f, "deprecated, removed in v{}", until)
+            }
+
+            Self::TypeMismatch { expected, found } => {
+                write!(f, "expected {}, found {}", expected, found)
+            }
+
+            Self::LengthMismatch { expected, found } => {
+                let noun = if *expected == 1 {
+                    "element"
+                } else {
+                    "elements"
+                };
+
+                write!(f, "expected {} {}, found {}", expected, noun, found)
+            }
+
+            Self::UnknownVariant { variant, expected } => {
+                let expected: Vec<_> = expected
+                    .iter()
+                    .map(|variant| format!("`{}`", variant))
+                    .collect();
+
+                write!(
+                    f,
+                    "unknown variant `{}`, expected one of: {}",
+                    variant,
+                    expected.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl Type {
+    /// Checks given value (e.g. a configuration file parsed into
+    /// `serde_json::Value`) against this type, reporting unknown keys,
+    /// deprecated fields and type mismatches:
+    ///
+    /// ```
+    /// use doku::{Document, Value};
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     port: u16,
+    ///
+    ///     #[doku(until = "2.0")]
+    ///     host: Option<String>,
+    /// }
+    ///
+    /// let val = Value::from(&serde_json::json!({
+    ///     "port": "8080",
+    ///     "host": "localhost",
+    ///     "workers": 4,
+    /// }));
+    ///
+    /// let lints: Vec<_> = Config::ty()
+    ///     .lint(&val)
+    ///     .into_iter()
+    ///     .map(|lint| lint.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "host: deprecated, removed in v2.0",
+    ///         "port: expected an integer, found a string",
+    ///         "workers: unknown key",
+    ///     ],
+    ///     lints,
+    /// );
+    /// ```
+    ///
+    /// Lints are returned in the order of the value's keys.
+    pub fn lint(&self, val: &Value) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        lint_type(&mut lints, "", self, None, val);
+        lints
+    }
+}
+
+fn lint_type(
+    out: &mut Vec<Lint>,
+    path: &str,
+    ty: &Type,
+    comment: Option<&'static str>,
+    val: &Value,
+) {
+    let comment = ty.comment.or(comment);
+
+    let expected = match &ty.kind {
+        TypeKind::Any | TypeKind::Duration => {
+            return;
+        }
+
+        TypeKind::Optional { ty } => {
+            if !matches!(val, Value::None) {
+                lint_type(out, path, ty, comment, val);
+            }
+
+            return;
+        }
+
+        TypeKind::Reference { ty, .. } => {
+            lint_type(out, path, &ty(), comment, val);
+            return;
+        }
+
+        TypeKind::Array { ty, .. } => {
+            if let Value::Array(items) = val {
+                for (idx, item) in items.iter().enumerate() {
+                    let path = format!("{}[{}]", path, idx);
+
+                    lint_type(out, &path, ty, comment, item);
+                }
+
+                return;
+            }
+
+            "an array"
+        }
+
+        TypeKind::Tuple { fields } => {
+            if let Value::Array(items) = val {
+                lint_length(out, path, comment, fields.len(), items.len());
+
+                for (idx, (ty, item)) in fields.iter().zip(items).enumerate() {
+                    let path = format!("{}[{}]", path, idx);
+
+                    lint_type(out, &path, ty, comment, item);
+                }
+
+                return;
+            }
+
+            "an array"
+        }
+
+        TypeKind::Map { value, .. } => {
+            if let Value::Map(entries) = val {
+                for (key, val) in entries {
+                    let path = join(path, &key_to_string(key));
+
+                    lint_type(out, &path, value, comment, val);
+                }
+
+                return;
+            }
+
+            "an object"
+        }
+
+        TypeKind::Struct {
+            fields,
+            transparent,
+            ..
+        } => {
+            // Transparent structs are serialized just like their only field
+            let field = match fields {
+                Fields::Named { fields }
+                    if *transparent && fields.len() == 1 =>
+                {
+                    Some(&fields[0].1)
+                }
+                Fields::Unnamed { fields }
+                    if *transparent && fields.len() == 1 =>
+                {
+                    Some(&fields[0])
+                }
+                _ => None,
+            };
+
+            if let Some(field) = field {
+                lint_type(out, path, &field.ty, comment, val);
+            } else {
+                lint_fields(out, path, fields, comment, val, None);
+            }
+
+            return;
+        }
+
+        TypeKind::Enum {
+            tag,
+            variants,
+            repr,
+            ..
+        } => {
+            lint_enum(out, path, *tag, variants, *repr, comment, val);
+            return;
+        }
+
+        TypeKind::Bool => {
+            if let Value::Bool(_) = val {
+                return;
+            }
+
+            "a boolean"
+        }
+
+        TypeKind::Float { .. } => {
+            if is_integer(val) || matches!(val, Value::F32(_) | Value::F64(_)) {
+                return;
+            }
+
+            "a number"
+        }
+
+        TypeKind::Integer { .. } => {
+            if is_integer(val) {
+                return;
+            }
+
+            "an integer"
+        }
+
+        TypeKind::Bytes => {
+            if let Value::String(_) | Value::Array(_) = val {
+                return;
+            }
+
+            "bytes"
+        }
+
+        TypeKind::Date
+        | TypeKind::DateTime { .. }
+        | TypeKind::Decimal { .. }
+        | TypeKind::IpAddr { .. }
+        | TypeKind::Path
+        | TypeKind::SocketAddr { .. }
+        | TypeKind::String
+        | TypeKind::Time
+        | TypeKind::Url
+        | TypeKind::Uuid => {
+            if let Value::String(_) | Value::Char(_) = val {
+                return;
+            }
+
+            "a string"
+        }
+    };
+
+    out.push(Lint {
+        path: path.to_owned(),
+        kind: LintKind::TypeMismatch {
+            expected,
+            found: describe(val),
+        },
+        comment,
+    });
+}
+
+/// Lints fields of a struct or of an enum's variant; `tag` is the key used
+/// by internally-tagged enums, which is allowed to appear next to the fields.
+fn lint_fields(
+    out: &mut Vec<Lint>,
+    path: &str,
+    fields: &Fields,
+    comment: Option<&'static str>,
+    val: &Value,
+    tag: Option<&str>,
+) {
+    let expected = match fields {
+        Fields::Named { fields } => {
+            if let Value::Map(entries) = val {
+                lint_named_fields(out, path, fields, entries, true, tag);
+                return;
+            }
+
+            "an object"
+        }
+
+        Fields::Unnamed { fields } => {
+            // Newtypes are serialized just like their inner types; values
+            // coming from `Value::from()` keep them wrapped in an array,
+            // though
+            if let [field] = fields.as_slice() {
+                let val = match val {
+                    Value::Array(items)
+                        if items.len() == 1 && !is_sequence(&field.ty) =>
+                    {
+                        &items[0]
+                    }
+                    val => val,
+                };
+
+                lint_type(out, path, &field.ty, comment, val);
+                return;
+            }
+
+            if let Value::Array(items) = val {
+                lint_length(out, path, comment, fields.len(), items.len());
+
+                for (idx, (field, item)) in fields.iter().zip(items).enumerate()
+                {
+                    let path = format!("{}[{}]", path, idx);
+
+                    lint_type(out, &path, &field.ty, comment, item);
+                }
+
+                return;
+            }
+
+            "an array"
+        }
+
+        Fields::Unit => {
+            if let Value::None = val {
+                return;
+            }
+
+            "null"
+        }
+    };
+
+    out.push(Lint {
+        path: path.to_owned(),
+        kind: LintKind::TypeMismatch {
+            expected,
+            found: describe(val),
+        },
+        comment,
+    });
+}
+
+fn lint_named_fields(
+    out: &mut Vec<Lint>,
+    path: &str,
+    fields: &[(&'static str, Field)],
+    entries: &[(Value, Value)],
+    check_unknown_keys: bool,
+    tag: Option<&str>,
+) {
+    // Flattened fields are serialized straight into the parent object, so
+    // they get linted against the very same entries
+    for (_, field) in fields {
+        if field.flattened {
+            // Flattened `Option<T>` is serialized just like `T` (or as nothing
+            // at all), so there's no need to look at the optional part
+            let ty = if let TypeKind::Optional { ty } = &field.ty.kind {
+                ty
+            } else {
+                &field.ty
+            };
+
+            if let TypeKind::Struct {
+                fields: Fields::Named { fields },
+                ..
+            } = &ty.kind
+            {
+                lint_named_fields(out, path, fields, entries, false, None);
+            }
+        }
+    }
+
+    let check_unknown_keys = check_unknown_keys
+        && fields.iter().all(|(_, field)| {
+            !field.flattened || known_keys(&field.ty).is_some()
+        });
+
+    for (key, val) in entries {
+        let key = key_to_string(key);
+
+        if Some(key.as_str()) == tag {
+            continue;
+        }
+
+        let path = join(path, &key);
+
+        let field = fields.iter().find(|(name, field)| {
+            !field.flattened
+                && (*name == key || field.aliases.contains(&key.as_str()))
+        });
+
+        if let Some((_, field)) = field {
+            if let Some(until) = field.ty.until {
+                out.push(Lint {
+                    path: path.clone(),
+                    kind: LintKind::Deprecated { until },
+                    comment: field.ty.comment,
+                });
+            }
+
+            lint_type(out, &path, &field.ty, None, val);
+        } else if check_unknown_keys && !is_flattened_key(fields, &key) {
+            out.push(Lint {
+                path,
+                kind: LintKind::UnknownKey,
+                comment: None,
+            });
+        }
+    }
+}
+
+/// Reports tuples (and tuple-like structs) with too few or too many elements.
+fn lint_length(
+    out: &mut Vec<Lint>,
+    path: &str,
+    comment: Option<&'static str>,
+    expected: usize,
+    found: usize,
+) {
+    if expected != found {
+        out.push(Lint {
+            path: path.to_owned(),
+            kind: LintKind::LengthMismatch { expected, found },
+            comment,
+        });
+    }
+}
+
+fn lint_enum(
+    out: &mut Vec<Lint>,
+    path: &str,
+    tag: Tag,
+    variants: &[Variant],
+    repr: bool,
+    comment: Option<&'static str>,
+    val: &Value,
+) {
+    if let Tag::None = tag {
+        // Untagged enums can be told apart only by their contents, so we're
+        // looking for the first variant that matches
+        let matches = variants.iter().any(|variant| {
+            let mut lints = Vec::new();
+
+            lint_fields(&mut lints, path, &variant.fields, comment, val, None);
+            lints.is_empty()
+        });
+
+        if !matches {
+            out.push(Lint {
+                path: path.to_owned(),
+                kind: LintKind::TypeMismatch {
+                    expected: "one of the enum's variants",
+                    found: describe(val),
+                },
+                comment,
+            });
+        }
+
+        return;
+    }
+
+    let (id, content, content_path, tag_key) = match (tag, val) {
+        (Tag::External, Value::Map(entries)) if entries.len() == 1 => {
+            let id = key_to_string(&entries[0].0);
+            let content_path = join(path, &id);
+
+            (id, Some(&entries[0].1), content_path, None)
+        }
+
+        (Tag::External, val) if !matches!(val, Value::Map(_)) => {
+            let id = if repr {
+                integer_to_string(val)
+            } else if let Value::String(id) = val {
+                Some(id.clone())
+            } else {
+                None
+            };
+
+            if let Some(id) = id {
+                (id, None, path.to_owned(), None)
+            } else {
+                out.push(Lint {
+                    path: path.to_owned(),
+                    kind: LintKind::TypeMismatch {
+                        expected: if repr { "an integer" } else { "a string" },
+                        found: describe(val),
+                    },
+                    comment,
+                });
+
+                return;
+            }
+        }
+
+        (Tag::Internal { tag }, Value::Map(_)) => {
+            if let Some(id) = val.as_struct_named_field(tag) {
+                (key_to_string(id), Some(val), path.to_owned(), Some(tag))
+            } else {
+                out.push(Lint {
+                    path: join(path, tag),
+                    kind: LintKind::TypeMismatch {
+                        expected: "a string",
+                        found: "nothing",
+                    },
+                    comment,
+                });
+
+                return;
+            }
+        }
+
+        (Tag::Adjacent { tag, content }, Value::Map(_)) => {
+            if let Some(id) = val.as_struct_named_field(tag) {
+                (
+                    key_to_string(id),
+                    val.as_struct_named_field(content),
+                    join(path, content),
+                    None,
+                )
+            } else {
+                out.push(Lint {
+                    path: join(path, tag),
+                    kind: LintKind::TypeMismatch {
+                        expected: "a string",
+                        found: "nothing",
+                    },
+                    comment,
+                });
+
+                return;
+            }
+        }
+
+        (_, val) => {
+            out.push(Lint {
+                path: path.to_owned(),
+                kind: LintKind::TypeMismatch {
+                    expected: if let Tag::External = tag {
+                        "a string or a single-key object"
+                    } else {
+                        "an object"
+                    },
+                    found: describe(val),
+                },
+                comment,
+            });
+
+            return;
+        }
+    };
+
+    let variant = variants.iter().find(|variant| {
+        if repr {
+            variant.discriminant == Some(id.as_str())
+        } else {
+            variant.id == id || variant.aliases.contains(&id.as_str())
+        }
+    });
+
+    let variant = if let Some(variant) = variant {
+        variant
+    } else if variants.iter().any(|variant| variant.other) {
+        return;
+    } else {
+        out.push(Lint {
+            path: path.to_owned(),
+            kind: LintKind::UnknownVariant {
+                variant: id,
+                expected: variants
+                    .iter()
+                    .map(|variant| {
+                        if repr {
+                            variant.discriminant.unwrap_or(variant.id)
+                        } else {
+                            variant.id
+                        }
+                    })
+                    .collect(),
+            },
+            comment,
+        });
+
+        return;
+    };
+
+    if let Some(until) = variant.until {
+        out.push(Lint {
+            path: path.to_owned(),
+            kind: LintKind::Deprecated { until },
+            comment: variant.comment.or(comment),
+        });
+    }
+
+    match (content, &variant.fields) {
+        (None, Fields::Unit) => (),
+
+        // Internally-tagged unit variants are just the tag
+        (Some(_), Fields::Unit) if tag_key.is_some() => (),
+
+        (Some(content), fields) => {
+            lint_fields(
+                out,
+                &content_path,
+                fields,
+                variant.comment.or(comment),
+                content,
+                tag_key,
+            );
+        }
+
+        (None, _) => {
+            out.push(Lint {
+                path: path.to_owned(),
+                kind: LintKind::TypeMismatch {
+                    expected: "an object",
+                    found: describe(val),
+                },
+                comment,
+            });
+        }
+    }
+}
+
+/// Returns names of fields that get serialized straight into the parent
+/// object when given type is flattened, or `None` if that's not known
+/// up-front (e.g. for flattened maps).
+fn known_keys(ty: &Type) -> Option<Vec<&'static str>> {
+    match &ty.kind {
+        TypeKind::Optional { ty } => known_keys(ty),
+
+        TypeKind::Struct {
+            fields: Fields::Named { fields },
+            ..
+        } => {
+            let mut keys = Vec::new();
+
+            for (name, field) in fields {
+                if field.flattened {
+                    keys.extend(known_keys(&field.ty)?);
+                } else {
+                    keys.push(*name);
+                    keys.extend(&field.aliases);
+                }
+            }
+
+            Some(keys)
+        }
+
+        _ => None,
+    }
+}
+
+fn is_flattened_key(fields: &[(&'static str, Field)], key: &str) -> bool {
+    fields.iter().any(|(_, field)| {
+        field.flattened
+            && known_keys(&field.ty).map_or(false, |keys| keys.contains(&key))
+    })
+}
+
+/// Returns whether given type is serialized as an array.
+fn is_sequence(ty: &Type) -> bool {
+    matches!(
+        ty.kind,
+        TypeKind::Array { .. } | TypeKind::Tuple { .. } | TypeKind::Bytes
+    )
+}
+
+fn is_integer(val: &Value) -> bool {
+    matches!(
+        val,
+        Value::U8(_)
+            | Value::I8(_)
+            | Value::U16(_)
+            | Value::I16(_)
+            | Value::U32(_)
+            | Value::I32(_)
+            | Value::U64(_)
+            | Value::I64(_)
+            | Value::U128(_)
+            | Value::I128(_)
+            | Value::Usize(_)
+            | Value::Isize(_)
+    )
+}
+
+fn describe(val: &Value) -> &'static str {
+    match val {
+        Value::None => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Char(_) | Value::String(_) => "a string",
+        Value::F32(_) | Value::F64(_) => "a number",
+        Value::Array(_) => "an array",
+        Value::Map(_) => "an object",
+        _ => "an integer",
+    }
+}
+
+fn key_to_string(key: &Value) -> String {
+    match key {
+        Value::String(key) => key.clone(),
+        Value::Char(key) => key.to_string(),
+        Value::Bool(key) => key.to_string(),
+        key => integer_to_string(key).unwrap_or_else(|| "?".to_owned()),
+    }
+}
+
+fn integer_to_string(val: &Value) -> Option<String> {
+    Some(match val {
+        Value::U8(val) => val.to_string(),
+        Value::I8(val) => val.to_string(),
+        Value::U16(val) => val.to_string(),
+        Value::I16(val) => val.to_string(),
+        Value::U32(val) => val.to_string(),
+        Value::I32(val) => val.to_string(),
+        Value::U64(val) => val.to_string(),
+        Value::I64(val) => val.to_string(),
+        Value::U128(val) => val.to_string(),
+        Value::I128(val) => val.to_string(),
+        Value::Usize(val) => val.to_string(),
+        Value::Isize(val) => val.to_string(),
+        _ => return None,
+    })
+}
+
+pub(super) fn join(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        segment.to_owned()
+    } else {
+        format!("{}.{}", path, segment)
+    }
+}
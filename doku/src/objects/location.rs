@@ -0,0 +1,24 @@
+#[cfg(feature = "deserialize")]
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+
+/// Place in the source code where a value has been defined.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+pub struct Location {
+    /// E.g. `my_crate::config::net`
+    pub module: &'static str,
+
+    /// E.g. `src/config/net.rs`
+    pub file: &'static str,
+
+    /// E.g. `42`
+    pub line: u32,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "deserialize")]
+use serde::Deserialize;
+use serde::Serialize;
 use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Default)]
@@ -20,7 +23,44 @@ impl Meta {
     }
 }
 
-#[derive(Clone, Debug, Default)]
+/// Arbitrary key-value metadata attached to a type.
+///
+/// Keys starting with `fmt` are understood by the JSON printer (see:
+/// [`crate::json::Formatting`]); all the other ones are left for your own use
+/// (e.g. for custom printers):
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     #[doku(meta(key = "x-team", value = "infra"))]
+///     #[doku(meta(key = "x-priority", value = 3))]
+///     port: u16,
+/// }
+///
+/// let ty = Config::ty();
+///
+/// let port = if let doku::TypeKind::Struct {
+///     fields: doku::Fields::Named { fields },
+///     ..
+/// } = ty.kind
+/// {
+///     fields[0].1.ty.metas.clone()
+/// } else {
+///     unreachable!()
+/// };
+///
+/// assert_eq!(Some("infra"), port.get("x-team"));
+/// assert_eq!(Some("3"), port.get("x-priority"));
+/// ```
+#[derive(Clone, Debug, Default, Serialize)]
+#[cfg_attr(
+    feature = "deserialize",
+    derive(Deserialize),
+    serde(bound(deserialize = "'de: 'static"))
+)]
+#[serde(transparent)]
 pub struct Metas {
     metas: BTreeMap<&'static str, &'static str>,
 }
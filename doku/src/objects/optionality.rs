@@ -0,0 +1,19 @@
+#[cfg(feature = "deserialize")]
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Determines whether a value has to be provided (see: `Type::optionality()`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
+pub enum Optionality {
+    /// E.g. `port: u16`
+    Required,
+
+    /// Value can be omitted, in which case it becomes `None` - e.g.
+    /// `port: Option<u16>`
+    Optional,
+
+    /// Value can be omitted, in which case it falls back to a default - e.g.
+    /// `#[serde(default)] port: u16`
+    Defaulted,
+}
@@ -1,5 +1,10 @@
+#[cfg(feature = "deserialize")]
+use serde::Deserialize;
+use serde::Serialize;
+
 /// Defines the way enums are represented (<https://serde.rs/enum-representations.html>)
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
 pub enum Tag {
     /// E.g.: `#[serde(tag = "t", content = "c")]`
     Adjacent {
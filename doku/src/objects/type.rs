@@ -1,11 +1,93 @@
 use crate::*;
 
-#[derive(Clone, Debug)]
+/// Description of a type - or of a field, together with its type.
+///
+/// The whole tree can be serialized (e.g. to JSON), so that it can be cached,
+/// diffed or consumed by external tools:
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Port to listen at
+///     port: u16,
+/// }
+///
+/// let ty = serde_json::to_value(Config::ty()).unwrap();
+/// let port = &ty["kind"]["Struct"]["fields"]["Named"]["fields"][0];
+///
+/// assert_eq!("port", port[0]);
+/// assert_eq!("Port to listen at", port[1]["ty"]["comment"]);
+/// assert_eq!(16, port[1]["ty"]["kind"]["Integer"]["bits"]);
+/// ```
+///
+/// With the `deserialize` feature enabled, types can be deserialized back as
+/// well - see: `Type::from_json()`.
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
 pub struct Type {
+    /// Doc-comment of the field, as written (see also: `CommentBlock`).
     pub comment: Option<&'static str>,
+
+    /// Doc-comment of the type itself - i.e. the one placed on the struct or
+    /// enum, as opposed to `comment`, which comes from the field.
+    pub header: Option<&'static str>,
+
+    /// Fully-qualified name of the type, including its module path (as
+    /// returned by `std::any::type_name()`, e.g. `my_crate::config::Database`);
+    /// present only for derived structs and enums - see also: `TypeRegistry`.
+    pub name: Option<&'static str>,
+
+    /// When disabled, printers render this type as a reference to a separate
+    /// definition instead of expanding it in place (think
+    /// `#[doku(inline = false)]`).
+    pub inline: bool,
+
     pub example: Option<Example>,
     pub metas: Metas,
 
+    /// Restrictions on the values this type accepts (think
+    /// `#[doku(pattern = "^[a-z0-9-]+$")]`).
+    pub constraints: Vec<Constraint>,
+
+    /// Unit the value is expressed in (think `#[doku(unit = "seconds")]`).
+    pub unit: Option<&'static str>,
+
+    /// Name of the environment variable that overrides this value (think
+    /// `#[doku(env = "APP_PORT")]`).
+    pub env: Option<&'static str>,
+
+    /// Version this value has been introduced in (think
+    /// `#[doku(since = "2.3")]`).
+    pub since: Option<&'static str>,
+
+    /// Version this value has been removed in (think
+    /// `#[doku(until = "3.0")]`).
+    pub until: Option<&'static str>,
+
+    /// Where this value has been defined (e.g. `src/config/net.rs:42`);
+    /// recorded by `#[derive(Document)]` for fields only when the `provenance`
+    /// feature is enabled.
+    pub location: Option<Location>,
+
+    /// Whether this value is a secret (think `#[doku(sensitive)]`); printers
+    /// never show actual values of such types, only a redacted placeholder.
+    pub sensitive: bool,
+
+    /// Whether this value can be omitted, falling back to a default (think
+    /// `#[serde(default)]`) - see also: `Type::optionality()`.
+    pub has_default: bool,
+
+    /// Whether fieldless enums should get documented through a compact,
+    /// single-line list of accepted values (think
+    /// `#[doku(enum_values_comment)]`).
+    pub enum_values_comment: bool,
+
+    /// Whether this value is marked as `#[doc(hidden)]`; printers skip such
+    /// values, unless told otherwise.
+    pub hidden: bool,
+
     /// When we have an adjacently-tagged enum, this field contains name of the
     /// field that should represent that enum's tag.
     pub tag: Option<&'static str>,
@@ -26,8 +108,21 @@ impl From<TypeKind> for Type {
     fn from(kind: TypeKind) -> Self {
         Self {
             comment: None,
+            header: None,
+            name: None,
+            inline: true,
             example: None,
             metas: Metas::default(),
+            constraints: Vec::new(),
+            unit: None,
+            env: None,
+            since: None,
+            until: None,
+            location: None,
+            sensitive: false,
+            has_default: false,
+            enum_values_comment: false,
+            hidden: false,
             tag: None,
             serializable: true,
             deserializable: true,
@@ -35,3 +130,27 @@ impl From<TypeKind> for Type {
         }
     }
 }
+
+impl Type {
+    /// Returns whether this value has to be provided.
+    pub fn optionality(&self) -> Optionality {
+        if let TypeKind::Optional { .. } = self.kind {
+            Optionality::Optional
+        } else if self.has_default {
+            Optionality::Defaulted
+        } else {
+            Optionality::Required
+        }
+    }
+
+    /// Deserializes a type serialized before (e.g. with `serde_json`).
+    ///
+    /// Since types borrow `'static` strings, the parsed JSON gets leaked; and
+    /// since functions can't be deserialized, `TypeKind::Reference` resolves
+    /// to `TypeKind::Any` (the referenced type can be still found by its name,
+    /// e.g. through `TypeRegistry::from_json()`).
+    #[cfg(feature = "deserialize")]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        from_json(json)
+    }
+}
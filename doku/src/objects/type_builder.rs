@@ -0,0 +1,362 @@
+use crate::*;
+
+/// Constructors and chainable setters for building type trees at runtime -
+/// useful when the documented structure isn't known at compile time (e.g.
+/// it's provided by a plugin):
+///
+/// ```
+/// use doku::{Tag, Type, Variant};
+///
+/// let ty = Type::struct_()
+///     .field(
+///         "port",
+///         Type::integer().comment("Port to listen at").example(8080),
+///     )
+///     .field("workers", Type::optional(Type::integer()).unit("threads"));
+///
+/// let doc = doku::json::Printer::default().print(&ty);
+///
+/// doku::assert_doc!(r#"
+///   {
+///     // Port to listen at
+///     "port": 8080,
+///     // In threads; optional
+///     "workers": 123
+///   }
+/// "#, doc);
+///
+/// let ty = Type::enum_()
+///     .tagged(Tag::Internal { tag: "kind" })
+///     .variant(Variant::new("Memory").comment("Keeps data in RAM"))
+///     .variant(
+///         Variant::new("Disk")
+///             .field("path", Type::path().example("/var/lib/app")),
+///     );
+///
+/// let doc = doku::json::Printer::default().print(&ty);
+///
+/// doku::assert_doc!(r#"
+///   // Keeps data in RAM
+///   {
+///     "kind": "Memory"
+///   }
+///   // or
+///   {
+///     "kind": "Disk",
+///     "path": "/var/lib/app"
+///   }
+/// "#, doc);
+/// ```
+///
+/// Since types borrow `'static` strings, names and comments known only at
+/// runtime have to be leaked (e.g. through `Box::leak()`) - which is fine for
+/// stuff that's built once per program's run.
+///
+/// Setters specific to a kind (e.g. `.field()`) panic when called on a type
+/// of another kind.
+impl Type {
+    pub fn any() -> Self {
+        TypeKind::Any.into()
+    }
+
+    pub fn array(ty: Type) -> Self {
+        TypeKind::Array {
+            ty: Box::new(ty),
+            size: None,
+        }
+        .into()
+    }
+
+    pub fn bool() -> Self {
+        TypeKind::Bool.into()
+    }
+
+    pub fn bytes() -> Self {
+        TypeKind::Bytes.into()
+    }
+
+    pub fn date() -> Self {
+        TypeKind::Date.into()
+    }
+
+    /// Creates a date with time that includes an offset from UTC.
+    pub fn date_time() -> Self {
+        TypeKind::DateTime { offset: true }.into()
+    }
+
+    /// Creates a decimal number with given maximum number of significant
+    /// digits (if any).
+    pub fn decimal(digits: Option<u8>) -> Self {
+        TypeKind::Decimal { digits }.into()
+    }
+
+    pub fn duration() -> Self {
+        TypeKind::Duration.into()
+    }
+
+    /// Creates an externally-tagged enum without any variants - see:
+    /// `.variant()` and `.tagged()`.
+    pub fn enum_() -> Self {
+        TypeKind::Enum {
+            tag: Tag::External,
+            variants: Vec::new(),
+            example_variant: None,
+            repr: false,
+        }
+        .into()
+    }
+
+    /// Creates an `f64`.
+    pub fn float() -> Self {
+        TypeKind::Float { bits: 64 }.into()
+    }
+
+    /// Creates an `i64`.
+    pub fn integer() -> Self {
+        TypeKind::Integer {
+            bits: 64,
+            signed: true,
+        }
+        .into()
+    }
+
+    pub fn ip_addr() -> Self {
+        TypeKind::IpAddr { version: None }.into()
+    }
+
+    pub fn map(key: Type, value: Type) -> Self {
+        TypeKind::Map {
+            key: Box::new(key),
+            value: Box::new(value),
+        }
+        .into()
+    }
+
+    pub fn optional(ty: Type) -> Self {
+        TypeKind::Optional { ty: Box::new(ty) }.into()
+    }
+
+    pub fn path() -> Self {
+        TypeKind::Path.into()
+    }
+
+    pub fn socket_addr() -> Self {
+        TypeKind::SocketAddr { version: None }.into()
+    }
+
+    pub fn string() -> Self {
+        TypeKind::String.into()
+    }
+
+    /// Creates a struct without any fields - see: `.field()`.
+    pub fn struct_() -> Self {
+        TypeKind::Struct {
+            fields: Fields::Unit,
+            transparent: false,
+            deny_unknown_fields: false,
+            opaque: false,
+        }
+        .into()
+    }
+
+    pub fn time() -> Self {
+        TypeKind::Time.into()
+    }
+
+    pub fn tuple(fields: Vec<Type>) -> Self {
+        TypeKind::Tuple { fields }.into()
+    }
+
+    pub fn url() -> Self {
+        TypeKind::Url.into()
+    }
+
+    pub fn uuid() -> Self {
+        TypeKind::Uuid.into()
+    }
+
+    pub fn comment(mut self, comment: &'static str) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    pub fn header(mut self, header: &'static str) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn example(mut self, example: impl Into<Example>) -> Self {
+        self.example = Some(example.into());
+        self
+    }
+
+    pub fn meta(mut self, key: &'static str, value: &'static str) -> Self {
+        self.metas.add(key, value);
+        self
+    }
+
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    pub fn unit(mut self, unit: &'static str) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    pub fn env(mut self, env: &'static str) -> Self {
+        self.env = Some(env);
+        self
+    }
+
+    pub fn since(mut self, since: &'static str) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn until(mut self, until: &'static str) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn sensitive(mut self) -> Self {
+        self.sensitive = true;
+        self
+    }
+
+    /// Marks this value as one that can be omitted (think
+    /// `#[serde(default)]`).
+    pub fn defaulted(mut self) -> Self {
+        self.has_default = true;
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Appends a named field to this struct.
+    pub fn field(self, name: &'static str, ty: Type) -> Self {
+        self.push_field(name, ty, false)
+    }
+
+    /// Appends a flattened field (think `#[serde(flatten)]`) to this struct.
+    pub fn flattened_field(self, name: &'static str, ty: Type) -> Self {
+        self.push_field(name, ty, true)
+    }
+
+    /// Appends a variant to this enum.
+    pub fn variant(mut self, variant: Variant) -> Self {
+        if let TypeKind::Enum { variants, .. } = &mut self.kind {
+            variants.push(variant);
+        } else {
+            panic!("`.variant()` can be called only on enums");
+        }
+
+        self
+    }
+
+    /// Changes the way this enum is represented (see: `Tag`).
+    pub fn tagged(mut self, tag: Tag) -> Self {
+        if let TypeKind::Enum { tag: this, .. } = &mut self.kind {
+            *this = tag;
+        } else {
+            panic!("`.tagged()` can be called only on enums");
+        }
+
+        self
+    }
+
+    fn push_field(
+        mut self,
+        name: &'static str,
+        ty: Type,
+        flattened: bool,
+    ) -> Self {
+        if let TypeKind::Struct { fields, .. } = &mut self.kind {
+            push_field(fields, name, ty, flattened);
+        } else {
+            panic!("`.field()` can be called only on structs");
+        }
+
+        self
+    }
+}
+
+impl Variant {
+    /// Creates a unit variant (see: `.field()`).
+    pub fn new(id: &'static str) -> Self {
+        Self {
+            id,
+            title: id,
+            aliases: Vec::new(),
+            comment: None,
+            since: None,
+            until: None,
+            other: false,
+            hidden: false,
+            discriminant: None,
+            serializable: true,
+            deserializable: true,
+            fields: Fields::Unit,
+        }
+    }
+
+    pub fn comment(mut self, comment: &'static str) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    pub fn alias(mut self, alias: &'static str) -> Self {
+        self.aliases.push(alias);
+        self
+    }
+
+    pub fn since(mut self, since: &'static str) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn until(mut self, until: &'static str) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Appends a named field to this variant.
+    pub fn field(mut self, name: &'static str, ty: Type) -> Self {
+        push_field(&mut self.fields, name, ty, false);
+        self
+    }
+}
+
+fn push_field(
+    fields: &mut Fields,
+    name: &'static str,
+    ty: Type,
+    flattened: bool,
+) {
+    if let Fields::Unit = fields {
+        *fields = Fields::Named { fields: Vec::new() };
+    }
+
+    if let Fields::Named { fields } = fields {
+        fields.push((
+            name,
+            Field {
+                ty,
+                aliases: Vec::new(),
+                flattened,
+                section: None,
+            },
+        ));
+    } else {
+        panic!("named fields cannot be mixed with unnamed ones");
+    }
+}
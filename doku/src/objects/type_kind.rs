@@ -1,7 +1,11 @@
 use crate::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
 pub enum TypeKind {
+    /// An arbitrary value, e.g. `serde_json::Value`
+    Any,
+
     /// A homogeneous array of a possibly known size
     Array {
         /// Type of items this array accepts
@@ -14,6 +18,31 @@ pub enum TypeKind {
     /// `true` / `false`
     Bool,
 
+    /// Binary data encoded as a string (e.g. through `serde_with::base64`);
+    /// applied through `#[doku(bytes)]`, since by default Serde serializes
+    /// binary data as arrays of integers
+    Bytes,
+
+    /// A calendar date, e.g. `2023-01-01`
+    Date,
+
+    /// A date with time, e.g. `2023-01-01T00:00:00Z`
+    DateTime {
+        /// Whether the value includes an offset from UTC (e.g. `Z` or
+        /// `+02:00`), as opposed to a local (naive) date and time
+        offset: bool,
+    },
+
+    /// A decimal number that's serialized as a string to retain its
+    /// precision, e.g. `"12.50"`
+    Decimal {
+        /// Maximum number of significant digits, if the precision is limited
+        digits: Option<u8>,
+    },
+
+    /// A span of time, e.g. `30s`
+    Duration,
+
     /// An algebraic data type
     Enum {
         /// The way enum should be serialized
@@ -21,13 +50,40 @@ pub enum TypeKind {
 
         /// All enum's variants
         variants: Vec<Variant>,
+
+        /// Title of the variant that should be used as the example; when
+        /// missing, the first variant is used instead (think
+        /// `#[doku(example_variant = "Foo")]`).
+        example_variant: Option<&'static str>,
+
+        /// Whether the enum gets serialized through its variants' discriminants
+        /// instead of their names (think `#[doku(repr)]`, matching
+        /// `serde_repr`).
+        repr: bool,
     },
 
     /// A floating-point number
-    Float,
+    Float {
+        /// Width of the number - `32` for `f32`, `64` for `f64`
+        bits: u8,
+    },
 
     /// An integer number
-    Integer,
+    Integer {
+        /// Width of the integer, e.g. `16` for `u16` (pointer-sized integers
+        /// use the width of the target platform)
+        bits: u8,
+
+        /// Whether the integer can be negative
+        signed: bool,
+    },
+
+    /// An IP address, e.g. `127.0.0.1`
+    IpAddr {
+        /// `Some(4)` for `Ipv4Addr`, `Some(6)` for `Ipv6Addr`, `None` when
+        /// both are accepted
+        version: Option<u8>,
+    },
 
     /// A homogeneous map
     Map { key: Box<Type>, value: Box<Type> },
@@ -35,6 +91,41 @@ pub enum TypeKind {
     /// `Option<Ty>`
     Optional { ty: Box<Type> },
 
+    /// A filesystem path, e.g. `/path/to/file`
+    Path,
+
+    /// A reference to a type that's already being documented somewhere up the
+    /// tree - used to break the cycle in recursive types, e.g.:
+    ///
+    /// ```
+    /// # use doku::Document;
+    /// #[derive(Document)]
+    /// struct Node {
+    ///     children: Vec<Node>,
+    /// }
+    /// ```
+    Reference {
+        /// Fully-qualified name of the referenced type (as returned by
+        /// `std::any::type_name()`)
+        name: &'static str,
+
+        /// Returns the referenced type; called lazily by printers, since
+        /// building the type eagerly would lead to an infinite recursion.
+        #[serde(skip)]
+        #[cfg_attr(
+            feature = "deserialize",
+            serde(default = "unresolved_reference")
+        )]
+        ty: fn() -> Type,
+    },
+
+    /// A socket address (an IP address and a port), e.g. `127.0.0.1:8080`
+    SocketAddr {
+        /// `Some(4)` for `SocketAddrV4`, `Some(6)` for `SocketAddrV6`, `None`
+        /// when both are accepted
+        version: Option<u8>,
+    },
+
     /// A UTF-8 string
     String,
 
@@ -45,8 +136,27 @@ pub enum TypeKind {
         /// Whether this type should behave as a passthrough-wrapper.
         /// Corresponds to `#[serde(transparent)]`.
         transparent: bool,
+
+        /// Whether this type rejects unknown fields.
+        /// Corresponds to `#[serde(deny_unknown_fields)]`.
+        deny_unknown_fields: bool,
+
+        /// Whether this type should be documented on its own, instead of being
+        /// unwrapped into its inner type as newtypes (`struct Foo(Bar);`) are
+        /// by default.
+        /// Corresponds to `#[doku(opaque)]`.
+        opaque: bool,
     },
 
+    /// A time of day, e.g. `12:00:00`
+    Time,
+
+    /// A URL, e.g. `https://example.com`
+    Url,
+
+    /// A UUID, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`
+    Uuid,
+
     /// A heterogeneous list of an up-front known size
     Tuple { fields: Vec<Type> },
 }
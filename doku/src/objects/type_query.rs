@@ -0,0 +1,106 @@
+use crate::*;
+
+impl Type {
+    /// Returns type of the value at given dotted path, if there's any:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use std::collections::BTreeMap;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     server: Option<Server>,
+    ///     upstreams: BTreeMap<String, Upstream>,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Server {
+    ///     /// Port to listen at
+    ///     #[doku(example = "8080")]
+    ///     port: u16,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Upstream {
+    ///     /// Upstream's URL
+    ///     url: String,
+    /// }
+    ///
+    /// let ty = Config::ty();
+    ///
+    /// let port = ty.at("server.port").unwrap();
+    /// let doc = doku::json::Printer::default().print(&port);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   // Port to listen at
+    ///   8080
+    /// "#, doc);
+    ///
+    /// // Maps accept any key
+    /// let url = ty.at("upstreams.backend.url").unwrap();
+    ///
+    /// assert_eq!(Some("Upstream's URL"), url.comment);
+    ///
+    /// assert!(ty.at("server.host").is_none());
+    /// ```
+    ///
+    /// The path goes through struct's fields (using their serialized names),
+    /// map's keys (any key matches), optionals, newtypes and flattened fields
+    /// (which are looked up as if they belonged to the parent struct); an
+    /// empty path returns this type.
+    ///
+    /// Since recursive types (see: `TypeKind::Reference`) are built lazily,
+    /// the returned type is an owned copy of the subtree.
+    pub fn at(&self, path: &str) -> Option<Type> {
+        if path.is_empty() {
+            return Some(self.clone());
+        }
+
+        let segments: Vec<_> = path.split('.').collect();
+
+        find_type(self, &segments)
+    }
+}
+
+fn find_type(ty: &Type, segments: &[&str]) -> Option<Type> {
+    let (segment, rest) = if let Some(segments) = segments.split_first() {
+        segments
+    } else {
+        return Some(ty.clone());
+    };
+
+    match &ty.kind {
+        TypeKind::Optional { ty } => find_type(ty, segments),
+
+        TypeKind::Reference { ty, .. } => find_type(&ty(), segments),
+
+        TypeKind::Map { value, .. } => find_type(value, rest),
+
+        TypeKind::Struct {
+            fields: Fields::Named { fields },
+            ..
+        } => {
+            let field = fields
+                .iter()
+                .find(|(name, field)| !field.flattened && name == segment);
+
+            if let Some((_, field)) = field {
+                return find_type(&field.ty, rest);
+            }
+
+            // Flattened fields are serialized straight into the parent
+            // object, so they get searched with the current segment included
+            fields
+                .iter()
+                .filter(|(_, field)| field.flattened)
+                .find_map(|(_, field)| find_type(&field.ty, segments))
+        }
+
+        TypeKind::Struct {
+            fields: Fields::Unnamed { fields },
+            ..
+        } if fields.len() == 1 => find_type(&fields[0].ty, segments),
+
+        _ => None,
+    }
+}
@@ -0,0 +1,177 @@
+use crate::*;
+
+/// A collection of all the named types (i.e. derived structs and enums)
+/// reachable from a root type, in order of their first appearance:
+///
+/// ```
+/// use doku::{Document, TypeRegistry};
+///
+/// #[derive(Document)]
+/// struct Config {
+///     db: Database,
+///     replicas: Vec<Database>,
+///     log: Option<Log>,
+/// }
+///
+/// #[derive(Document)]
+/// struct Database {
+///     url: String,
+/// }
+///
+/// #[derive(Document)]
+/// enum Log {
+///     Stdout,
+///     File { path: String },
+/// }
+///
+/// let registry = TypeRegistry::of::<Config>();
+///
+/// let names: Vec<_> = registry
+///     .names()
+///     .map(|name| name.rsplit("::").next().unwrap())
+///     .collect();
+///
+/// assert_eq!(vec!["Config", "Database", "Log"], names);
+///
+/// assert!(registry.get(std::any::type_name::<Database>()).is_some());
+/// ```
+///
+/// Recursive types (see: `TypeKind::Reference`) are resolved and registered
+/// once.
+#[derive(Clone, Debug, Default, Serialize)]
+#[cfg_attr(
+    feature = "deserialize",
+    derive(Deserialize),
+    serde(bound(deserialize = "'de: 'static"))
+)]
+#[serde(transparent)]
+pub struct TypeRegistry {
+    types: Vec<Type>,
+}
+
+impl TypeRegistry {
+    /// Creates a registry containing `T` and all the named types reachable
+    /// from it.
+    pub fn of<T>() -> Self
+    where
+        T: Document,
+    {
+        let mut this = Self::default();
+        this.collect(&T::ty());
+        this
+    }
+
+    /// Deserializes a registry serialized before (e.g. with `serde_json`),
+    /// leaking the parsed JSON - see: `Type::from_json()`.
+    #[cfg(feature = "deserialize")]
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        from_json(json)
+    }
+
+    /// Registers given type (if it's named) and all the named types reachable
+    /// from it.
+    pub fn collect(&mut self, ty: &Type) {
+        if let Some(name) = ty.name {
+            if self.get(name).is_some() {
+                return;
+            }
+
+            if let TypeKind::Reference { ty, .. } = ty.kind {
+                self.collect(&ty());
+                return;
+            }
+
+            self.types.push(ty.clone());
+        }
+
+        match &ty.kind {
+            TypeKind::Array { ty, .. } | TypeKind::Optional { ty } => {
+                self.collect(ty);
+            }
+
+            TypeKind::Enum { variants, .. } => {
+                for variant in variants {
+                    self.collect_fields(&variant.fields);
+                }
+            }
+
+            TypeKind::Map { key, value } => {
+                self.collect(key);
+                self.collect(value);
+            }
+
+            TypeKind::Reference { ty, .. } => {
+                self.collect(&ty());
+            }
+
+            TypeKind::Struct { fields, .. } => {
+                self.collect_fields(fields);
+            }
+
+            TypeKind::Tuple { fields } => {
+                for field in fields {
+                    self.collect(field);
+                }
+            }
+
+            TypeKind::Any
+            | TypeKind::Bool
+            | TypeKind::Bytes
+            | TypeKind::Date
+            | TypeKind::DateTime { .. }
+            | TypeKind::Decimal { .. }
+            | TypeKind::Duration
+            | TypeKind::Float { .. }
+            | TypeKind::Integer { .. }
+            | TypeKind::IpAddr { .. }
+            | TypeKind::Path
+            | TypeKind::SocketAddr { .. }
+            | TypeKind::String
+            | TypeKind::Time
+            | TypeKind::Url
+            | TypeKind::Uuid => (),
+        }
+    }
+
+    fn collect_fields(&mut self, fields: &Fields) {
+        match fields {
+            Fields::Named { fields } => {
+                for (_, field) in fields {
+                    self.collect(&field.ty);
+                }
+            }
+
+            Fields::Unnamed { fields } => {
+                for field in fields {
+                    self.collect(&field.ty);
+                }
+            }
+
+            Fields::Unit => (),
+        }
+    }
+
+    /// Returns type with given fully-qualified name (as returned by
+    /// `std::any::type_name()`).
+    pub fn get(&self, name: &str) -> Option<&Type> {
+        self.types.iter().find(|ty| ty.name == Some(name))
+    }
+
+    /// Returns fully-qualified names of all the registered types.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.types.iter().filter_map(|ty| ty.name)
+    }
+
+    /// Returns all the registered types.
+    pub fn iter(&self) -> impl Iterator<Item = &Type> {
+        self.types.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+}
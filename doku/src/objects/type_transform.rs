@@ -0,0 +1,129 @@
+use crate::*;
+
+/// Rewrites types of a type tree - see: `Type::transform()`.
+///
+/// This trait is implemented for all `FnMut(&mut Type)` closures, so usually
+/// there's no need to implement it manually.
+pub trait TypeTransform {
+    fn transform(&mut self, ty: &mut Type);
+}
+
+impl<F> TypeTransform for F
+where
+    F: FnMut(&mut Type),
+{
+    fn transform(&mut self, ty: &mut Type) {
+        self(ty)
+    }
+}
+
+impl Type {
+    /// Applies given transformation to this type and to all the types nested
+    /// inside it (fields, variants' fields, array items etc.), allowing to
+    /// e.g. rename or remove fields, or provide examples:
+    ///
+    /// ```
+    /// use doku::{Document, Type};
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Tenant's identifier
+    ///     tenant_id: String,
+    ///
+    ///     /// Address to listen at
+    ///     addr: String,
+    /// }
+    ///
+    /// let mut ty = Config::ty();
+    ///
+    /// ty.transform(&mut |ty: &mut Type| {
+    ///     if let Some(fields) = ty.named_fields_mut() {
+    ///         fields.retain(|(name, _)| *name != "tenant_id");
+    ///     }
+    ///
+    ///     if ty.comment == Some("Address to listen at") {
+    ///         ty.example = Some("127.0.0.1".into());
+    ///     }
+    /// });
+    ///
+    /// let doc = doku::json::Printer::default().print(&ty);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Address to listen at
+    ///     "addr": "127.0.0.1"
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// The transformation is applied top-down (i.e. to the parent before its
+    /// children), so types removed from the tree are not visited at all.
+    /// Types of recursive references (see: `TypeKind::Reference`) are built
+    /// lazily, so they are not transformed.
+    pub fn transform(&mut self, transform: &mut impl TypeTransform) {
+        transform.transform(self);
+
+        match &mut self.kind {
+            TypeKind::Array { ty, .. } | TypeKind::Optional { ty } => {
+                ty.transform(transform);
+            }
+
+            TypeKind::Enum { variants, .. } => {
+                for variant in variants {
+                    transform_fields(&mut variant.fields, transform);
+                }
+            }
+
+            TypeKind::Map { key, value } => {
+                key.transform(transform);
+                value.transform(transform);
+            }
+
+            TypeKind::Struct { fields, .. } => {
+                transform_fields(fields, transform);
+            }
+
+            TypeKind::Tuple { fields } => {
+                for ty in fields {
+                    ty.transform(transform);
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    /// Returns named fields of this struct, or `None` if this type is not a
+    /// struct with named fields.
+    pub fn named_fields_mut(
+        &mut self,
+    ) -> Option<&mut Vec<(&'static str, Field)>> {
+        if let TypeKind::Struct {
+            fields: Fields::Named { fields },
+            ..
+        } = &mut self.kind
+        {
+            Some(fields)
+        } else {
+            None
+        }
+    }
+}
+
+fn transform_fields(fields: &mut Fields, transform: &mut impl TypeTransform) {
+    match fields {
+        Fields::Named { fields } => {
+            for (_, field) in fields {
+                field.ty.transform(transform);
+            }
+        }
+
+        Fields::Unnamed { fields } => {
+            for field in fields {
+                field.ty.transform(transform);
+            }
+        }
+
+        Fields::Unit => (),
+    }
+}
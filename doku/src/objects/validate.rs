@@ -0,0 +1,395 @@
+use super::lint::join;
+use crate::*;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// A value that doesn't satisfy one of its type's constraints - see:
+/// `Type::validate()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// Path leading to the offending value, e.g. `server.port` or
+    /// `upstreams[0].url`; empty for the root value.
+    pub path: String,
+
+    /// Constraint that hasn't been satisfied.
+    pub constraint: Constraint,
+
+    /// Doc comment of the field the offending value belongs to.
+    pub comment: Option<&'static str>,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path)?;
+        }
+
+        match &self.constraint {
+            Constraint::Length { min, max } => match (min, max) {
+                (Some(min), Some(max)) => {
+                    write!(f, "length must be between {} and {}", min, max)
+                }
+                (Some(min), None) => {
+                    write!(f, "length must be at least {}", min)
+                }
+                (None, Some(max)) => {
+                    write!(f, "length must be at most {}", max)
+                }
+                (None, None) => write!(f, "invalid length"),
+            },
+
+            Constraint::Pattern(pattern) => {
+                write!(f, "must match pattern: {}", pattern)
+            }
+
+            Constraint::Range { min, max } => match (min, max) {
+                (Some(min), Some(max)) => {
+                    write!(f, "must be between {} and {}", min, max)
+                }
+                (Some(min), None) => write!(f, "must be at least {}", min),
+                (None, Some(max)) => write!(f, "must be at most {}", max),
+                (None, None) => write!(f, "out of range"),
+            },
+
+            Constraint::MultipleOf(value) => {
+                write!(f, "must be a multiple of {}", value)
+            }
+
+            Constraint::OneOf(values) => {
+                write!(f, "must be one of: {}", values.join(", "))
+            }
+        }
+    }
+}
+
+impl Type {
+    /// Checks given value against constraints of this type and of all the
+    /// types nested inside it (e.g. `#[doku(min = 1)]` on a field):
+    ///
+    /// ```
+    /// use doku::{Document, Value};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize, Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     #[doku(min = 1024)]
+    ///     port: u16,
+    ///
+    ///     #[doku(min_length = 1)]
+    ///     workers: Vec<String>,
+    /// }
+    ///
+    /// let config = Config {
+    ///     port: 80,
+    ///     workers: vec![],
+    /// };
+    ///
+    /// let violations: Vec<_> = Config::ty()
+    ///     .validate(&Value::from(&config))
+    ///     .into_iter()
+    ///     .map(|violation| violation.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         "port: must be at least 1024",
+    ///         "workers: length must be at least 1",
+    ///     ],
+    ///     violations,
+    /// );
+    /// ```
+    ///
+    /// `Constraint::Pattern` is checked only when the `regex-1` feature is
+    /// enabled; values of enums' variants are not checked.
+    pub fn validate(&self, val: &Value) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        validate_type(&mut violations, "", self, None, val);
+        violations
+    }
+}
+
+fn validate_type(
+    out: &mut Vec<Violation>,
+    path: &str,
+    ty: &Type,
+    comment: Option<&'static str>,
+    val: &Value,
+) {
+    if let Value::None = val {
+        return;
+    }
+
+    let comment = ty.comment.or(comment);
+
+    // Newtypes are serialized as one-element arrays, while their constraints
+    // refer to the inner value
+    let val = match (&ty.kind, val) {
+        (
+            TypeKind::Struct {
+                fields: Fields::Unnamed { fields },
+                transparent: false,
+                ..
+            },
+            Value::Array(items),
+        ) if fields.len() == 1 && items.len() == 1 => &items[0],
+        _ => val,
+    };
+
+    for constraint in &ty.constraints {
+        if !satisfies(constraint, val) {
+            out.push(Violation {
+                path: path.to_owned(),
+                constraint: constraint.clone(),
+                comment,
+            });
+        }
+    }
+
+    match (&ty.kind, val) {
+        (TypeKind::Optional { ty }, val) => {
+            validate_type(out, path, ty, comment, val);
+        }
+
+        (TypeKind::Reference { ty, .. }, val) => {
+            validate_type(out, path, &ty(), comment, val);
+        }
+
+        (TypeKind::Array { ty, .. }, Value::Array(items)) => {
+            for (idx, item) in items.iter().enumerate() {
+                let path = format!("{}[{}]", path, idx);
+
+                validate_type(out, &path, ty, comment, item);
+            }
+        }
+
+        (TypeKind::Tuple { fields }, Value::Array(items)) => {
+            for (idx, (ty, item)) in fields.iter().zip(items).enumerate() {
+                let path = format!("{}[{}]", path, idx);
+
+                validate_type(out, &path, ty, comment, item);
+            }
+        }
+
+        (TypeKind::Map { value, .. }, Value::Map(entries)) => {
+            for (key, val) in entries {
+                let key = if let Value::String(key) = key {
+                    key.clone()
+                } else {
+                    number_to_string(key).unwrap_or_else(|| "?".to_owned())
+                };
+
+                validate_type(out, &join(path, &key), value, comment, val);
+            }
+        }
+
+        (TypeKind::Struct { fields, .. }, val) => {
+            validate_fields(out, path, fields, comment, val);
+        }
+
+        _ => (),
+    }
+}
+
+fn validate_fields(
+    out: &mut Vec<Violation>,
+    path: &str,
+    fields: &Fields,
+    comment: Option<&'static str>,
+    val: &Value,
+) {
+    match fields {
+        Fields::Named { fields } => {
+            for (name, field) in fields {
+                // Flattened fields are serialized straight into the parent
+                // object
+                if field.flattened {
+                    validate_type(out, path, &field.ty, None, val);
+                } else if let Some(val) = val.as_struct_named_field(name) {
+                    validate_type(out, &join(path, name), &field.ty, None, val);
+                }
+            }
+        }
+
+        Fields::Unnamed { fields } => {
+            // Newtypes have been already unwrapped by `validate_type()`
+            if let [field] = fields.as_slice() {
+                validate_type(out, path, &field.ty, comment, val);
+                return;
+            }
+
+            for (idx, field) in fields.iter().enumerate() {
+                if let Some(val) = val.as_struct_unnamed_field(idx) {
+                    let path = format!("{}[{}]", path, idx);
+
+                    validate_type(out, &path, &field.ty, comment, val);
+                }
+            }
+        }
+
+        Fields::Unit => (),
+    }
+}
+
+/// Returns whether given value satisfies given constraint; constraints that
+/// don't apply to the value (e.g. `Range` for a string) are considered
+/// satisfied.
+fn satisfies(constraint: &Constraint, val: &Value) -> bool {
+    match constraint {
+        Constraint::Length { min, max } => {
+            let len = match val {
+                Value::String(val) => val.chars().count(),
+                Value::Array(items) => items.len(),
+                Value::Map(entries) => entries.len(),
+                _ => return true,
+            };
+
+            min.map_or(true, |min| len >= min)
+                && max.map_or(true, |max| len <= max)
+        }
+
+        Constraint::Pattern(pattern) => {
+            if let Value::String(val) = val {
+                matches_pattern(pattern, val)
+            } else {
+                true
+            }
+        }
+
+        Constraint::Range { min, max } => {
+            let val = if let Some(val) = Number::from_value(val) {
+                val
+            } else {
+                return true;
+            };
+
+            let within_min = min
+                .and_then(Number::parse)
+                .map_or(true, |min| val.compare(min) != Some(Ordering::Less));
+
+            let within_max = max.and_then(Number::parse).map_or(true, |max| {
+                val.compare(max) != Some(Ordering::Greater)
+            });
+
+            within_min && within_max
+        }
+
+        Constraint::MultipleOf(multiple) => {
+            match (Number::from_value(val), Number::parse(multiple)) {
+                (Some(Number::Int(val)), Some(Number::Int(multiple))) => {
+                    multiple == 0 || val % multiple == 0
+                }
+                (Some(val), Some(multiple)) => {
+                    let multiple = multiple.as_f64();
+
+                    if multiple == 0.0 {
+                        return true;
+                    }
+
+                    // Most of the decimal fractions can't be represented
+                    // exactly (e.g. `0.3 / 0.1` is `2.9999999999999996`), so
+                    // we have to allow for some rounding error - and a pretty
+                    // generous one, since the value might've been an `f32`
+                    let quotient = val.as_f64() / multiple;
+
+                    (quotient - quotient.round()).abs()
+                        <= 1e-6 * quotient.abs().max(1.0)
+                }
+                _ => true,
+            }
+        }
+
+        Constraint::OneOf(values) => {
+            let val = match val {
+                Value::String(val) => val.clone(),
+                Value::Char(val) => val.to_string(),
+                Value::Bool(val) => val.to_string(),
+                Value::F32(val) => val.to_string(),
+                Value::F64(val) => val.to_string(),
+                val => {
+                    if let Some(val) = number_to_string(val) {
+                        val
+                    } else {
+                        return true;
+                    }
+                }
+            };
+
+            values.contains(&val.as_str())
+        }
+    }
+}
+
+#[cfg(feature = "regex-1")]
+fn matches_pattern(pattern: &str, val: &str) -> bool {
+    // Invalid patterns can't be validated against, so - just like unknown
+    // constraints - they are considered satisfied
+    regex::Regex::new(pattern).map_or(true, |pattern| pattern.is_match(val))
+}
+
+#[cfg(not(feature = "regex-1"))]
+fn matches_pattern(_: &str, _: &str) -> bool {
+    true
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+impl Number {
+    fn from_value(val: &Value) -> Option<Self> {
+        Some(match *val {
+            Value::F32(val) => Self::Float(val.into()),
+            Value::F64(val) => Self::Float(val),
+            Value::U8(val) => Self::Int(val.into()),
+            Value::I8(val) => Self::Int(val.into()),
+            Value::U16(val) => Self::Int(val.into()),
+            Value::I16(val) => Self::Int(val.into()),
+            Value::U32(val) => Self::Int(val.into()),
+            Value::I32(val) => Self::Int(val.into()),
+            Value::U64(val) => Self::Int(val.into()),
+            Value::I64(val) => Self::Int(val.into()),
+            Value::U128(val) => {
+                i128::try_from(val).map_or(Self::Float(val as f64), Self::Int)
+            }
+            Value::I128(val) => Self::Int(val),
+            Value::Usize(val) => Self::Int(val as i128),
+            Value::Isize(val) => Self::Int(val as i128),
+            _ => return None,
+        })
+    }
+
+    fn parse(val: &str) -> Option<Self> {
+        let val = val.replace('_', "");
+
+        val.parse()
+            .map(Self::Int)
+            .or_else(|_| val.parse().map(Self::Float))
+            .ok()
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Self::Int(val) => val as f64,
+            Self::Float(val) => val,
+        }
+    }
+
+    fn compare(self, other: Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => Some(a.cmp(&b)),
+            (a, b) => a.as_f64().partial_cmp(&b.as_f64()),
+        }
+    }
+}
+
+fn number_to_string(val: &Value) -> Option<String> {
+    Number::from_value(val).map(|val| match val {
+        Number::Int(val) => val.to_string(),
+        Number::Float(val) => val.to_string(),
+    })
+}
@@ -3,7 +3,7 @@ mod serializer;
 use self::serializer::*;
 use crate::*;
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum Value {
     /// Value of [`TypeKind::Optional`].
     None,
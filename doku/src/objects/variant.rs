@@ -1,6 +1,7 @@
 use crate::*;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[cfg_attr(feature = "deserialize", derive(Deserialize))]
 pub struct Variant {
     /// Identifier of the variant; it includes `#[serde(rename)]` and similar
     /// attributes, so this string is exactly what gets serialized into the
@@ -11,7 +12,31 @@ pub struct Variant {
     /// stuff like `#[serde(rename)]`.
     pub title: &'static str,
 
+    /// Alternative identifiers accepted during deserialization (think
+    /// `#[serde(alias = "...")]`).
+    pub aliases: Vec<&'static str>,
+
     pub comment: Option<&'static str>,
+
+    /// Version this variant has been introduced in (think
+    /// `#[doku(since = "2.3")]`).
+    pub since: Option<&'static str>,
+
+    /// Version this variant has been removed in (think
+    /// `#[doku(until = "3.0")]`).
+    pub until: Option<&'static str>,
+
+    /// Whether this variant is a catch-all for all the unknown values (think
+    /// `#[serde(other)]`).
+    pub other: bool,
+
+    /// Whether this variant is marked as `#[doc(hidden)]`.
+    pub hidden: bool,
+
+    /// Discriminant of the variant (think `Info = 1`); for variants without
+    /// an explicit discriminant, it's inferred the same way rustc does it.
+    pub discriminant: Option<&'static str>,
+
     pub serializable: bool,
     pub deserializable: bool,
     pub fields: Fields,
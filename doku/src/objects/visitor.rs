@@ -0,0 +1,270 @@
+use crate::*;
+
+/// Traverses a type tree, calling a method for each kind of node found along
+/// the way:
+///
+/// ```
+/// use doku::{Document, Field, Visitor};
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Address to listen at
+///     addr: String,
+///
+///     #[doku(flatten)]
+///     db: Db,
+///
+///     workers: Vec<Worker>,
+/// }
+///
+/// #[derive(Document)]
+/// struct Db {
+///     /// Database's URL
+///     db_url: String,
+/// }
+///
+/// #[derive(Document)]
+/// struct Worker {
+///     name: String,
+/// }
+///
+/// #[derive(Default)]
+/// struct DocumentedFields(Vec<String>);
+///
+/// impl<'ty> Visitor<'ty> for DocumentedFields {
+///     fn visit_field(&mut self, name: &'static str, field: &'ty Field) {
+///         if let Some(comment) = field.ty.comment {
+///             self.0.push(format!("{}: {}", name, comment));
+///         }
+///
+///         self.visit_type(&field.ty);
+///     }
+/// }
+///
+/// let ty = Config::ty();
+/// let mut visitor = DocumentedFields::default();
+///
+/// visitor.visit_type(&ty);
+///
+/// assert_eq!(
+///     vec!["addr: Address to listen at", "db_url: Database's URL"],
+///     visitor.0,
+/// );
+/// ```
+///
+/// Each method's default implementation visits the node's children (see:
+/// `walk_type()` and friends), so an overridden method can call it back to
+/// continue the traversal - or not, to skip the node's children.
+///
+/// Flattened fields (`#[serde(flatten)]`) and newtypes (`struct Foo(Bar)`)
+/// are visited through their inner types, as if their fields belonged to the
+/// parent - matching the way they get serialized. Recursive types (see:
+/// `TypeKind::Reference`) are not walked into.
+pub trait Visitor<'ty> {
+    fn visit_type(&mut self, ty: &'ty Type) {
+        walk_type(self, ty);
+    }
+
+    fn visit_array(
+        &mut self,
+        ty: &'ty Type,
+        item: &'ty Type,
+        size: Option<usize>,
+    ) {
+        let _ = (ty, size);
+
+        self.visit_type(item);
+    }
+
+    fn visit_enum(
+        &mut self,
+        ty: &'ty Type,
+        tag: Tag,
+        variants: &'ty [Variant],
+    ) {
+        let _ = ty;
+
+        walk_variants(self, tag, variants);
+    }
+
+    /// Visits given variant; `tag` determines how the variant gets
+    /// represented (see: `Tag`).
+    fn visit_variant(&mut self, tag: Tag, variant: &'ty Variant) {
+        let _ = tag;
+
+        self.visit_fields(&variant.fields);
+    }
+
+    fn visit_map(&mut self, ty: &'ty Type, key: &'ty Type, value: &'ty Type) {
+        let _ = ty;
+
+        self.visit_type(key);
+        self.visit_type(value);
+    }
+
+    fn visit_optional(&mut self, ty: &'ty Type, inner: &'ty Type) {
+        let _ = ty;
+
+        self.visit_type(inner);
+    }
+
+    fn visit_struct(&mut self, ty: &'ty Type, fields: &'ty Fields) {
+        let _ = ty;
+
+        self.visit_fields(fields);
+    }
+
+    fn visit_tuple(&mut self, ty: &'ty Type, items: &'ty [Type]) {
+        let _ = ty;
+
+        for (idx, item) in items.iter().enumerate() {
+            self.visit_tuple_item(idx, item);
+        }
+    }
+
+    fn visit_tuple_item(&mut self, idx: usize, item: &'ty Type) {
+        let _ = idx;
+
+        self.visit_type(item);
+    }
+
+    /// Visits fields of a struct or of an enum's variant.
+    fn visit_fields(&mut self, fields: &'ty Fields) {
+        walk_fields(self, fields);
+    }
+
+    /// Visits a named field (`struct Foo { bar: Bar }`).
+    fn visit_field(&mut self, name: &'static str, field: &'ty Field) {
+        let _ = name;
+
+        self.visit_type(&field.ty);
+    }
+
+    /// Visits a field marked with `#[serde(flatten)]`.
+    fn visit_flattened_field(&mut self, field: &'ty Field) {
+        self.visit_type(&field.ty);
+    }
+
+    /// Visits the only field of a newtype (`struct Foo(Bar)`).
+    fn visit_newtype_field(&mut self, field: &'ty Field) {
+        self.visit_type(&field.ty);
+    }
+
+    /// Visits an unnamed field of a struct with more than one of them
+    /// (`struct Foo(Bar, Zar)`).
+    fn visit_unnamed_field(&mut self, idx: usize, field: &'ty Field) {
+        let _ = idx;
+
+        self.visit_type(&field.ty);
+    }
+
+    /// Visits a reference to a type that's already being visited somewhere up
+    /// the tree.
+    fn visit_reference(&mut self, ty: &'ty Type, name: &'static str) {
+        let _ = (ty, name);
+    }
+
+    /// Visits a type without any children (e.g. `TypeKind::String`).
+    fn visit_scalar(&mut self, ty: &'ty Type) {
+        let _ = ty;
+    }
+}
+
+/// Dispatches given type to the visitor's method matching its kind.
+pub fn walk_type<'ty, V>(visitor: &mut V, ty: &'ty Type)
+where
+    V: Visitor<'ty> + ?Sized,
+{
+    match &ty.kind {
+        TypeKind::Array { ty: item, size } => {
+            visitor.visit_array(ty, item, *size);
+        }
+
+        TypeKind::Enum { tag, variants, .. } => {
+            visitor.visit_enum(ty, *tag, variants);
+        }
+
+        TypeKind::Map { key, value } => {
+            visitor.visit_map(ty, key, value);
+        }
+
+        TypeKind::Optional { ty: inner } => {
+            visitor.visit_optional(ty, inner);
+        }
+
+        TypeKind::Reference { name, .. } => {
+            visitor.visit_reference(ty, name);
+        }
+
+        TypeKind::Struct { fields, .. } => {
+            visitor.visit_struct(ty, fields);
+        }
+
+        TypeKind::Tuple { fields } => {
+            visitor.visit_tuple(ty, fields);
+        }
+
+        TypeKind::Any
+        | TypeKind::Bool
+        | TypeKind::Bytes
+        | TypeKind::Date
+        | TypeKind::DateTime { .. }
+        | TypeKind::Decimal { .. }
+        | TypeKind::Duration
+        | TypeKind::Float { .. }
+        | TypeKind::Integer { .. }
+        | TypeKind::IpAddr { .. }
+        | TypeKind::Path
+        | TypeKind::SocketAddr { .. }
+        | TypeKind::String
+        | TypeKind::Time
+        | TypeKind::Url
+        | TypeKind::Uuid => {
+            visitor.visit_scalar(ty);
+        }
+    }
+}
+
+/// Visits each of given variants.
+pub fn walk_variants<'ty, V>(
+    visitor: &mut V,
+    tag: Tag,
+    variants: &'ty [Variant],
+) where
+    V: Visitor<'ty> + ?Sized,
+{
+    for variant in variants {
+        visitor.visit_variant(tag, variant);
+    }
+}
+
+/// Visits each of given fields, telling apart named, flattened, newtype and
+/// unnamed ones.
+pub fn walk_fields<'ty, V>(visitor: &mut V, fields: &'ty Fields)
+where
+    V: Visitor<'ty> + ?Sized,
+{
+    match fields {
+        Fields::Named { fields } => {
+            for (name, field) in fields {
+                if field.flattened {
+                    visitor.visit_flattened_field(field);
+                } else {
+                    visitor.visit_field(name, field);
+                }
+            }
+        }
+
+        Fields::Unnamed { fields } => {
+            if let [field] = fields.as_slice() {
+                visitor.visit_newtype_field(field);
+            } else {
+                for (idx, field) in fields.iter().enumerate() {
+                    visitor.visit_unnamed_field(idx, field);
+                }
+            }
+        }
+
+        Fields::Unit => (),
+    }
+}
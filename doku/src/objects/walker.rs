@@ -0,0 +1,162 @@
+use crate::*;
+use std::{mem, vec};
+
+/// Iterates over all the fields reachable from a type, assigning each one a
+/// stable, path-based identifier:
+///
+/// ```
+/// use doku::{Document, Walker};
+/// use std::collections::BTreeMap;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     server: Server,
+///     upstreams: Vec<Upstream>,
+///     databases: BTreeMap<String, Database>,
+/// }
+///
+/// #[derive(Document)]
+/// struct Server {
+///     port: u16,
+///     tls: Option<Tls>,
+/// }
+///
+/// #[derive(Document)]
+/// struct Tls {
+///     cert: String,
+/// }
+///
+/// #[derive(Document)]
+/// struct Upstream {
+///     url: String,
+/// }
+///
+/// #[derive(Document)]
+/// #[doku(rename_all = "lowercase")]
+/// enum Database {
+///     Sqlite { path: String },
+/// }
+///
+/// let ty = Config::ty();
+/// let ids: Vec<_> = Walker::new(&ty).map(|field| field.id).collect();
+///
+/// assert_eq!(
+///     vec![
+///         "server",
+///         "server.port",
+///         "server.tls",
+///         "server.tls.cert",
+///         "upstreams",
+///         "upstreams[].url",
+///         "databases",
+///         "databases.*.sqlite.path",
+///     ],
+///     ids,
+/// );
+/// ```
+///
+/// Identifiers are built out of fields' serialized names, so they stay the same
+/// as long as the serialized representation does:
+///
+/// - flattened fields and newtypes don't introduce a segment of their own,
+/// - array items are denoted with `[]`, map values - with `*`,
+/// - fields of enum variants are nested under the variant's identifier.
+///
+/// Recursive types (see: `TypeKind::Reference`) are not walked into.
+#[derive(Debug)]
+pub struct Walker<'ty> {
+    fields: vec::IntoIter<WalkedField<'ty>>,
+}
+
+/// A field visited by `Walker`.
+#[derive(Clone, Debug)]
+pub struct WalkedField<'ty> {
+    /// E.g. `server.tls.cert`
+    pub id: String,
+    pub field: &'ty Field,
+}
+
+impl<'ty> Walker<'ty> {
+    pub fn new(ty: &'ty Type) -> Self {
+        let mut collector = Collector::default();
+
+        collector.visit_type(ty);
+
+        Self {
+            fields: collector.fields.into_iter(),
+        }
+    }
+}
+
+impl<'ty> Iterator for Walker<'ty> {
+    type Item = WalkedField<'ty>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.fields.next()
+    }
+}
+
+#[derive(Default)]
+struct Collector<'ty> {
+    fields: Vec<WalkedField<'ty>>,
+
+    /// Identifier of the node that's being visited
+    id: String,
+}
+
+impl<'ty> Collector<'ty> {
+    /// Visits given type, with given identifier.
+    fn visit_type_as(&mut self, id: String, ty: &'ty Type) {
+        let id = mem::replace(&mut self.id, id);
+
+        self.visit_type(ty);
+        self.id = id;
+    }
+
+    fn push_field(&mut self, id: String, field: &'ty Field) {
+        self.fields.push(WalkedField {
+            id: id.clone(),
+            field,
+        });
+
+        self.visit_type_as(id, &field.ty);
+    }
+}
+
+impl<'ty> Visitor<'ty> for Collector<'ty> {
+    fn visit_array(&mut self, _: &'ty Type, item: &'ty Type, _: Option<usize>) {
+        self.visit_type_as(format!("{}[]", self.id), item);
+    }
+
+    fn visit_variant(&mut self, _: Tag, variant: &'ty Variant) {
+        let id = join(&self.id, variant.id);
+        let id = mem::replace(&mut self.id, id);
+
+        self.visit_fields(&variant.fields);
+        self.id = id;
+    }
+
+    fn visit_map(&mut self, _: &'ty Type, _: &'ty Type, value: &'ty Type) {
+        self.visit_type_as(join(&self.id, "*"), value);
+    }
+
+    fn visit_tuple_item(&mut self, idx: usize, item: &'ty Type) {
+        self.visit_type_as(join(&self.id, &idx.to_string()), item);
+    }
+
+    fn visit_field(&mut self, name: &'static str, field: &'ty Field) {
+        self.push_field(join(&self.id, name), field);
+    }
+
+    fn visit_unnamed_field(&mut self, idx: usize, field: &'ty Field) {
+        self.push_field(join(&self.id, &idx.to_string()), field);
+    }
+}
+
+fn join(id: &str, segment: &str) -> String {
+    if id.is_empty() {
+        segment.to_owned()
+    } else {
+        format!("{}.{}", id, segment)
+    }
+}
@@ -2,6 +2,7 @@ mod config;
 pub mod json;
 
 mod prelude {
+    pub(crate) use super::is_available;
     pub use super::Visibility;
     pub use crate::*;
     pub use std::fmt::Write;
@@ -1,3 +1,5 @@
+mod version;
 mod visibility;
 
+pub(crate) use self::version::*;
 pub use self::visibility::*;
@@ -0,0 +1,82 @@
+use std::cmp::Ordering;
+
+/// Returns whether something introduced in `since` and removed in `until` is
+/// available in given `version`.
+///
+/// Both bounds are optional; `since` is inclusive, while `until` is exclusive -
+/// i.e. a field with `#[doku(since = "1.2", until = "2.0")]` is available in
+/// `1.2`, `1.3` and `1.9.1`, but not in `1.1` or `2.0`.
+pub(crate) fn is_available(
+    version: &str,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> bool {
+    let since_ok = since.map_or(true, |since| {
+        compare_versions(version, since) != Ordering::Less
+    });
+
+    let until_ok = until.map_or(true, |until| {
+        compare_versions(version, until) == Ordering::Less
+    });
+
+    since_ok && until_ok
+}
+
+/// Compares two dotted versions (e.g. `1.2` and `v1.10.0`) segment by segment,
+/// treating missing segments as zeros.
+pub(crate) fn compare_versions(a: &str, b: &str) -> Ordering {
+    let segments = |version: &str| -> Vec<String> {
+        version
+            .trim()
+            .trim_start_matches('v')
+            .split('.')
+            .map(ToOwned::to_owned)
+            .collect()
+    };
+
+    let (a, b) = (segments(a), segments(b));
+
+    for idx in 0..a.len().max(b.len()) {
+        let a = a.get(idx).map(String::as_str).unwrap_or("0");
+        let b = b.get(idx).map(String::as_str).unwrap_or("0");
+
+        let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+            (Ok(a), Ok(b)) => a.cmp(&b),
+            _ => a.cmp(b),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("1.2", "1.2" => Ordering::Equal ; "same")]
+    #[test_case("1.2", "1.2.0" => Ordering::Equal ; "missing segment")]
+    #[test_case("v1.2", "1.2" => Ordering::Equal ; "prefix")]
+    #[test_case("1.2", "1.10" => Ordering::Less ; "numeric")]
+    #[test_case("2.0", "1.10" => Ordering::Greater ; "major")]
+    fn compares(a: &str, b: &str) -> Ordering {
+        compare_versions(a, b)
+    }
+
+    #[test_case("1.1", Some("1.2"), None => false ; "before since")]
+    #[test_case("1.2", Some("1.2"), None => true ; "at since")]
+    #[test_case("1.9", Some("1.2"), Some("2.0") => true ; "between")]
+    #[test_case("2.0", Some("1.2"), Some("2.0") => false ; "at until")]
+    #[test_case("0.1", None, None => true ; "unbounded")]
+    fn checks_availability(
+        version: &str,
+        since: Option<&str>,
+        until: Option<&str>,
+    ) -> bool {
+        is_available(version, since, until)
+    }
+}
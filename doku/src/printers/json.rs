@@ -1,28 +1,47 @@
+mod comments;
 mod ctxt;
 mod formatting;
 mod output;
 mod print_array;
+mod print_bytes;
+mod print_cfg;
 mod print_comment;
+mod print_constraints;
+mod print_diff;
 mod print_enum;
+mod print_env;
 mod print_fields;
+mod print_formats;
+mod print_inline;
+mod print_literal;
+mod print_location;
 mod print_map;
 mod print_optional;
+mod print_reference;
 mod print_scalar;
+mod print_sensitive;
 mod print_struct;
 mod print_tuple;
+mod print_unit;
+mod print_unset;
+mod print_versions;
 mod value_to_string;
 
 use self::{ctxt::*, output::*};
 use crate::printers::prelude::*;
 use std::borrow::Cow;
+use std::cell::RefCell;
 
-pub use self::formatting::*;
+pub use self::{comments::CommentInfo, formatting::*};
 
 #[derive(Debug, Default)]
 pub struct Printer<'a> {
     visibility: Visibility,
+    version: Option<&'a str>,
     formatting: Option<&'a Formatting>,
     value: Option<&'a Value>,
+    partial: bool,
+    old_value: Option<&'a Value>,
 }
 
 impl<'a> Printer<'a> {
@@ -64,6 +83,60 @@ impl<'a> Printer<'a> {
         self
     }
 
+    /// Specifies which version of the type should be documented - fields and
+    /// variants annotated with `#[doku(since = "...")]` or
+    /// `#[doku(until = "...")]` that are not available in that version get
+    /// skipped:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use doku::json::*;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     host: String,
+    ///
+    ///     #[doku(since = "2.0")]
+    ///     port: u16,
+    ///
+    ///     #[doku(until = "2.0")]
+    ///     address: String,
+    /// }
+    ///
+    /// let doc = Printer::default()
+    ///     .with_version("1.5")
+    ///     .print(&Config::ty());
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "host": "string",
+    ///     // Until v2.0
+    ///     "address": "string"
+    ///   }
+    /// "#, doc);
+    ///
+    /// let doc = Printer::default()
+    ///     .with_version("2.1")
+    ///     .print(&Config::ty());
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "host": "string",
+    ///     // Since v2.0
+    ///     "port": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub fn set_version(&mut self, value: &'a str) {
+        self.version = Some(value);
+    }
+
+    /// A consuming variant of [`Self::set_version()`].
+    pub fn with_version(mut self, value: &'a str) -> Self {
+        self.set_version(value);
+        self
+    }
+
     pub fn set_formatting(&mut self, value: &'a Formatting) {
         self.formatting = Some(value);
     }
@@ -84,6 +157,109 @@ impl<'a> Printer<'a> {
         self
     }
 
+    /// Like [`Self::set_value()`], but for values that might be missing some
+    /// of the fields (e.g. user's current configuration) - fields present in
+    /// the value get printed as they are, while the missing ones get printed
+    /// using their examples and marked as unset:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use doku::json::Printer;
+    /// use doku::Value;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at
+    ///     #[doku(example = "127.0.0.1")]
+    ///     addr: String,
+    ///
+    ///     /// Port to listen at
+    ///     port: u16,
+    /// }
+    ///
+    /// let val = Value::from(&serde_json::json!({
+    ///     "port": 8080,
+    /// }));
+    ///
+    /// let doc = Printer::default()
+    ///     .with_partial_value(&val)
+    ///     .print(&Config::ty());
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Address to listen at; unset, default shown
+    ///     "addr": "127.0.0.1",
+    ///     // Port to listen at
+    ///     "port": 8080
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub fn set_partial_value(&mut self, value: &'a Value) {
+        self.value = Some(value);
+        self.partial = true;
+    }
+
+    /// A consuming variant of [`Self::set_partial_value()`].
+    pub fn with_partial_value(mut self, value: &'a Value) -> Self {
+        self.set_partial_value(value);
+        self
+    }
+
+    /// Specifies the previous version of the value (see:
+    /// [`Self::set_value()`]), turning the document into a diff - fields
+    /// that have been added, removed or changed get marked as such, and keys
+    /// that don't correspond to any of the fields get listed:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use doku::json::Printer;
+    /// use doku::Value;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at
+    ///     addr: String,
+    ///
+    ///     /// Port to listen at
+    ///     port: u16,
+    /// }
+    ///
+    /// let old = Value::from(&serde_json::json!({
+    ///     "addr": "localhost",
+    ///     "port": 80,
+    /// }));
+    ///
+    /// let new = Value::from(&serde_json::json!({
+    ///     "addr": "localhost",
+    ///     "port": 8080,
+    ///     "workers": 4,
+    /// }));
+    ///
+    /// let doc = Printer::default()
+    ///     .with_value(&new)
+    ///     .with_old_value(&old)
+    ///     .print(&Config::ty());
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Address to listen at
+    ///     "addr": "localhost",
+    ///     // Port to listen at; changed from 80
+    ///     "port": 8080
+    ///     // Unknown key: workers
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub fn set_old_value(&mut self, value: &'a Value) {
+        self.old_value = Some(value);
+    }
+
+    /// A consuming variant of [`Self::set_old_value()`].
+    pub fn with_old_value(mut self, value: &'a Value) -> Self {
+        self.set_old_value(value);
+        self
+    }
+
     pub fn print(&self, ty: &'a Type) -> String {
         let fmt = self
             .formatting
@@ -91,21 +267,300 @@ impl<'a> Printer<'a> {
             .unwrap_or_else(|| Cow::Owned(Default::default()));
 
         let mut out = Output::new(fmt.as_ref());
+        let definitions = RefCell::new(vec![ty.clone()]);
 
         Ctxt {
             ty,
             val: self.value,
+            partial: self.partial,
+            old_val: self.old_value,
+            diff: self.old_value.is_some(),
             vis: self.visibility,
+            ver: self.version,
             fmt: fmt.as_ref(),
             out: &mut out,
             is_key: Default::default(),
+            is_field: Default::default(),
             parent: Default::default(),
             example: Default::default(),
             flat: Default::default(),
+            definitions: &definitions,
             depth: Default::default(),
+            level: Default::default(),
+            path: Default::default(),
         }
         .print();
 
-        out.render()
+        let mut doc = String::new();
+
+        if let Some(header) = &fmt.header {
+            let header = self.print_banner(header, ty, &fmt);
+
+            if !header.is_empty() {
+                doc.push_str(&header);
+                doc.push('\n');
+            }
+        }
+
+        // Optionals don't have headers on their own, so - e.g. when printing
+        // just a part of the document - we've got to look into the inner type
+        let header = match &ty.kind {
+            TypeKind::Optional { ty: inner } if ty.header.is_none() => {
+                inner.header
+            }
+            _ => ty.header,
+        };
+
+        if let (DocHeader::Visible, Some(header), false) =
+            (&fmt.doc_header, header, fmt.comments_style.hidden)
+        {
+            let header = fmt.comments_style.reflow(header);
+
+            for line in fmt.comments_style.fit(header.split('\n'), 0) {
+                let line = fmt.comments_style.render(&line);
+                swrite!(doc, "{}\n", line.trim_end());
+            }
+
+            doc.push('\n');
+        }
+
+        doc.push_str(&out.render());
+
+        // Printing a definition might register further ones, so we can't just
+        // iterate over `definitions` here; also, the first definition is the
+        // root type, which we've just printed
+        for idx in 1.. {
+            let def = definitions.borrow().get(idx).cloned();
+
+            if let Some(def) = def {
+                doc.push_str("\n\n");
+                doc.push_str(&self.print_definition(&def, &fmt, &definitions));
+            } else {
+                break;
+            }
+        }
+
+        if let Some(footer) = &fmt.footer {
+            let footer = self.print_banner(footer, ty, &fmt);
+
+            if !footer.is_empty() {
+                doc.push_str("\n\n");
+                doc.push_str(footer.trim_end());
+            }
+        }
+
+        #[cfg(feature = "color")]
+        if let Some(colors) = &fmt.colors {
+            doc = colors.paint(&doc, &fmt.comments_style);
+        }
+
+        fmt.line_endings.apply(doc)
     }
+
+    /// Returns doc-comments and hints of all the fields of given type, without
+    /// rendering the document (e.g. to display them as tooltips):
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use doku::json::{CommentInfo, Printer};
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     server: Option<Server>,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Server {
+    ///     /// Port to listen at
+    ///     #[doku(min = 1024)]
+    ///     port: u16,
+    /// }
+    ///
+    /// let comments = Printer::default().comments(&Config::ty());
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         (
+    ///             "server".to_string(),
+    ///             CommentInfo {
+    ///                 comment: None,
+    ///                 hints: vec!["Optional".to_string()],
+    ///             },
+    ///         ),
+    ///         (
+    ///             "server.port".to_string(),
+    ///             CommentInfo {
+    ///                 comment: Some("Port to listen at"),
+    ///                 hints: vec!["Must be at least 1024".to_string()],
+    ///             },
+    ///         ),
+    ///     ],
+    ///     comments,
+    /// );
+    /// ```
+    ///
+    /// Fields are keyed by their dotted paths (the same ones as accepted by
+    /// `Type::at()`), with `*` standing for map keys; fields without any
+    /// comments are skipped, as are fields of arrays and enums. Values (see:
+    /// [`Self::set_value()`]) are not taken into account.
+    pub fn comments(&self, ty: &'a Type) -> Vec<(String, CommentInfo)> {
+        let fmt = self
+            .formatting
+            .map(Cow::Borrowed)
+            .unwrap_or_else(|| Cow::Owned(Default::default()));
+
+        let mut out = Output::new(fmt.as_ref());
+        let definitions = RefCell::new(vec![ty.clone()]);
+        let mut comments = Vec::new();
+
+        Ctxt {
+            ty,
+            val: None,
+            partial: false,
+            old_val: None,
+            diff: false,
+            vis: self.visibility,
+            ver: self.version,
+            fmt: fmt.as_ref(),
+            out: &mut out,
+            is_key: Default::default(),
+            is_field: Default::default(),
+            parent: Default::default(),
+            example: Default::default(),
+            flat: Default::default(),
+            definitions: &definitions,
+            depth: Default::default(),
+            level: Default::default(),
+            path: Default::default(),
+        }
+        .collect_comments("", &mut comments);
+
+        comments
+    }
+
+    /// Prints `Formatting::header` or `Formatting::footer`, as a comment.
+    fn print_banner(
+        &self,
+        banner: &str,
+        ty: &Type,
+        fmt: &Formatting,
+    ) -> String {
+        let mut doc = String::new();
+
+        if fmt.comments_style.hidden {
+            return doc;
+        }
+
+        let banner = Messages::fill(
+            banner,
+            &[
+                (
+                    "type",
+                    &print_reference::type_title(ty.name.unwrap_or_default()),
+                ),
+                ("version", &self.version.unwrap_or_default()),
+                ("date", &today()),
+            ],
+        );
+
+        for line in banner.split('\n') {
+            let line = fmt.comments_style.render(line);
+            swrite!(doc, "{}\n", line.trim_end());
+        }
+
+        doc
+    }
+
+    /// Prints definition of a type that's been referenced (instead of inlined)
+    /// somewhere in the document.
+    fn print_definition(
+        &self,
+        def: &Type,
+        fmt: &Formatting,
+        definitions: &RefCell<Vec<Type>>,
+    ) -> String {
+        let mut doc = String::new();
+
+        if !fmt.comments_style.hidden {
+            let name =
+                print_reference::type_title(def.name.unwrap_or_default());
+
+            swrite!(doc, "{}\n", fmt.comments_style.render(&name));
+        }
+
+        if let (DocComments::Visible, Some(header), false) =
+            (&fmt.doc_comments, def.header, fmt.comments_style.hidden)
+        {
+            let header = fmt.comments_style.reflow(header);
+
+            for line in fmt.comments_style.fit(header.split('\n'), 0) {
+                let line = fmt.comments_style.render(&line);
+                swrite!(doc, "{}\n", line.trim_end());
+            }
+        }
+
+        // Field-specific information (e.g. the field's comment) has been
+        // already printed next to the reference
+        let ty = Type {
+            comment: None,
+            example: None,
+            env: None,
+            since: None,
+            until: None,
+            location: None,
+            has_default: false,
+            ..def.clone()
+        };
+
+        let mut out = Output::new(fmt);
+
+        Ctxt {
+            ty: &ty,
+            val: None,
+            partial: Default::default(),
+            old_val: None,
+            diff: Default::default(),
+            vis: self.visibility,
+            ver: self.version,
+            fmt,
+            out: &mut out,
+            is_key: Default::default(),
+            is_field: Default::default(),
+            parent: Default::default(),
+            example: Default::default(),
+            flat: Default::default(),
+            definitions,
+            depth: Default::default(),
+            level: Default::default(),
+            path: Default::default(),
+        }
+        .print();
+
+        doc.push_str(&out.render());
+        doc
+    }
+}
+
+/// Returns current date (UTC), formatted as `YYYY-MM-DD`.
+fn today() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs() / 86400)
+        .unwrap_or_default() as i64;
+
+    // Converts days since 1970-01-01 into a civil date; see:
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
 }
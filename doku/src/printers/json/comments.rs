@@ -0,0 +1,116 @@
+use super::*;
+
+/// Doc-comment and hints of a single field - see: `Printer::comments()`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommentInfo {
+    /// Field's doc-comment, as written.
+    pub comment: Option<&'static str>,
+
+    /// Comments generated automatically (e.g. `Optional` or `Must be at
+    /// least 1`), in the order they would be printed in - see:
+    /// `Formatting::auto_comments`.
+    pub hints: Vec<String>,
+}
+
+impl<'ty> Ctxt<'_, 'ty, '_> {
+    /// Collects comments of fields nested inside `self.ty`, keyed by their
+    /// dotted paths (prefixed with `path`).
+    pub(super) fn collect_comments(
+        &mut self,
+        path: &str,
+        comments: &mut Vec<(String, CommentInfo)>,
+    ) {
+        match &self.ty.kind {
+            TypeKind::Optional { ty } => {
+                self.nested().with_ty(ty).collect_comments(path, comments);
+            }
+
+            // Maps' keys are not known up-front, so - matching `Type::at()` -
+            // we're using a placeholder
+            TypeKind::Map { value, .. } => {
+                let path = join(path, "*");
+
+                self.nested()
+                    .with_ty(value)
+                    .collect_comments(&path, comments);
+            }
+
+            TypeKind::Struct {
+                fields: Fields::Named { fields },
+                ..
+            } => {
+                for (name, field) in fields {
+                    if !self.allows(&field.ty) {
+                        continue;
+                    }
+
+                    // Flattened fields are serialized straight into the parent
+                    // object, so that's where their comments belong to
+                    if field.flattened {
+                        self.nested()
+                            .with_ty(&field.ty)
+                            .with_flat()
+                            .collect_comments(path, comments);
+
+                        continue;
+                    }
+
+                    let path = join(path, name);
+                    let info = self.comment_info(name, field);
+
+                    if info.comment.is_some() || !info.hints.is_empty() {
+                        comments.push((path.clone(), info));
+                    }
+
+                    self.nested()
+                        .with_ty(&field.ty)
+                        .with_field_name(name)
+                        .inside_object()
+                        .collect_comments(&path, comments);
+                }
+            }
+
+            TypeKind::Struct {
+                fields: Fields::Unnamed { fields },
+                ..
+            } if fields.len() == 1 => {
+                self.nested()
+                    .with_ty(&fields[0].ty)
+                    .collect_comments(path, comments);
+            }
+
+            _ => (),
+        }
+    }
+
+    fn comment_info(
+        &self,
+        field_name: &'ty str,
+        field: &'ty Field,
+    ) -> CommentInfo {
+        // Hints are generated by different parts of the printer (e.g. by the
+        // one responsible for URLs), so it's easiest to just print the field
+        // on the side and see what got appended
+        let mut out = Output::new(self.fmt);
+
+        self.detached(self.fmt, &mut out)
+            .with_ty(&field.ty)
+            .with_field_name(field_name)
+            .set_is_field()
+            .inside_object()
+            .print();
+
+        CommentInfo {
+            comment: field.ty.comment,
+            hints: out.take_leading_hints(),
+        }
+    }
+}
+
+fn join(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        segment.to_owned()
+    } else {
+        format!("{}.{}", path, segment)
+    }
+}
@@ -3,11 +3,33 @@ use super::*;
 pub struct Ctxt<'fmt, 'ty, 'out> {
     pub ty: &'ty Type,
     pub val: Option<&'ty Value>,
+
+    /// Whether `val` might be missing some of the fields (see:
+    /// `Printer::with_partial_value()`); when enabled, fields without values
+    /// are marked as unset.
+    pub partial: bool,
+
+    /// Previous version of `val`, compared against it when printing a diff
+    /// (see: `Printer::with_old_value()`).
+    pub old_val: Option<&'ty Value>,
+
+    /// Whether we're printing a diff between `old_val` and `val`.
+    pub diff: bool,
+
     pub vis: Visibility,
+
+    /// Version of the type that's being documented; when present, fields and
+    /// variants unavailable in this version are skipped.
+    pub ver: Option<&'ty str>,
+
     pub fmt: &'fmt Formatting,
     pub out: &'out mut Output,
     pub is_key: bool,
 
+    /// Whether `ty` is a named field of a struct or a variant; used to print
+    /// hints that make sense only for fields (e.g. `Required`).
+    pub is_field: bool,
+
     /// Parent of `ty`.
     ///
     /// # Example
@@ -48,9 +70,26 @@ pub struct Ctxt<'fmt, 'ty, 'out> {
     /// ```
     pub flat: bool,
 
+    /// Types printed as references (see: `Type::inline` and
+    /// `TypeKind::Reference`), whose definitions are printed after the
+    /// document itself.
+    ///
+    /// The first entry is always the root type, which - being the document
+    /// itself - is considered already defined.
+    pub definitions: &'out RefCell<Vec<Type>>,
+
     /// Incremented each time `Ctxt::nested()` is called; used to detect
     /// recursion.
     pub depth: u8,
+
+    /// Names of fields leading to `ty` (e.g. `["server", "tls"]`); used to
+    /// match `Formatting::overrides`.
+    pub path: Vec<&'ty str>,
+
+    /// Number of objects `ty` is nested in (e.g. `1` for fields of the root
+    /// struct); used to fold objects nested deeper than
+    /// `Formatting::max_depth`.
+    pub level: usize,
 }
 
 impl<'fmt, 'ty, 'out> Ctxt<'fmt, 'ty, 'out> {
@@ -60,17 +99,61 @@ impl<'fmt, 'ty, 'out> Ctxt<'fmt, 'ty, 'out> {
         Ctxt {
             ty: self.ty,
             val: self.val,
+            partial: self.partial,
+            old_val: self.old_val,
+            diff: self.diff,
             vis: self.vis,
+            ver: self.ver,
             fmt: self.fmt,
             out: self.out,
             is_key: false,
+            is_field: false,
             parent: self.parent,
             example: self.example,
             flat: self.flat,
+            definitions: self.definitions,
+            depth: self.depth.checked_add(1).expect(
+                "Seems like the printer got stuck; this might indicate a bug \
+                 in Doku or a recursive type in your code-base",
+            ),
+            level: self.level,
+            path: self.path.clone(),
+        }
+    }
+
+    /// Creates a context that prints into a separate output, using given
+    /// formatting (e.g. to render a value that's going to be embedded inside a
+    /// comment).
+    pub fn detached<'fmt2, 'out2>(
+        &self,
+        fmt: &'fmt2 Formatting,
+        out: &'out2 mut Output,
+    ) -> Ctxt<'fmt2, 'ty, 'out2>
+    where
+        'out: 'out2,
+    {
+        Ctxt {
+            ty: self.ty,
+            val: self.val,
+            partial: self.partial,
+            old_val: self.old_val,
+            diff: self.diff,
+            vis: self.vis,
+            ver: self.ver,
+            fmt,
+            out,
+            is_key: false,
+            is_field: false,
+            parent: self.parent,
+            example: self.example,
+            flat: false,
+            definitions: self.definitions,
             depth: self.depth.checked_add(1).expect(
                 "Seems like the printer got stuck; this might indicate a bug \
                  in Doku or a recursive type in your code-base",
             ),
+            level: self.level,
+            path: self.path.clone(),
         }
     }
 
@@ -105,6 +188,8 @@ impl<'fmt, 'ty, 'out> Ctxt<'fmt, 'ty, 'out> {
             TypeKind::Struct {
                 transparent: true,
                 fields: _,
+                deny_unknown_fields: _,
+                opaque: _,
             }
         );
 
@@ -119,6 +204,11 @@ impl<'fmt, 'ty, 'out> Ctxt<'fmt, 'ty, 'out> {
         self
     }
 
+    pub fn with_old_val(mut self, old_val: Option<&'ty Value>) -> Self {
+        self.old_val = old_val;
+        self
+    }
+
     pub fn with_fmt<'fmt2>(
         self,
         fmt: &'fmt2 Formatting,
@@ -126,14 +216,22 @@ impl<'fmt, 'ty, 'out> Ctxt<'fmt, 'ty, 'out> {
         Ctxt {
             ty: self.ty,
             val: self.val,
+            partial: self.partial,
+            old_val: self.old_val,
+            diff: self.diff,
             vis: self.vis,
+            ver: self.ver,
             fmt,
             out: self.out,
             is_key: false,
+            is_field: self.is_field,
             parent: self.parent,
             example: self.example,
             flat: self.flat,
+            definitions: self.definitions,
             depth: self.depth,
+            level: self.level,
+            path: self.path.clone(),
         }
     }
 
@@ -152,12 +250,50 @@ impl<'fmt, 'ty, 'out> Ctxt<'fmt, 'ty, 'out> {
         self
     }
 
+    pub fn set_is_field(mut self) -> Self {
+        self.is_field = true;
+        self
+    }
+
+    /// Marks `ty` as a field with given name - see: `Ctxt::path`.
+    pub fn with_field_name(mut self, field_name: &'ty str) -> Self {
+        self.path.push(field_name);
+        self
+    }
+
+    /// Marks `ty` as placed inside an object (e.g. as a struct's field or a
+    /// map's value).
+    pub fn inside_object(mut self) -> Self {
+        self.level += 1;
+        self
+    }
+
     pub fn example(&self) -> Option<Example> {
         self.example.or(self.ty.example)
     }
 
-    pub fn first_example(&self) -> Option<&'static str> {
-        self.example().and_then(Example::first)
+    /// Returns the first example, ready to be written into the document
+    /// as-is (i.e. with `Example::String` surrounded with quotes).
+    pub fn first_example(&self) -> Option<Cow<'static, str>> {
+        if let Some(Example::String(example)) = self.example() {
+            Some(Cow::Owned(format!("\"{}\"", example)))
+        } else {
+            self.first_example_text()
+        }
+    }
+
+    /// Returns the first example (without any quotes) - or, if there are none,
+    /// the first value accepted by `Constraint::OneOf`.
+    pub fn first_example_text(&self) -> Option<Cow<'static, str>> {
+        self.example().and_then(Example::first).or_else(|| {
+            self.ty.constraints.iter().find_map(|constraint| {
+                if let Constraint::OneOf(vals) = constraint {
+                    vals.first().copied().map(Cow::Borrowed)
+                } else {
+                    None
+                }
+            })
+        })
     }
 
     pub fn literal_example(&self) -> Option<&'static str> {
@@ -170,50 +306,173 @@ impl<'fmt, 'ty, 'out> Ctxt<'fmt, 'ty, 'out> {
         })
     }
 
+    /// Returns whether given type (e.g. a field) should get printed, taking
+    /// into account the visibility, the version and `#[doc(hidden)]`.
+    pub fn allows(&self, ty: &Type) -> bool {
+        self.vis.allows(ty.serializable, ty.deserializable)
+            && self
+                .ver
+                .map_or(true, |ver| is_available(ver, ty.since, ty.until))
+            && self.fmt.doc_hidden.allows(ty.hidden)
+    }
+
+    /// Returns whether given variant should get printed, taking into account
+    /// the visibility, the version and `#[doc(hidden)]`.
+    pub fn allows_variant(&self, variant: &Variant) -> bool {
+        self.vis
+            .allows(variant.serializable, variant.deserializable)
+            && self.ver.map_or(true, |ver| {
+                is_available(ver, variant.since, variant.until)
+            })
+            && self.fmt.doc_hidden.allows(variant.hidden)
+    }
+
     pub fn print(self) {
-        if !self
-            .vis
-            .allows(self.ty.serializable, self.ty.deserializable)
-        {
+        if !self.allows(self.ty) {
             return;
         }
 
-        let requires_custom_formatting =
-            self.ty.metas.iter().any(|meta| {
-                meta.key() == "fmt" || meta.key().starts_with("fmt.")
-            });
+        // Overrides are matched only once per field - otherwise e.g. inner
+        // type of `Option<T>` (which shares its path) would discard the
+        // customizations coming from the outer type's metas
+        let path = if self.is_field {
+            Some(self.path.as_slice())
+        } else {
+            None
+        };
 
-        if requires_custom_formatting {
-            let fmt = self.fmt.customize(self.ty.metas.iter());
+        match self.fmt_of(self.ty, path) {
+            Cow::Owned(fmt) => {
+                if fmt.layout != *self.out.layout() && !self.flat {
+                    self.print_with_layout(&fmt);
+                } else {
+                    self.with_fmt(&fmt).print_inner();
+                }
+            }
+            Cow::Borrowed(_) => self.print_inner(),
+        }
+    }
+
+    /// Prints `ty` using a layout different from the one of `self.out` (e.g.
+    /// coming from `#[doku(fmt(layout = "..."))]`) - since layouts apply to
+    /// whole outputs, the type gets rendered separately and then embedded line
+    /// by line.
+    fn print_with_layout(self, fmt: &Formatting) {
+        let mut out = Output::new(fmt);
+        let mut ctxt = self.detached(fmt, &mut out);
+
+        ctxt.is_field = self.is_field;
+        ctxt.print_inner();
+
+        for comment in out.take_leading_comments() {
+            self.out.writeln_comment(comment);
+        }
 
-            self.with_fmt(&fmt).print_inner();
+        for (line_idx, line) in out.render().split('\n').enumerate() {
+            if line_idx > 0 {
+                self.out.ln();
+            }
+
+            self.out.write(line);
+        }
+    }
+
+    /// Returns formatting for given type, taking into account
+    /// `Formatting::overrides` matching given field path (if any) and type's
+    /// `fmt.*` metas (e.g. coming from `#[doku(fmt(...))]`).
+    pub fn fmt_of(
+        &self,
+        ty: &Type,
+        path: Option<&[&str]>,
+    ) -> Cow<'fmt, Formatting> {
+        let fmt = path
+            .and_then(|path| self.fmt.override_for(path))
+            .map_or(Cow::Borrowed(self.fmt), Cow::Owned);
+
+        let requires_custom_formatting = ty
+            .metas
+            .iter()
+            .any(|meta| meta.key() == "fmt" || meta.key().starts_with("fmt."));
+
+        if requires_custom_formatting {
+            Cow::Owned(fmt.customize(ty.metas.iter()))
         } else {
-            self.print_inner();
+            fmt
         }
     }
 
     fn print_inner(mut self) {
         self.print_comment();
+        self.print_unit();
+        self.print_env();
+        self.print_versions();
+        self.print_cfg();
+        self.print_constraints();
+        self.print_location();
+        self.print_optionality();
+        self.print_unset();
+        self.print_changes();
+
+        if self.ty.sensitive {
+            self.print_sensitive();
+            return;
+        }
+
+        if self.print_reference() {
+            return;
+        }
 
         if let Some(example) = self.literal_example() {
-            self.out.write(example);
+            self.print_literal(example);
             return;
         }
 
         match &self.ty.kind {
+            TypeKind::Any => self.print_any(),
             TypeKind::Bool => self.print_bool(),
-            TypeKind::Float => self.print_float(),
-            TypeKind::Integer => self.print_integer(),
+            TypeKind::Float { bits } => self.print_float(*bits),
+            TypeKind::Integer { bits, signed } => {
+                self.print_integer(*bits, *signed)
+            }
             TypeKind::String => self.print_string(),
+            TypeKind::Bytes => self.print_bytes(),
+            TypeKind::Date => self.print_date(),
+            TypeKind::DateTime { offset } => self.print_datetime(*offset),
+            TypeKind::Decimal { digits } => self.print_decimal(*digits),
+            TypeKind::Duration => self.print_duration(),
+            TypeKind::Time => self.print_time(),
+            TypeKind::IpAddr { version } => self.print_ip_addr(*version),
+            TypeKind::SocketAddr { version } => {
+                self.print_socket_addr(*version)
+            }
+            TypeKind::Path => self.print_path(),
+            TypeKind::Url => self.print_url(),
+            TypeKind::Uuid => self.print_uuid(),
             TypeKind::Array { ty, size } => self.print_array(ty, *size),
-            TypeKind::Enum { tag, variants } => self.print_enum(*tag, variants),
+            TypeKind::Enum {
+                tag,
+                variants,
+                example_variant,
+                repr,
+            } => self.print_enum(*tag, variants, *example_variant, *repr),
             TypeKind::Struct {
                 fields,
                 transparent,
-            } => self.print_struct(fields, *transparent, None),
+                deny_unknown_fields,
+                opaque,
+            } => self.print_struct(
+                fields,
+                *transparent,
+                *deny_unknown_fields,
+                *opaque,
+                None,
+            ),
             TypeKind::Tuple { fields } => self.print_tuple(fields),
             TypeKind::Map { key, value } => self.print_map(key, value),
             TypeKind::Optional { ty } => self.print_optional(ty),
+            TypeKind::Reference { name, ty } => {
+                self.print_recursive_reference(name, *ty)
+            }
         }
     }
 }
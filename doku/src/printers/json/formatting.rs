@@ -1,52 +1,794 @@
+mod arrays_style;
 mod auto_comments;
+mod blank_lines;
+#[cfg(feature = "color")]
+mod colors;
+mod columns_style;
 mod comments_style;
 mod doc_comments;
+mod doc_header;
+mod doc_hidden;
+mod durations_style;
 mod enums_style;
+mod error;
 mod indent_style;
 mod layout;
+mod line_endings;
+mod maps_style;
+mod messages;
 mod objects_style;
+mod optionals_style;
+mod overrides;
+mod placeholders;
+mod rename_rule;
+mod sort_fields;
+mod untagged_enums_style;
 mod values_style;
 
 pub use self::{
-    auto_comments::*, comments_style::*, doc_comments::*, enums_style::*,
-    indent_style::*, layout::*, objects_style::*, values_style::*,
+    arrays_style::*, auto_comments::*, blank_lines::*, columns_style::*,
+    comments_style::*, doc_comments::*, doc_header::*, doc_hidden::*,
+    durations_style::*, enums_style::*, error::*, indent_style::*, layout::*,
+    line_endings::*, maps_style::*, messages::*, objects_style::*,
+    optionals_style::*, overrides::*, placeholders::*, rename_rule::*,
+    sort_fields::*, untagged_enums_style::*, values_style::*,
 };
 
+#[cfg(feature = "color")]
+pub use self::colors::*;
+
 use crate::*;
 use std::any;
 
 /// Determines the look & feel of the documentation.
+///
+/// Apart from filling the struct directly, it can be built starting from one
+/// of the presets (e.g. `Formatting::compact()`) with the `with_*` methods:
+///
+/// ```
+/// use doku::Document;
+/// use doku::json::{Formatting, SortFields};
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Port to listen at
+///     port: u16,
+///
+///     /// Address to listen at
+///     addr: String,
+/// }
+///
+/// let fmt = Formatting::compact()
+///     .with_sort_fields(SortFields::Alphabetical)
+///     .with_header("DO NOT EDIT");
+///
+/// let doc = doku::to_json_fmt::<Config>(&fmt);
+///
+/// doku::assert_doc!(r#"
+///   // DO NOT EDIT
+///
+///   {
+///     "addr": "string", // Address to listen at
+///     "port": 123       // Port to listen at
+///   }
+/// "#, doc);
+/// ```
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
 pub struct Formatting {
+    /// Determines how arrays should get displayed.
+    pub arrays_style: ArraysStyle,
+
     /// Determines which auto-comments - _hints_, so to say - should get
     /// displayed.
     pub auto_comments: AutoComments,
 
+    /// When set, objects and tuples that - when squashed into a single line -
+    /// are no longer than given number of characters get printed inline:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Shape {
+    ///     /// Shape's origin
+    ///     origin: Point,
+    ///     size: (u32, u32),
+    ///     styles: Styles,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Styles {
+    ///     background_color: String,
+    ///     border_color: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_inline_under: Some(30),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Shape>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Shape's origin
+    ///     "origin": { "x": 123, "y": 123 },
+    ///     "size": [123, 123],
+    ///     "styles": {
+    ///       "background_color": "string",
+    ///       "border_color": "string"
+    ///     }
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// Objects containing any comments are never inlined.
+    pub auto_inline_under: Option<usize>,
+
+    /// Determines where blank lines should get inserted between fields.
+    pub blank_lines: BlankLines,
+
+    /// When set, the document gets colored using ANSI escape codes.
+    #[cfg(feature = "color")]
+    pub colors: Option<Colors>,
+
+    /// Determines where comments get placed in `Layout::TwoColumns`.
+    pub columns_style: ColumnsStyle,
+
     /// Determines how comments should get displayed.
     pub comments_style: CommentsStyle,
 
     /// Determines if doc-comments should get displayed.
     pub doc_comments: DocComments,
 
+    /// Determines if type's doc-comment should get displayed as a header.
+    pub doc_header: DocHeader,
+
+    /// Determines if fields and variants marked with `#[doc(hidden)]` (e.g.
+    /// internal debugging knobs) should get displayed.
+    pub doc_hidden: DocHidden,
+
+    /// Determines how durations should get displayed.
+    pub durations_style: DurationsStyle,
+
     /// Determines how enums should get displayed.
     pub enums_style: EnumsStyle,
 
+    /// Text appended to the document as a comment; see: `header`.
+    pub footer: Option<String>,
+
+    /// Text prepended to the document as a comment - e.g. to mark the file as
+    /// generated:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     port: u16,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     header: Some("DO NOT EDIT - generated from {type} v{version}".into()),
+    ///     footer: Some("End of {type}".into()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::json::Printer::default()
+    ///     .with_formatting(&fmt)
+    ///     .with_version("1.2.0")
+    ///     .print(&Config::ty());
+    ///
+    /// doku::assert_doc!(r#"
+    ///   // DO NOT EDIT - generated from Config v1.2.0
+    ///
+    ///   {
+    ///     "port": 123
+    ///   }
+    ///
+    ///   // End of Config
+    /// "#, doc);
+    /// ```
+    ///
+    /// Following placeholders are supported:
+    ///
+    /// - `{type}` - name of the printed type,
+    /// - `{version}` - version given to `Printer::with_version()` (empty, if
+    ///   there's none),
+    /// - `{date}` - current date (UTC), as `YYYY-MM-DD`.
+    ///
+    /// Just like other comments, header and footer are not displayed when
+    /// `CommentsStyle::hidden` is set.
+    pub header: Option<String>,
+
     /// Determines the indenting style.
     pub indent_style: IndentStyle,
 
     /// Determines whether the document should contain one or two columns.
     pub layout: Layout,
 
+    /// Determines how lines should be terminated.
+    pub line_endings: LineEndings,
+
+    /// Determines how maps should get displayed.
+    pub maps_style: MapsStyle,
+
+    /// When set, objects nested deeper than given number of levels are
+    /// folded into `{ ... }`:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Database's configuration
+    ///     db: Database,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Database {
+    ///     /// Connection string
+    ///     url: String,
+    ///
+    ///     /// Pool's configuration
+    ///     pool: Pool,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Pool {
+    ///     size: usize,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     max_depth: Some(2),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Database's configuration
+    ///     "db": {
+    ///       // Connection string
+    ///       "url": "string",
+    ///       // Pool's configuration; see type "Pool"
+    ///       "pool": { ... }
+    ///     }
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub max_depth: Option<usize>,
+
+    /// Texts used to build auto-comments.
+    pub messages: Messages,
+
     /// Determines how objects should get displayed.
     pub objects_style: ObjectsStyle,
 
+    /// Determines how optional values should get displayed.
+    pub optionals_style: OptionalsStyle,
+
+    /// Formatting used for selected fields (and everything inside them),
+    /// instead of this one; when many selectors match the same field, the
+    /// last one wins:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     server: Server,
+    ///     fallback: Server,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Server {
+    ///     /// Address to listen at
+    ///     addr: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     overrides: vec![(
+    ///         "fallback".into(),
+    ///         doku::json::Formatting {
+    ///             doc_comments: doku::json::DocComments::Hidden,
+    ///             ..Default::default()
+    ///         },
+    ///     )],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "server": {
+    ///       // Address to listen at
+    ///       "addr": "string"
+    ///     },
+    ///     "fallback": {
+    ///       "addr": "string"
+    ///     }
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// Overriding formatting doesn't have to repeat the overrides - when its
+    /// own list is empty, the outer one is used for the nested fields.
+    ///
+    /// Just like with `#[doku(fmt(...))]`, options that affect the document as
+    /// a whole (e.g. `layout`) are always taken from the top-level formatting.
+    pub overrides: Vec<(Selector, Formatting)>,
+
+    /// Determines how values without any examples should get displayed.
+    pub placeholders: Placeholders,
+
+    /// When set, field names get re-cased before being displayed - e.g. to
+    /// preview how the document would look like with `camelCase` keys,
+    /// without changing the `#[serde(rename_all = ...)]` attributes:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     listen_addr: String,
+    ///     max_connections: usize,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     rename_all: Some(doku::json::RenameRule::CamelCase),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "listenAddr": "string",
+    ///     "maxConnections": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// Only field names are affected - enum variants, tags and map keys are
+    /// displayed as they are.
+    pub rename_all: Option<RenameRule>,
+
+    /// Determines the order in which fields should get displayed.
+    pub sort_fields: SortFields,
+
+    /// Determines how untagged enums should get displayed.
+    pub untagged_enums_style: UntaggedEnumsStyle,
+
     /// Determines how values should get displayed.
     pub values_style: ValuesStyle,
 }
 
 impl Formatting {
+    /// Formatting focused on the structure of the document, with no
+    /// auto-comments and doc-comments aligned in a separate column:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     port: Option<u16>,
+    ///
+    ///     /// Workers to spawn
+    ///     workers: [String; 2],
+    /// }
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&doku::json::Formatting::compact());
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "port": 123, // Port to listen at
+    ///     "workers": [ // Workers to spawn
+    ///       "string",
+    ///       /* ... */
+    ///     ]
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub fn compact() -> Self {
+        Self {
+            auto_comments: AutoComments::none(),
+            layout: Layout::TwoColumns {
+                align: true,
+                spacing: 1,
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Formatting that displays everything Doku knows about the types - all
+    /// the auto-comments (except for `AutoComments::location`, which is meant
+    /// for developers rather than readers of the document) and type's
+    /// doc-comment as the document's header:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// /// Application's configuration
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     port: Option<u16>,
+    /// }
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&doku::json::Formatting::verbose());
+    ///
+    /// doku::assert_doc!(r#"
+    ///   // Application's configuration
+    ///
+    ///   {
+    ///     // Port to listen at; optional; unsigned 16-bit integer (0..=65535)
+    ///     "port": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub fn verbose() -> Self {
+        Self {
+            auto_comments: AutoComments {
+                location: false,
+                ..AutoComments::all()
+            },
+            doc_header: DocHeader::Visible,
+            ..Default::default()
+        }
+    }
+
+    /// Formatting that produces a valid JSON - without comments, ellipses and
+    /// alternatives:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     port: Option<u16>,
+    ///
+    ///     /// Workers to spawn
+    ///     workers: Vec<String>,
+    ///
+    ///     mode: Mode,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// enum Mode {
+    ///     Fast,
+    ///     Safe,
+    /// }
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&doku::json::Formatting::strict_json());
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "port": 123,
+    ///     "workers": [
+    ///       "string"
+    ///     ],
+    ///     "mode": "Fast"
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// Note that types printed as references (`#[doku(inline = false)]`)
+    /// still yield separate definitions, which are not a part of the JSON.
+    pub fn strict_json() -> Self {
+        Self {
+            comments_style: CommentsStyle::none(),
+            enums_style: EnumsStyle::Commented,
+            objects_style: ObjectsStyle {
+                use_ellipsis: false,
+                use_trailing_comma: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Sets `Self::arrays_style`.
+    pub fn with_arrays_style(mut self, arrays_style: ArraysStyle) -> Self {
+        self.arrays_style = arrays_style;
+        self
+    }
+
+    /// Sets `Self::auto_comments`.
+    pub fn with_auto_comments(mut self, auto_comments: AutoComments) -> Self {
+        self.auto_comments = auto_comments;
+        self
+    }
+
+    /// Sets `Self::auto_inline_under`.
+    pub fn with_auto_inline_under(mut self, auto_inline_under: usize) -> Self {
+        self.auto_inline_under = Some(auto_inline_under);
+        self
+    }
+
+    /// Sets `Self::blank_lines`.
+    pub fn with_blank_lines(mut self, blank_lines: BlankLines) -> Self {
+        self.blank_lines = blank_lines;
+        self
+    }
+
+    /// Sets `Self::colors`.
+    #[cfg(feature = "color")]
+    pub fn with_colors(mut self, colors: Colors) -> Self {
+        self.colors = Some(colors);
+        self
+    }
+
+    /// Sets `Self::columns_style`.
+    pub fn with_columns_style(mut self, columns_style: ColumnsStyle) -> Self {
+        self.columns_style = columns_style;
+        self
+    }
+
+    /// Sets `Self::comments_style`.
+    pub fn with_comments_style(
+        mut self,
+        comments_style: CommentsStyle,
+    ) -> Self {
+        self.comments_style = comments_style;
+        self
+    }
+
+    /// Sets `Self::doc_comments`.
+    pub fn with_doc_comments(mut self, doc_comments: DocComments) -> Self {
+        self.doc_comments = doc_comments;
+        self
+    }
+
+    /// Sets `Self::doc_header`.
+    pub fn with_doc_header(mut self, doc_header: DocHeader) -> Self {
+        self.doc_header = doc_header;
+        self
+    }
+
+    /// Sets `Self::doc_hidden`.
+    pub fn with_doc_hidden(mut self, doc_hidden: DocHidden) -> Self {
+        self.doc_hidden = doc_hidden;
+        self
+    }
+
+    /// Sets `Self::durations_style`.
+    pub fn with_durations_style(
+        mut self,
+        durations_style: DurationsStyle,
+    ) -> Self {
+        self.durations_style = durations_style;
+        self
+    }
+
+    /// Sets `Self::enums_style`.
+    pub fn with_enums_style(mut self, enums_style: EnumsStyle) -> Self {
+        self.enums_style = enums_style;
+        self
+    }
+
+    /// Sets `Self::footer`.
+    pub fn with_footer(mut self, footer: impl Into<String>) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Sets `Self::header`.
+    pub fn with_header(mut self, header: impl Into<String>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Sets `Self::indent_style`.
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    /// Sets `Self::layout`.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets `Self::line_endings`.
+    pub fn with_line_endings(mut self, line_endings: LineEndings) -> Self {
+        self.line_endings = line_endings;
+        self
+    }
+
+    /// Sets `Self::maps_style`.
+    pub fn with_maps_style(mut self, maps_style: MapsStyle) -> Self {
+        self.maps_style = maps_style;
+        self
+    }
+
+    /// Sets `Self::max_depth`.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets `Self::messages`.
+    pub fn with_messages(mut self, messages: Messages) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Sets `Self::objects_style`.
+    pub fn with_objects_style(mut self, objects_style: ObjectsStyle) -> Self {
+        self.objects_style = objects_style;
+        self
+    }
+
+    /// Sets `Self::optionals_style`.
+    pub fn with_optionals_style(
+        mut self,
+        optionals_style: OptionalsStyle,
+    ) -> Self {
+        self.optionals_style = optionals_style;
+        self
+    }
+
+    /// Adds an entry to `Self::overrides`.
+    pub fn with_override(
+        mut self,
+        selector: impl Into<Selector>,
+        fmt: Formatting,
+    ) -> Self {
+        self.overrides.push((selector.into(), fmt));
+        self
+    }
+
+    /// Sets `Self::placeholders`.
+    pub fn with_placeholders(mut self, placeholders: Placeholders) -> Self {
+        self.placeholders = placeholders;
+        self
+    }
+
+    /// Sets `Self::rename_all`.
+    pub fn with_rename_all(mut self, rename_all: RenameRule) -> Self {
+        self.rename_all = Some(rename_all);
+        self
+    }
+
+    /// Sets `Self::sort_fields`.
+    pub fn with_sort_fields(mut self, sort_fields: SortFields) -> Self {
+        self.sort_fields = sort_fields;
+        self
+    }
+
+    /// Sets `Self::untagged_enums_style`.
+    pub fn with_untagged_enums_style(
+        mut self,
+        untagged_enums_style: UntaggedEnumsStyle,
+    ) -> Self {
+        self.untagged_enums_style = untagged_enums_style;
+        self
+    }
+
+    /// Sets `Self::values_style`.
+    pub fn with_values_style(mut self, values_style: ValuesStyle) -> Self {
+        self.values_style = values_style;
+        self
+    }
+
+    /// Loads formatting from a JSON document, e.g. a file provided by the
+    /// end user:
+    ///
+    /// ```
+    /// use doku::json::{Formatting, Layout};
+    ///
+    /// let fmt = Formatting::from_json(r#"
+    ///   {
+    ///     "layout": {
+    ///       "TwoColumns": { "align": true, "spacing": 2 }
+    ///     }
+    ///   }
+    /// "#).unwrap();
+    ///
+    /// assert!(matches!(fmt.layout, Layout::TwoColumns { spacing: 2, .. }));
+    ///
+    /// let err = Formatting::from_json(r#"{ "layot": "OneColumn" }"#)
+    ///     .unwrap_err()
+    ///     .to_string();
+    ///
+    /// assert!(err.starts_with(
+    ///     "Couldn't load formatting from JSON: unknown field `layot`"
+    /// ));
+    /// ```
+    ///
+    /// Options that are not present keep their default values.
+    pub fn from_json(json: &str) -> Result<Self, FormattingError> {
+        serde_json::from_str(json).map_err(FormattingError::Json)
+    }
+
+    /// Loads formatting from a YAML document; see: `Self::from_json()`.
+    ///
+    /// ```
+    /// use doku::json::{Formatting, SortFields};
+    ///
+    /// let fmt = Formatting::from_yaml(r#"
+    ///   sort_fields: Alphabetical
+    ///   comments_style:
+    ///     separator: ";"
+    /// "#).unwrap();
+    ///
+    /// assert!(matches!(fmt.sort_fields, SortFields::Alphabetical));
+    /// assert_eq!(";", fmt.comments_style.separator);
+    ///
+    /// let err = Formatting::from_yaml("sort_fields: Random")
+    ///     .unwrap_err()
+    ///     .to_string();
+    ///
+    /// assert!(err.starts_with(
+    ///     "Couldn't load formatting from YAML: sort_fields: unknown variant `Random`"
+    /// ));
+    /// ```
+    #[cfg(feature = "serde-yaml-08")]
+    pub fn from_yaml(yaml: &str) -> Result<Self, FormattingError> {
+        serde_yaml::from_str(yaml).map_err(FormattingError::Yaml)
+    }
+
+    /// Loads formatting from a TOML document; see: `Self::from_json()`.
+    ///
+    /// ```
+    /// use doku::json::{Formatting, SortFields};
+    ///
+    /// let fmt = Formatting::from_toml(r#"
+    ///   sort_fields = "Alphabetical"
+    ///
+    ///   [comments_style]
+    ///   separator = ";"
+    /// "#).unwrap();
+    ///
+    /// assert!(matches!(fmt.sort_fields, SortFields::Alphabetical));
+    /// assert_eq!(";", fmt.comments_style.separator);
+    ///
+    /// let err = Formatting::from_toml(r#"sort_fields = "Random""#)
+    ///     .unwrap_err()
+    ///     .to_string();
+    ///
+    /// assert!(err.starts_with(
+    ///     "Couldn't load formatting from TOML: unknown variant `Random`"
+    /// ));
+    /// ```
+    #[cfg(feature = "toml-05")]
+    pub fn from_toml(toml: &str) -> Result<Self, FormattingError> {
+        toml::from_str(toml).map_err(FormattingError::Toml)
+    }
+
+    /// Returns formatting for field at given path, if any of `overrides`
+    /// matches it.
+    pub(crate) fn override_for(&self, path: &[&str]) -> Option<Self> {
+        let (_, fmt) = self
+            .overrides
+            .iter()
+            .rev()
+            .find(|(selector, _)| selector.matches(path))?;
+
+        let mut fmt = fmt.clone();
+
+        if fmt.overrides.is_empty() {
+            fmt.overrides = self.overrides.clone();
+        }
+
+        Some(fmt)
+    }
+
     pub(crate) fn customize(&self, metas: impl Iterator<Item = Meta>) -> Self {
         let mut this = serde_json::to_value(self).unwrap();
 
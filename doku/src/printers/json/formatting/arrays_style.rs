@@ -0,0 +1,47 @@
+use crate::*;
+
+/// Determines the arrays style.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArraysStyle {
+    /// How many example elements should get displayed for each array:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     ports: Vec<u16>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     arrays_style: doku::json::ArraysStyle {
+    ///         elements: 2,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "ports": [
+    ///       123,
+    ///       123,
+    ///       /* ... */
+    ///     ]
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// When the array has multiple examples, consecutive elements use
+    /// consecutive examples (starting over once they run out), but all of the
+    /// examples get displayed at least once.
+    pub elements: usize,
+}
+
+impl Default for ArraysStyle {
+    fn default() -> Self {
+        Self { elements: 1 }
+    }
+}
@@ -8,7 +8,39 @@ use crate::*;
 /// printed or not.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
+#[serde(default)]
 pub struct AutoComments {
+    /// When set, displays aliases of enum variants:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, Document)]
+    /// #[serde(rename_all = "lowercase")]
+    /// enum Protocol {
+    ///     /// HTTP/2
+    ///     #[serde(alias = "h2")]
+    ///     Http2,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         aliases: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Protocol>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   // HTTP/2; aliases: "h2"
+    ///   "http2"
+    /// "#, doc);
+    /// ```
+    pub aliases: bool,
+
     /// When set, displays hints for arrays of known sizes:
     ///
     /// ```
@@ -41,6 +73,394 @@ pub struct AutoComments {
     /// ```
     pub array_size: bool,
 
+    /// When set, displays hints for binary data (disabled by default):
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     #[doku(bytes)]
+    ///     secret_key: [u8; 32],
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         bytes: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Base64-encoded bytes
+    ///     "secret_key": "aGVsbG8gd29ybGQ="
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub bytes: bool,
+
+    /// When set, displays hints for fields available only with specific
+    /// `#[cfg(...)]` options enabled:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     #[cfg(not(test))]
+    ///     port: u16,
+    ///
+    ///     #[cfg(any(unix, not(unix)))]
+    ///     address: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         cfg: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Port to listen at; only with cfg(not(test))
+    ///     "port": 123,
+    ///     // Only with cfg(any(unix, not(unix)))
+    ///     "address": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub cfg: bool,
+
+    /// When set, displays hints for fields that can be omitted, falling back
+    /// to a default value (disabled by default):
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Serialize, Deserialize, Document)]
+    /// struct Config {
+    ///     #[serde(default)]
+    ///     verbose: bool,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         defaults: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Optional, has a default value
+    ///     "verbose": true
+    ///   }
+    /// "#, doc);
+    ///
+    /// // When printing with a value, it's treated as the default one:
+    ///
+    /// let doc = doku::to_json_fmt_val(&fmt, &Config { verbose: false });
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Optional, defaults to false
+    ///     "verbose": false
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub defaults: bool,
+
+    /// When set, fields marked with `#[doku(enum_values_comment)]` list all
+    /// the accepted values of their (fieldless) enums in a single line:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     #[doku(enum_values_comment)]
+    ///     level: Level,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// #[doku(rename_all = "lowercase")]
+    /// enum Level {
+    ///     Debug,
+    ///     Info,
+    ///     Warn,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         enum_values: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // One of: "debug", "info", "warn"
+    ///     "level": "debug"
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub enum_values: bool,
+
+    /// When set, displays hints for values that can be overridden through
+    /// environmental variables:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     #[doku(env = "APP_PORT")]
+    ///     port: u16,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         env: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Port to listen at; env: APP_PORT
+    ///     "port": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub env: bool,
+
+    /// When set, displays precision of floating-point numbers (disabled by
+    /// default):
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     ratio: f32,
+    ///     threshold: f64,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         float_precision: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Floating-point number (single-precision, 32-bit)
+    ///     "ratio": 123.45,
+    ///     // Floating-point number (double-precision, 64-bit)
+    ///     "threshold": 123.45
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub float_precision: bool,
+
+    /// When set, displays hints for values of well-known formats, such as IP
+    /// addresses, paths or URLs (disabled by default):
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use std::net::{Ipv4Addr, SocketAddr};
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     listen: SocketAddr,
+    ///     gateway: Ipv4Addr,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         formats: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // IPv4 or IPv6 address with port
+    ///     "listen": "127.0.0.1:8080",
+    ///     // IPv4 address
+    ///     "gateway": "127.0.0.1"
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub formats: bool,
+
+    /// When set, displays widths and ranges of integers (disabled by
+    /// default):
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     port: u16,
+    ///     offset: i8,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         integer_range: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Unsigned 16-bit integer (0..=65535)
+    ///     "port": 123,
+    ///     // Signed 8-bit integer (-128..=127)
+    ///     "offset": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub integer_range: bool,
+
+    /// When set, displays hints for values of a constrained length:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Person {
+    ///     #[doku(min_length = 1, max_length = 32)]
+    ///     name: String,
+    ///
+    ///     #[doku(max_length = 3)]
+    ///     friends: Vec<String>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         length: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Person>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Must contain between 1 and 32 characters
+    ///     "name": "string",
+    ///     // Must contain at most 3 elements
+    ///     "friends": [
+    ///       "string",
+    ///       /* ... */
+    ///     ]
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub length: bool,
+
+    /// When set, displays where values have been defined - for derived
+    /// types, this requires the `provenance` feature, which makes
+    /// `#[derive(Document)]` record the source location of each field:
+    ///
+    /// ```
+    /// use doku::{Document, Location, Type};
+    ///
+    /// struct Port;
+    ///
+    /// impl Document for Port {
+    ///     fn ty() -> Type {
+    ///         Type {
+    ///             location: Some(Location {
+    ///                 module: "app::config",
+    ///                 file: "src/config.rs",
+    ///                 line: 42,
+    ///             }),
+    ///             ..u16::ty()
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         location: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Port>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   // Defined in src/config.rs:42
+    ///   123
+    /// "#, doc);
+    /// ```
+    pub location: bool,
+
+    /// When set, displays hints for fields accepting only specific values:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     #[doku(one_of = "debug", one_of = "info")]
+    ///     level: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         one_of: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Must be one of: "debug", "info"
+    ///     "level": "debug"
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub one_of: bool,
+
     /// When set, displays hints for optional values:
     ///
     /// ```
@@ -69,26 +489,355 @@ pub struct AutoComments {
     /// "#, doc);
     /// ```
     pub optional: bool,
+
+    /// When set, displays hints for values constrained by a pattern:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Service {
+    ///     #[doku(pattern = "^[a-z0-9-]+$")]
+    ///     id: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         pattern: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Service>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Must match pattern: ^[a-z0-9-]+$
+    ///     "id": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub pattern: bool,
+
+    /// When set, displays hints for numbers constrained by a range or a
+    /// multiple:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     #[doku(min = 1, max = 65535)]
+    ///     port: u16,
+    ///
+    ///     #[doku(min = "-1.5", multiple_of = 0.5)]
+    ///     offset: f32,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         range: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Must be between 1 and 65535
+    ///     "port": 123,
+    ///     // Must be at least -1.5; must be a multiple of 0.5
+    ///     "offset": 123.45
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub range: bool,
+
+    /// When set, displays hints for fields that have to be provided (i.e.
+    /// neither `Option`-al nor `#[serde(default)]`):
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     addr: String,
+    ///     port: Option<u16>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         required: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Required
+    ///     "addr": "string",
+    ///     // Optional
+    ///     "port": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub required: bool,
+
+    /// When set, displays names of enum variants as written in the Rust code,
+    /// if they're serialized differently (e.g. due to `#[serde(rename)]`):
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, Document)]
+    /// #[serde(rename_all = "lowercase")]
+    /// enum Protocol {
+    ///     /// HTTP/2
+    ///     Http2,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         rust_names: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Protocol>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   // HTTP/2; Rust: Http2
+    ///   "http2"
+    /// "#, doc);
+    /// ```
+    pub rust_names: bool,
+
+    /// When set, displays hints for sensitive values:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Database's password
+    ///     #[doku(sensitive)]
+    ///     db_password: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         sensitive: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Database's password; sensitive — do not commit
+    ///     "db_password": "<secret>"
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// Regardless of this setting, sensitive values are always printed as a
+    /// redacted placeholder - even when the documentation is generated from
+    /// an actual value (e.g. through [`crate::to_json_val()`]).
+    pub sensitive: bool,
+
+    /// When set, displays hints for values expressed in a specific unit:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Connection timeout
+    ///     #[doku(unit = "seconds")]
+    ///     timeout: usize,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         unit: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Connection timeout; in seconds
+    ///     "timeout": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub unit: bool,
+
+    /// When set, displays hints for objects that reject unknown keys (think
+    /// `#[serde(deny_unknown_fields)]`; disabled by default):
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, Document)]
+    /// struct Config {
+    ///     db: Database,
+    /// }
+    ///
+    /// #[derive(Deserialize, Document)]
+    /// #[serde(deny_unknown_fields)]
+    /// struct Database {
+    ///     url: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         unknown_fields: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Unknown keys are rejected
+    ///     "db": {
+    ///       "url": "string"
+    ///     }
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub unknown_fields: bool,
+
+    /// When set, displays hints for values available only in specific
+    /// versions:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     #[doku(since = "2.3")]
+    ///     port: u16,
+    ///
+    ///     #[doku(since = "1.0", until = "3.0")]
+    ///     address: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     auto_comments: doku::json::AutoComments {
+    ///         versions: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Port to listen at; since v2.3
+    ///     "port": 123,
+    ///     // Since v1.0; until v3.0
+    ///     "address": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub versions: bool,
 }
 
 impl AutoComments {
     pub fn all() -> Self {
         Self {
+            aliases: true,
             array_size: true,
+            bytes: true,
+            cfg: true,
+            defaults: true,
+            enum_values: true,
+            env: true,
+            float_precision: true,
+            formats: true,
+            integer_range: true,
+            length: true,
+            location: true,
+            one_of: true,
             optional: true,
+            pattern: true,
+            range: true,
+            required: true,
+            rust_names: true,
+            sensitive: true,
+            unit: true,
+            unknown_fields: true,
+            versions: true,
         }
     }
 
     pub fn none() -> Self {
         Self {
+            aliases: false,
             array_size: false,
+            bytes: false,
+            cfg: false,
+            defaults: false,
+            enum_values: false,
+            env: false,
+            float_precision: false,
+            formats: false,
+            integer_range: false,
+            length: false,
+            location: false,
+            one_of: false,
             optional: false,
+            pattern: false,
+            range: false,
+            required: false,
+            rust_names: false,
+            sensitive: false,
+            unit: false,
+            unknown_fields: false,
+            versions: false,
         }
     }
 }
 
 impl Default for AutoComments {
     fn default() -> Self {
-        Self::all()
+        // These would get printed next to almost every value, cluttering the
+        // documentation - so unlike the rest, they are opt-in
+        Self {
+            float_precision: false,
+            integer_range: false,
+            location: false,
+            required: false,
+            rust_names: false,
+
+            // These are opt-in as well, so that upgrading doesn't change the
+            // documents that have been already generated
+            bytes: false,
+            defaults: false,
+            formats: false,
+            unknown_fields: false,
+
+            ..Self::all()
+        }
     }
 }
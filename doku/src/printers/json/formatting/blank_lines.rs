@@ -0,0 +1,114 @@
+use crate::*;
+
+/// Determines where blank lines should get inserted between fields.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum BlankLines {
+    /// Prints fields one after another:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at
+    ///     addr: String,
+    ///     port: u16,
+    ///     /// Number of workers
+    ///     workers: usize,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     blank_lines: doku::json::BlankLines::Never,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Address to listen at
+    ///     "addr": "string",
+    ///     "port": 123,
+    ///     // Number of workers
+    ///     "workers": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    Never,
+
+    /// Inserts a blank line before each field that has a doc-comment:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at
+    ///     addr: String,
+    ///     port: u16,
+    ///     /// Number of workers
+    ///     workers: usize,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     blank_lines: doku::json::BlankLines::BeforeCommentedFields,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Address to listen at
+    ///     "addr": "string",
+    ///     "port": 123,
+    ///
+    ///     // Number of workers
+    ///     "workers": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    BeforeCommentedFields,
+
+    /// Inserts a blank line between all the fields:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at
+    ///     addr: String,
+    ///     port: u16,
+    ///     /// Number of workers
+    ///     workers: usize,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     blank_lines: doku::json::BlankLines::BetweenFields,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Address to listen at
+    ///     "addr": "string",
+    ///
+    ///     "port": 123,
+    ///
+    ///     // Number of workers
+    ///     "workers": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    BetweenFields,
+}
+
+impl Default for BlankLines {
+    fn default() -> Self {
+        Self::Never
+    }
+}
@@ -0,0 +1,170 @@
+use super::CommentsStyle;
+use crate::*;
+
+/// Determines colors of the document, making it more readable when printed
+/// into a terminal (requires the `color` feature):
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Port to listen at
+///     port: u16,
+/// }
+///
+/// let fmt = doku::json::Formatting {
+///     colors: Some(doku::json::Colors::default()),
+///     ..Default::default()
+/// };
+///
+/// let doc = doku::to_json_fmt::<Config>(&fmt);
+///
+/// assert_eq!(
+///     "{\n  \u{1b}[90m// Port to listen at\u{1b}[0m\n  \u{1b}[34m\"port\"\u{1b}[0m: \u{1b}[32m123\u{1b}[0m\n}",
+///     doc,
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(default)]
+pub struct Colors {
+    pub keys: Color,
+    pub values: Color,
+    pub comments: Color,
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Self {
+            keys: Color::Blue,
+            values: Color::Green,
+            comments: Color::BrightBlack,
+        }
+    }
+}
+
+impl Colors {
+    /// Colors given (already rendered) document.
+    pub(crate) fn paint(&self, doc: &str, comments: &CommentsStyle) -> String {
+        doc.split('\n')
+            .map(|line| self.paint_line(line, &comments.separator))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn paint_line(&self, line: &str, separator: &str) -> String {
+        let mut result = String::new();
+        let mut rest = line;
+
+        while let Some(ch) = rest.chars().next() {
+            // Comments (and commented-out lines) span until the end of line
+            if !separator.is_empty() && rest.starts_with(separator) {
+                result.push_str(&self.comments.paint(rest));
+                break;
+            }
+
+            let len = if rest.starts_with("/*") {
+                // Ellipsis (`/* ... */`)
+                rest.find("*/").map_or(rest.len(), |idx| idx + 2)
+            } else if ch == '"' {
+                string_len(rest)
+            } else if is_word(ch) {
+                rest.find(|ch| !is_word(ch)).unwrap_or(rest.len())
+            } else {
+                0
+            };
+
+            if len == 0 {
+                result.push(ch);
+                rest = &rest[ch.len_utf8()..];
+                continue;
+            }
+
+            let (token, tail) = rest.split_at(len);
+
+            let color = if token.starts_with("/*") {
+                self.comments
+            } else if tail.trim_start().starts_with(':') {
+                self.keys
+            } else {
+                self.values
+            };
+
+            result.push_str(&color.paint(token));
+            rest = tail;
+        }
+
+        result
+    }
+}
+
+/// Returns length of the string literal at the beginning of given text
+/// (including quotes).
+fn string_len(text: &str) -> usize {
+    let mut escaped = false;
+
+    for (idx, ch) in text.char_indices().skip(1) {
+        match ch {
+            '\\' if !escaped => escaped = true,
+            '"' if !escaped => return idx + 1,
+            _ => escaped = false,
+        }
+    }
+
+    text.len()
+}
+
+/// Returns whether given character can be a part of an unquoted value (e.g.
+/// `123.45`, `true` or `<Database>`).
+fn is_word(ch: char) -> bool {
+    ch.is_alphanumeric() || "_-+.<>".contains(ch)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl Color {
+    fn code(self) -> u8 {
+        match self {
+            Self::Black => 30,
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Yellow => 33,
+            Self::Blue => 34,
+            Self::Magenta => 35,
+            Self::Cyan => 36,
+            Self::White => 37,
+            Self::BrightBlack => 90,
+            Self::BrightRed => 91,
+            Self::BrightGreen => 92,
+            Self::BrightYellow => 93,
+            Self::BrightBlue => 94,
+            Self::BrightMagenta => 95,
+            Self::BrightCyan => 96,
+            Self::BrightWhite => 97,
+        }
+    }
+
+    fn paint(self, text: &str) -> String {
+        format!("\u{1b}[{}m{}\u{1b}[0m", self.code(), text)
+    }
+}
@@ -0,0 +1,247 @@
+use crate::*;
+
+/// Determines where comments get placed in `Layout::TwoColumns`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ColumnsStyle {
+    /// Determines which lines get their comments aligned together, when
+    /// `Layout::TwoColumns { align: true, .. }` is used.
+    #[serde(default)]
+    pub align_scope: AlignScope,
+
+    /// When set, comments start at given column, regardless of the `align`
+    /// setting:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Person {
+    ///     /// First name
+    ///     name: String,
+    ///
+    ///     /// Favourite numbers
+    ///     numbers: Vec<usize>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     columns_style: doku::json::ColumnsStyle {
+    ///         column: Some(24),
+    ///         ..Default::default()
+    ///     },
+    ///     layout: doku::json::Layout::TwoColumns {
+    ///         align: false,
+    ///         spacing: 1,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Person>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "name": "string",     // First name
+    ///     "numbers": [          // Favourite numbers
+    ///       123,
+    ///       /* ... */
+    ///     ]
+    ///   }
+    /// "#, doc);
+    /// ```
+    #[serde(default)]
+    pub column: Option<usize>,
+
+    /// Determines what happens with lines that don't fit before `column`.
+    #[serde(default)]
+    pub overflow: ColumnOverflow,
+}
+
+/// Determines which lines get their comments aligned together.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum AlignScope {
+    /// Aligns comments across the entire document:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Database's configuration
+    ///     db: Database,
+    ///
+    ///     /// Number of workers
+    ///     workers: usize,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Database {
+    ///     /// Connection string
+    ///     connection_string: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     columns_style: doku::json::ColumnsStyle {
+    ///         align_scope: doku::json::AlignScope::Document,
+    ///         ..Default::default()
+    ///     },
+    ///     layout: doku::json::Layout::TwoColumns {
+    ///         align: true,
+    ///         spacing: 1,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "db": {                         // Database's configuration
+    ///       "connection_string": "string" // Connection string
+    ///     },
+    ///     "workers": 123                  // Number of workers
+    ///   }
+    /// "#, doc);
+    /// ```
+    Document,
+
+    /// Aligns comments of each object separately:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Database's configuration
+    ///     db: Database,
+    ///
+    ///     /// Number of workers
+    ///     workers: usize,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Database {
+    ///     /// Connection string
+    ///     connection_string: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     columns_style: doku::json::ColumnsStyle {
+    ///         align_scope: doku::json::AlignScope::Object,
+    ///         ..Default::default()
+    ///     },
+    ///     layout: doku::json::Layout::TwoColumns {
+    ///         align: true,
+    ///         spacing: 1,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "db": {        // Database's configuration
+    ///       "connection_string": "string" // Connection string
+    ///     },
+    ///     "workers": 123 // Number of workers
+    ///   }
+    /// "#, doc);
+    /// ```
+    Object,
+}
+
+impl Default for AlignScope {
+    fn default() -> Self {
+        Self::Document
+    }
+}
+
+/// Determines what happens with lines that don't fit before
+/// `ColumnsStyle::column`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum ColumnOverflow {
+    /// Moves the comment to the right, right after the line:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at
+    ///     addr: String,
+    ///
+    ///     /// Connection string
+    ///     connection_string: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     columns_style: doku::json::ColumnsStyle {
+    ///         column: Some(20),
+    ///         overflow: doku::json::ColumnOverflow::Shift,
+    ///         ..Default::default()
+    ///     },
+    ///     layout: doku::json::Layout::TwoColumns {
+    ///         align: false,
+    ///         spacing: 1,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "addr": "string", // Address to listen at
+    ///     "connection_string": "string" // Connection string
+    ///   }
+    /// "#, doc);
+    /// ```
+    Shift,
+
+    /// Moves the comment to the next line, keeping it at the column:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at
+    ///     addr: String,
+    ///
+    ///     /// Connection string
+    ///     connection_string: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     columns_style: doku::json::ColumnsStyle {
+    ///         column: Some(20),
+    ///         overflow: doku::json::ColumnOverflow::NextLine,
+    ///         ..Default::default()
+    ///     },
+    ///     layout: doku::json::Layout::TwoColumns {
+    ///         align: false,
+    ///         spacing: 1,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "addr": "string", // Address to listen at
+    ///     "connection_string": "string"
+    ///                       // Connection string
+    ///   }
+    /// "#, doc);
+    /// ```
+    NextLine,
+}
+
+impl Default for ColumnOverflow {
+    fn default() -> Self {
+        Self::Shift
+    }
+}
@@ -1,8 +1,37 @@
 use crate::*;
+use std::borrow::Cow;
 
 /// Determines the comments style.
+///
+/// Apart from setting the fields directly, there are a few presets available
+/// - e.g. `CommentsStyle::hash()`, for tools that accept `#`-comments:
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Person {
+///     /// First name
+///     name: String,
+/// }
+///
+/// let fmt = doku::json::Formatting {
+///     comments_style: doku::json::CommentsStyle::block(),
+///     ..Default::default()
+/// };
+///
+/// let doc = doku::to_json_fmt::<Person>(&fmt);
+///
+/// doku::assert_doc!(r#"
+///   {
+///     /* First name */
+///     "name": "string"
+///   }
+/// "#, doc);
+/// ```
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
+#[serde(default)]
 pub struct CommentsStyle {
     /// String to use as comment separator
     ///
@@ -16,7 +45,10 @@ pub struct CommentsStyle {
     /// }
     ///
     /// let fmt = doku::json::Formatting {
-    ///     comments_style: doku::json::CommentsStyle { separator: "#".to_owned() },
+    ///     comments_style: doku::json::CommentsStyle {
+    ///         separator: "#".to_owned(),
+    ///         ..Default::default()
+    ///     },
     ///     ..Default::default()
     /// };
     ///
@@ -30,12 +62,292 @@ pub struct CommentsStyle {
     /// "#, doc);
     /// ```
     pub separator: String,
+
+    /// String to put at the end of each comment line (e.g. `*/`); empty by
+    /// default.
+    pub suffix: String,
+
+    /// Whether to skip all the comments, making the document contain only
+    /// the values (see: `CommentsStyle::none()`).
+    pub hidden: bool,
+
+    /// When set, doc-comments get re-flowed to fit given number of characters
+    /// (not counting the separator and indentation): paragraphs and list
+    /// items get wrapped, while code blocks are kept as they are.
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at; supports both IPv4 and IPv6
+    ///     /// addresses, with an optional port.
+    ///     addr: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     comments_style: doku::json::CommentsStyle {
+    ///         width: Some(30),
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Address to listen at; supports
+    ///     // both IPv4 and IPv6 addresses,
+    ///     // with an optional port.
+    ///     "addr": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub width: Option<usize>,
+
+    /// When set, comments (including auto-comments) that would make a line
+    /// longer than given number of characters get wrapped across multiple
+    /// lines; unlike `width`, this counts the entire line, together with the
+    /// indentation and - in `Layout::TwoColumns` - the left column.
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen at, e.g. for the incoming HTTP connections
+    ///     addr: std::net::SocketAddr,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     comments_style: doku::json::CommentsStyle {
+    ///         max_width: Some(60),
+    ///         ..Default::default()
+    ///     },
+    ///     layout: doku::json::Layout::TwoColumns {
+    ///         align: true,
+    ///         spacing: 1,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "addr": "127.0.0.1:8080" // Address to listen at, e.g. for
+    ///                              // the incoming HTTP connections
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub max_width: Option<usize>,
+}
+
+impl CommentsStyle {
+    /// `// comment`
+    pub fn double_slash() -> Self {
+        Self::default()
+    }
+
+    /// `# comment`
+    pub fn hash() -> Self {
+        Self::with_separator("#")
+    }
+
+    /// `; comment`
+    pub fn semicolon() -> Self {
+        Self::with_separator(";")
+    }
+
+    /// `/* comment */`
+    pub fn block() -> Self {
+        Self {
+            suffix: "*/".to_owned(),
+            ..Self::with_separator("/*")
+        }
+    }
+
+    /// No comments at all.
+    pub fn none() -> Self {
+        Self {
+            hidden: true,
+            ..Self::default()
+        }
+    }
+
+    fn with_separator(separator: &str) -> Self {
+        Self {
+            separator: separator.to_owned(),
+            ..Self::default()
+        }
+    }
+
+    /// Turns given line of text into a comment.
+    pub(crate) fn render(&self, line: &str) -> String {
+        if self.suffix.is_empty() {
+            format!("{} {}", self.separator, line)
+        } else if line.is_empty() {
+            format!("{} {}", self.separator, self.suffix)
+        } else {
+            format!("{} {} {}", self.separator, line, self.suffix)
+        }
+    }
+
+    /// Re-flows given doc-comment according to `self.width`.
+    pub(crate) fn reflow<'a>(&self, comment: &'a str) -> Cow<'a, str> {
+        let width = if let Some(width) = self.width {
+            width
+        } else {
+            return Cow::Borrowed(comment);
+        };
+
+        let mut lines = Vec::new();
+
+        for (idx, block) in CommentBlock::parse(comment).into_iter().enumerate()
+        {
+            if idx > 0 {
+                lines.push(String::new());
+            }
+
+            match block {
+                CommentBlock::Paragraph(text) => {
+                    wrap(&text, width, "", "", &mut lines);
+                }
+
+                CommentBlock::Code { code, .. } => {
+                    lines.extend(
+                        code.lines().map(|line| format!("    {}", line)),
+                    );
+                }
+
+                CommentBlock::List { ordered, items } => {
+                    for (idx, item) in items.iter().enumerate() {
+                        let bullet = if ordered {
+                            format!("{}. ", idx + 1)
+                        } else {
+                            "- ".to_owned()
+                        };
+
+                        let indent = " ".repeat(bullet.len());
+
+                        wrap(item, width, &bullet, &indent, &mut lines);
+                    }
+                }
+            }
+        }
+
+        Cow::Owned(lines.join("\n"))
+    }
+
+    /// Splits given comment lines so that - when printed at given column,
+    /// after the separator - they fit within `self.max_width`.
+    ///
+    /// Leading whitespace (e.g. of a code block) is kept on each of the
+    /// resulting lines, and list items get a hanging indent.
+    pub(crate) fn fit<'a>(
+        &self,
+        comments: impl IntoIterator<Item = &'a str>,
+        column: usize,
+    ) -> Vec<String> {
+        let mut overhead = column + self.separator.chars().count() + 1;
+
+        if !self.suffix.is_empty() {
+            overhead += self.suffix.chars().count() + 1;
+        }
+        let mut lines = Vec::new();
+
+        for comment in comments {
+            let max_width = match self.max_width {
+                Some(max_width)
+                    if overhead + comment.chars().count() > max_width =>
+                {
+                    max_width
+                }
+
+                _ => {
+                    lines.push(comment.to_owned());
+                    continue;
+                }
+            };
+
+            let text = comment.trim_start();
+            let indent = &comment[..comment.len() - text.len()];
+            let bullet = list_bullet(text).unwrap_or("");
+
+            wrap(
+                &text[bullet.len()..],
+                max_width.saturating_sub(overhead),
+                &format!("{}{}", indent, bullet),
+                &format!("{}{}", indent, " ".repeat(bullet.len())),
+                &mut lines,
+            );
+        }
+
+        lines
+    }
+}
+
+/// Returns the bullet (e.g. `- ` or `1. `) given line starts with, if any.
+fn list_bullet(line: &str) -> Option<&str> {
+    if line.starts_with("- ") || line.starts_with("* ") {
+        return Some(&line[..2]);
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+
+    if digits > 0 && line[digits..].starts_with(". ") {
+        Some(&line[..digits + 2])
+    } else {
+        None
+    }
+}
+
+/// Splits given text into lines of at most `width` characters (unless a single
+/// word is longer than that); the first line gets prefixed with `first`, the
+/// rest - with `rest`.
+fn wrap(
+    text: &str,
+    width: usize,
+    first: &str,
+    rest: &str,
+    out: &mut Vec<String>,
+) {
+    let mut line = first.to_owned();
+    let mut line_len = first.chars().count();
+    let mut line_empty = true;
+
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+
+        if !line_empty && line_len + 1 + word_len > width {
+            out.push(line);
+            line = rest.to_owned();
+            line_len = rest.chars().count();
+            line_empty = true;
+        }
+
+        if !line_empty {
+            line.push(' ');
+            line_len += 1;
+        }
+
+        line.push_str(word);
+        line_len += word_len;
+        line_empty = false;
+    }
+
+    out.push(line);
 }
 
 impl Default for CommentsStyle {
     fn default() -> Self {
         Self {
             separator: "//".to_owned(),
+            suffix: String::new(),
+            hidden: false,
+            width: None,
+            max_width: None,
         }
     }
 }
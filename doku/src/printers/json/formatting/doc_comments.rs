@@ -1,6 +1,34 @@
 use crate::*;
 
 /// Determines if doc-comments should get displayed.
+///
+/// Doc-comments are controlled independently from the auto-comments (see:
+/// `AutoComments`), so e.g. in order to display only the doc-comments, use:
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Person {
+///     /// First name
+///     name: Option<String>,
+/// }
+///
+/// let fmt = doku::json::Formatting {
+///     auto_comments: doku::json::AutoComments::none(),
+///     doc_comments: doku::json::DocComments::Visible,
+///     ..Default::default()
+/// };
+///
+/// let doc = doku::to_json_fmt::<Person>(&fmt);
+///
+/// doku::assert_doc!(r#"
+///   {
+///     // First name
+///     "name": "string"
+///   }
+/// "#, doc);
+/// ```
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub enum DocComments {
@@ -0,0 +1,72 @@
+use crate::*;
+
+/// Determines if the type's own doc-comment (i.e. the one placed on the struct
+/// or enum, not on any of its fields) should get displayed as the document's
+/// header.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum DocHeader {
+    /// Shows the doc-comment as a header:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// /// Application's configuration
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen on
+    ///     addr: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     doc_header: doku::json::DocHeader::Visible,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   // Application's configuration
+    ///
+    ///   {
+    ///     // Address to listen on
+    ///     "addr": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    Visible,
+
+    /// Hides the doc-comment:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// /// Application's configuration
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Address to listen on
+    ///     addr: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     doc_header: doku::json::DocHeader::Hidden,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Address to listen on
+    ///     "addr": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    Hidden,
+}
+
+impl Default for DocHeader {
+    fn default() -> Self {
+        Self::Hidden
+    }
+}
@@ -0,0 +1,79 @@
+use crate::*;
+
+/// Determines if items marked with `#[doc(hidden)]` should get displayed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum DocHidden {
+    /// Skips fields and variants marked with `#[doc(hidden)]`:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     port: u16,
+    ///
+    ///     #[doc(hidden)]
+    ///     debug_token: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     doc_hidden: doku::json::DocHidden::Hidden,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "port": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    Hidden,
+
+    /// Displays fields and variants marked with `#[doc(hidden)]` as if they
+    /// were regular ones:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     port: u16,
+    ///
+    ///     #[doc(hidden)]
+    ///     debug_token: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     doc_hidden: doku::json::DocHidden::Visible,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "port": 123,
+    ///     "debug_token": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    Visible,
+}
+
+impl DocHidden {
+    pub(crate) fn allows(&self, hidden: bool) -> bool {
+        match self {
+            Self::Hidden => !hidden,
+            Self::Visible => true,
+        }
+    }
+}
+
+impl Default for DocHidden {
+    fn default() -> Self {
+        Self::Hidden
+    }
+}
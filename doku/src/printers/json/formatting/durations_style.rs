@@ -0,0 +1,71 @@
+use crate::*;
+
+/// Determines how durations should get displayed.
+///
+/// Note that `std::time::Duration` is documented through its Serde shape (i.e.
+/// `{ "secs": ..., "nanos": ... }`) unless the field is marked with
+/// `#[doku(duration)]` - which is what e.g. `humantime-serde` requires.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum DurationsStyle {
+    /// Displays durations as human-friendly strings (as accepted e.g. by the
+    /// `humantime-serde` crate):
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     #[doku(duration)]
+    ///     timeout: Duration,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     durations_style: doku::json::DurationsStyle::Humantime,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "timeout": "30s"
+    ///   }
+    /// "#, doc);
+    /// ```
+    Humantime,
+
+    /// Displays durations as numbers of seconds:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     #[doku(duration)]
+    ///     timeout: Duration,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     durations_style: doku::json::DurationsStyle::Seconds,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "timeout": 30
+    ///   }
+    /// "#, doc);
+    /// ```
+    Seconds,
+}
+
+impl Default for DurationsStyle {
+    fn default() -> Self {
+        Self::Humantime
+    }
+}
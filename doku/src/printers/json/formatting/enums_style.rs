@@ -40,6 +40,53 @@ pub enum EnumsStyle {
     /// ```
     Commented,
 
+    /// Displays the example variant as-is, with all the other variants (and
+    /// their fields) listed as commented-out alternatives:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize, Document)]
+    /// struct Config {
+    ///     storage: Storage,
+    /// }
+    ///
+    /// #[derive(Serialize, Document)]
+    /// #[serde(tag = "type")]
+    /// enum Storage {
+    ///     /// Keeps everything in memory
+    ///     Memory,
+    ///
+    ///     /// Keeps everything on disk
+    ///     Disk {
+    ///         path: String,
+    ///     },
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     enums_style: doku::json::EnumsStyle::Expanded,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Keeps everything in memory; alternatively:
+    ///     // - {
+    ///     //     "type": "Disk",
+    ///     //     "path": "string"
+    ///     //   }
+    ///     //   = Keeps everything on disk
+    ///     "storage": {
+    ///       "type": "Memory"
+    ///     }
+    ///   }
+    /// "#, doc);
+    /// ```
+    Expanded,
+
     /// Displays enum variants as separated with a pipe:
     ///
     /// ```
@@ -0,0 +1,48 @@
+use std::{error, fmt};
+
+/// Error returned when formatting couldn't be loaded - e.g. by
+/// `Formatting::from_json()`.
+#[derive(Debug)]
+pub enum FormattingError {
+    Json(serde_json::Error),
+
+    #[cfg(feature = "serde-yaml-08")]
+    Yaml(serde_yaml::Error),
+
+    #[cfg(feature = "toml-05")]
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for FormattingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(err) => {
+                write!(f, "Couldn't load formatting from JSON: {}", err)
+            }
+
+            #[cfg(feature = "serde-yaml-08")]
+            Self::Yaml(err) => {
+                write!(f, "Couldn't load formatting from YAML: {}", err)
+            }
+
+            #[cfg(feature = "toml-05")]
+            Self::Toml(err) => {
+                write!(f, "Couldn't load formatting from TOML: {}", err)
+            }
+        }
+    }
+}
+
+impl error::Error for FormattingError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Json(err) => Some(err),
+
+            #[cfg(feature = "serde-yaml-08")]
+            Self::Yaml(err) => Some(err),
+
+            #[cfg(feature = "toml-05")]
+            Self::Toml(err) => Some(err),
+        }
+    }
+}
@@ -22,6 +22,7 @@ pub struct IndentStyle {
     /// let fmt = doku::json::Formatting {
     ///     indent_style: doku::json::IndentStyle {
     ///         size: 8,
+    ///         ..Default::default()
     ///     },
     ///     ..Default::default()
     /// };
@@ -40,10 +41,65 @@ pub struct IndentStyle {
     /// "#, doc);
     /// ```
     pub size: usize,
+
+    /// Whether to indent using tabs instead of spaces; `size` is then used
+    /// only to align comments in `Layout::TwoColumns`, so it should match
+    /// the tab width of the reader's editor:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Response {
+    ///     people: Vec<Person>,
+    /// }
+    ///
+    /// #[derive(Document)]
+    /// struct Person {
+    ///     name: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     indent_style: doku::json::IndentStyle {
+    ///         use_tabs: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Response>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///   <tab>"people": [
+    ///   <tab><tab>{
+    ///   <tab><tab><tab>"name": "string"
+    ///   <tab><tab>},
+    ///   <tab><tab>/* ... */
+    ///   <tab>]
+    ///   }
+    /// "#, doc.replace('\t', "<tab>"));
+    /// ```
+    #[serde(default)]
+    pub use_tabs: bool,
+}
+
+impl IndentStyle {
+    /// Returns text representing given indenting level.
+    pub(crate) fn render(&self, level: usize) -> String {
+        if self.use_tabs {
+            "\t".repeat(level)
+        } else {
+            " ".repeat(self.size * level)
+        }
+    }
 }
 
 impl Default for IndentStyle {
     fn default() -> Self {
-        Self { size: 2 }
+        Self {
+            size: 2,
+            use_tabs: false,
+        }
     }
 }
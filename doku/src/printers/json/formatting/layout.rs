@@ -1,7 +1,7 @@
 use crate::*;
 
 /// Determines whether the document should contain one or two columns.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub enum Layout {
     /// Prints types and comments inline, in a single chunk of text:
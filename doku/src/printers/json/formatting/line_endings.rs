@@ -0,0 +1,84 @@
+use crate::*;
+
+/// Determines how lines of the document should be terminated.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LineEndings {
+    /// Character sequence used to separate lines:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     port: u16,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     line_endings: doku::json::LineEndings {
+    ///         newline: doku::json::Newline::CrLf,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// assert_eq!("{\r\n  \"port\": 123\r\n}", doc);
+    /// ```
+    pub newline: Newline,
+
+    /// Whether the document should end with a newline:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     port: u16,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     line_endings: doku::json::LineEndings {
+    ///         final_newline: true,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// assert_eq!("{\n  \"port\": 123\n}\n", doc);
+    /// ```
+    pub final_newline: bool,
+}
+
+impl LineEndings {
+    pub(crate) fn apply(&self, mut doc: String) -> String {
+        if self.final_newline {
+            doc.push('\n');
+        }
+
+        match self.newline {
+            Newline::Lf => doc,
+            Newline::CrLf => doc.replace('\n', "\r\n"),
+        }
+    }
+}
+
+/// Character sequence used to separate lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum Newline {
+    /// `\n`, as used on Unix-like systems.
+    Lf,
+
+    /// `\r\n`, as used on Windows.
+    CrLf,
+}
+
+impl Default for Newline {
+    fn default() -> Self {
+        Self::Lf
+    }
+}
@@ -0,0 +1,47 @@
+use crate::*;
+
+/// Determines the maps style.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MapsStyle {
+    /// How many example entries should get displayed for each map:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use std::collections::BTreeMap;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     services: BTreeMap<String, usize>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     maps_style: doku::json::MapsStyle {
+    ///         entries: 2,
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "services": {
+    ///       "string": 123,
+    ///       "string": 123,
+    ///       /* ... */
+    ///     }
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// When the key's type has multiple examples, consecutive entries use
+    /// consecutive examples.
+    pub entries: usize,
+}
+
+impl Default for MapsStyle {
+    fn default() -> Self {
+        Self { entries: 1 }
+    }
+}
@@ -0,0 +1,350 @@
+use crate::*;
+use std::borrow::Cow;
+use std::fmt::{Display, Write};
+
+/// Texts used to build auto-comments, allowing to translate them.
+///
+/// Messages are written the way they appear in the middle of a comment (e.g.
+/// `optional`) - when a message starts the comment, its first letter gets
+/// capitalized. Parts in braces (e.g. `{count}`) are placeholders that get
+/// replaced with the actual values:
+///
+/// ```
+/// use doku::Document;
+///
+/// #[derive(Document)]
+/// struct Config {
+///     /// Port to listen at
+///     port: Option<u16>,
+///
+///     workers: [String; 4],
+/// }
+///
+/// let fmt = doku::json::Formatting {
+///     messages: doku::json::Messages {
+///         optional: "opcjonalne".into(),
+///         array_size: "musi zawierać dokładnie {count} {elements}".into(),
+///         elements: "elementy".into(),
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+///
+/// let doc = doku::to_json_fmt::<Config>(&fmt);
+///
+/// doku::assert_doc!(r#"
+///   {
+///     // Port to listen at; opcjonalne
+///     "port": 123,
+///     // Musi zawierać dokładnie 4 elementy
+///     "workers": [
+///       "string",
+///       /* ... */
+///     ]
+///   }
+/// "#, doc);
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Messages {
+    /// Displayed next to fields that have been added, when printing a diff
+    /// (see: `Printer::with_old_value()`).
+    pub added: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::aliases`; placeholders: `{aliases}`.
+    pub aliases: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to `serde_json::Value`-s.
+    pub any_json: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::array_size`; placeholders: `{count}` and
+    /// `{elements}` (which is either `element` or `elements`).
+    pub array_size: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::bytes`.
+    pub bytes: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::cfg`; placeholders: `{cfg}`.
+    pub cfg: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::cfg`, when the condition is a single
+    /// feature; placeholders: `{feature}`.
+    pub cfg_feature: Cow<'static, str>,
+
+    /// Displayed next to fields that have been changed, when printing a diff
+    /// (see: `Printer::with_old_value()`); placeholders: `{value}`.
+    pub changed: Cow<'static, str>,
+
+    /// Noun used by `length_*` messages for strings, when the length is 1.
+    pub character: Cow<'static, str>,
+
+    /// Noun used by `length_*` messages for strings.
+    pub characters: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to dates.
+    pub date: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to dates with time that
+    /// include an offset from UTC (e.g. `chrono::DateTime<Utc>`).
+    pub date_time: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to decimal numbers of
+    /// unlimited precision (e.g. `bigdecimal::BigDecimal`).
+    pub decimal: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to decimal numbers of
+    /// limited precision (e.g. `rust_decimal::Decimal`); placeholders:
+    /// `{digits}`.
+    pub decimal_digits: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::defaults`, when the default value is
+    /// known; placeholders: `{value}`.
+    pub defaults_to: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to durations printed with
+    /// `DurationsStyle::Seconds`.
+    pub duration_seconds: Cow<'static, str>,
+
+    /// Noun used by `array_size` and `length_*` messages, when the number of
+    /// elements is 1.
+    pub element: Cow<'static, str>,
+
+    /// Noun used by `array_size` and `length_*` messages.
+    pub elements: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::enum_values`, next to an enum's fallback
+    /// variant (`#[serde(other)]`); placeholders: `{value}`.
+    pub enum_fallback: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::enum_values`; placeholders: `{values}`.
+    pub enum_values: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::env`; placeholders: `{env}`.
+    pub env: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::float_precision` next to `f32`-s.
+    pub float32: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::float_precision` next to `f64`-s.
+    pub float64: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::defaults`, when the default value is not
+    /// known.
+    pub has_default: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to `IpAddr`-s.
+    pub ip_addr: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to `Ipv4Addr`-s.
+    pub ipv4_addr: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to `Ipv6Addr`-s.
+    pub ipv6_addr: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::length`; placeholders: `{min}` and
+    /// `{noun}`.
+    pub length_at_least: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::length`; placeholders: `{max}` and
+    /// `{noun}`.
+    pub length_at_most: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::length`; placeholders: `{min}`, `{max}`
+    /// and `{noun}`.
+    pub length_between: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::length` when the minimum and maximum
+    /// length are the same; placeholders: `{length}` and `{noun}`.
+    pub length_exactly: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::location`; placeholders: `{location}`.
+    pub location: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::range`; placeholders: `{value}`.
+    pub multiple_of: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to dates with time that
+    /// don't include any offset from UTC (e.g. `chrono::NaiveDateTime`).
+    pub naive_date_time: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::one_of`; placeholders: `{values}`.
+    pub one_of: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::optional`.
+    pub optional: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to `PathBuf`-s.
+    pub path: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::pattern`; placeholders: `{pattern}`.
+    pub pattern: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::range`; placeholders: `{min}`.
+    pub range_at_least: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::range`; placeholders: `{max}`.
+    pub range_at_most: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::range`; placeholders: `{min}` and `{max}`.
+    pub range_between: Cow<'static, str>,
+
+    /// Displayed next to fields that have been removed, when printing a diff
+    /// (see: `Printer::with_old_value()`); placeholders: `{value}`.
+    pub removed: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::required`.
+    pub required: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::rust_names`; placeholders: `{name}`.
+    pub rust_name: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::sensitive`.
+    pub sensitive: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::integer_range` next to signed integers;
+    /// placeholders: `{bits}`, `{min}` and `{max}`.
+    pub signed_integer: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::versions`; placeholders: `{version}`.
+    pub since: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to `SocketAddr`-s.
+    pub socket_addr: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to `SocketAddrV4`-s.
+    pub socket_addr_v4: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to `SocketAddrV6`-s.
+    pub socket_addr_v6: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to times of day.
+    pub time: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::unit`; placeholders: `{unit}`.
+    pub unit: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::unknown_fields`.
+    pub unknown_fields: Cow<'static, str>,
+
+    /// Displayed for keys that don't correspond to any of the fields, when
+    /// printing a diff (see: `Printer::with_old_value()`); placeholders:
+    /// `{key}`.
+    pub unknown_key: Cow<'static, str>,
+
+    /// Displayed next to fields missing from a partial value (see:
+    /// `Printer::with_partial_value()`).
+    pub unset: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::integer_range` next to unsigned
+    /// integers; placeholders: `{bits}`, `{min}` and `{max}`.
+    pub unsigned_integer: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::versions`; placeholders: `{version}`.
+    pub until: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to URLs.
+    pub url: Cow<'static, str>,
+
+    /// Displayed for `AutoComments::formats` next to UUIDs.
+    pub uuid: Cow<'static, str>,
+}
+
+impl Messages {
+    /// Replaces placeholders in given message with their values; unknown
+    /// placeholders are left as they are.
+    pub(crate) fn fill(message: &str, args: &[(&str, &dyn Display)]) -> String {
+        let mut out = String::new();
+        let mut rest = message;
+
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let arg = rest.find('}').and_then(|end| {
+                args.iter()
+                    .find(|(name, _)| *name == &rest[1..end])
+                    .map(|(_, val)| (end, val))
+            });
+
+            if let Some((end, val)) = arg {
+                swrite!(out, "{}", val);
+                rest = &rest[end + 1..];
+            } else {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+
+        out.push_str(rest);
+        out
+    }
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Self {
+            added: "added".into(),
+            aliases: "aliases: {aliases}".into(),
+            any_json: "any JSON value".into(),
+            array_size: "must contain exactly {count} {elements}".into(),
+            bytes: "base64-encoded bytes".into(),
+            cfg: "only with cfg({cfg})".into(),
+            cfg_feature: "only with feature {feature}".into(),
+            changed: "changed from {value}".into(),
+            character: "character".into(),
+            characters: "characters".into(),
+            date: "ISO 8601 date".into(),
+            date_time: "RFC 3339 date and time".into(),
+            decimal: "decimal number, as a string".into(),
+            decimal_digits: "decimal number, as a string ({digits} digits max)"
+                .into(),
+            defaults_to: "optional, defaults to {value}".into(),
+            duration_seconds: "duration in seconds".into(),
+            element: "element".into(),
+            elements: "elements".into(),
+            enum_fallback: "any other value maps to {value}".into(),
+            enum_values: "one of: {values}".into(),
+            env: "env: {env}".into(),
+            float32: "floating-point number (single-precision, 32-bit)".into(),
+            float64: "floating-point number (double-precision, 64-bit)".into(),
+            has_default: "optional, has a default value".into(),
+            ip_addr: "IPv4 or IPv6 address".into(),
+            ipv4_addr: "IPv4 address".into(),
+            ipv6_addr: "IPv6 address".into(),
+            length_at_least: "must contain at least {min} {noun}".into(),
+            length_at_most: "must contain at most {max} {noun}".into(),
+            length_between: "must contain between {min} and {max} {noun}"
+                .into(),
+            length_exactly: "must contain exactly {length} {noun}".into(),
+            location: "defined in {location}".into(),
+            multiple_of: "must be a multiple of {value}".into(),
+            naive_date_time: "ISO 8601 date and time, without offset".into(),
+            one_of: "must be one of: {values}".into(),
+            optional: "optional".into(),
+            path: "filesystem path".into(),
+            pattern: "must match pattern: {pattern}".into(),
+            range_at_least: "must be at least {min}".into(),
+            range_at_most: "must be at most {max}".into(),
+            range_between: "must be between {min} and {max}".into(),
+            removed: "removed, was {value}".into(),
+            required: "required".into(),
+            rust_name: "Rust: {name}".into(),
+            sensitive: "sensitive — do not commit".into(),
+            signed_integer: "signed {bits}-bit integer ({min}..={max})".into(),
+            since: "since v{version}".into(),
+            socket_addr: "IPv4 or IPv6 address with port".into(),
+            socket_addr_v4: "IPv4 address with port".into(),
+            socket_addr_v6: "IPv6 address with port".into(),
+            time: "ISO 8601 time of day".into(),
+            unit: "in {unit}".into(),
+            unknown_fields: "unknown keys are rejected".into(),
+            unknown_key: "unknown key: {key}".into(),
+            unset: "unset, default shown".into(),
+            unsigned_integer: "unsigned {bits}-bit integer ({min}..={max})"
+                .into(),
+            until: "until v{version}".into(),
+            url: "URL".into(),
+            uuid: "UUID".into(),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::*;
 /// Determines the objects style.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
+#[serde(default)]
 pub struct ObjectsStyle {
     /// Whether to use quotes to surround keys
     ///
@@ -82,6 +83,87 @@ pub struct ObjectsStyle {
     /// "#, doc);
     /// ```
     pub use_comma_as_separator: bool,
+
+    /// Whether to mark arrays and maps with `/* ... */`, to indicate that they
+    /// might contain more elements than the ones displayed; disabling this
+    /// (together with the comments and `use_trailing_comma`) makes the
+    /// document a valid JSON:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Person {
+    ///     nicknames: Vec<String>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     objects_style: doku::json::ObjectsStyle {
+    ///         use_ellipsis: false,
+    ///         use_trailing_comma: false,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Person>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "nicknames": [
+    ///       "string"
+    ///     ]
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub use_ellipsis: bool,
+
+    /// Whether to put a comma after the last element of an array or map (when
+    /// it's not followed by the ellipsis) - can be disabled to get rid of the
+    /// comma e.g. after complete fixed-size arrays:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Person {
+    ///     #[doku(example = "Bob", example = "Bobby")]
+    ///     nicknames: [String; 2],
+    /// }
+    ///
+    /// let doc = doku::to_json::<Person>();
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Must contain exactly 2 elements
+    ///     "nicknames": [
+    ///       "Bob",
+    ///       "Bobby",
+    ///     ]
+    ///   }
+    /// "#, doc);
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     objects_style: doku::json::ObjectsStyle {
+    ///         use_trailing_comma: false,
+    ///         ..Default::default()
+    ///     },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Person>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Must contain exactly 2 elements
+    ///     "nicknames": [
+    ///       "Bob",
+    ///       "Bobby"
+    ///     ]
+    ///   }
+    /// "#, doc);
+    /// ```
+    pub use_trailing_comma: bool,
 }
 
 impl Default for ObjectsStyle {
@@ -89,6 +171,8 @@ impl Default for ObjectsStyle {
         Self {
             surround_keys_with_quotes: true,
             use_comma_as_separator: true,
+            use_ellipsis: true,
+            use_trailing_comma: true,
         }
     }
 }
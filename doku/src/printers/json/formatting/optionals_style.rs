@@ -0,0 +1,113 @@
+use crate::*;
+
+/// Determines how optional values (e.g. `Option<String>`) should get
+/// displayed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum OptionalsStyle {
+    /// Displays optional values as if they were required:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Proxy to connect through
+    ///     proxy: Option<String>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     optionals_style: doku::json::OptionalsStyle::Value,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Proxy to connect through; optional
+    ///     "proxy": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    Value,
+
+    /// Displays optional values as `null`, with the value's shape in a
+    /// comment:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     /// Proxy to connect through
+    ///     proxy: Option<String>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     optionals_style: doku::json::OptionalsStyle::Null,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Proxy to connect through; optional; e.g. "string"
+    ///     "proxy": null
+    ///   }
+    /// "#, doc);
+    /// ```
+    Null,
+
+    /// Displays fields that can be omitted (i.e. optional fields and fields
+    /// with a default value - see: `Type::optionality()`) as commented-out,
+    /// so that they are disabled by default:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize, Document)]
+    /// struct Config {
+    ///     /// Port to listen at
+    ///     port: u16,
+    ///
+    ///     /// Number of workers
+    ///     #[serde(default)]
+    ///     workers: usize,
+    ///
+    ///     /// Proxy to connect through
+    ///     proxy: Option<String>,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     optionals_style: doku::json::OptionalsStyle::CommentedOut,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Port to listen at
+    ///     "port": 123
+    ///     // Number of workers
+    ///     // "workers": 123
+    ///     // Proxy to connect through; optional
+    ///     // "proxy": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// Optional values that are not fields (e.g. `Vec<Option<String>>`) are
+    /// displayed as `null`; optional fields of flattened structs are displayed
+    /// as values.
+    CommentedOut,
+}
+
+impl Default for OptionalsStyle {
+    fn default() -> Self {
+        Self::Value
+    }
+}
@@ -0,0 +1,102 @@
+use crate::*;
+
+/// Selects fields by their path, e.g. `server.tls` or `server.*.port`; used by
+/// `Formatting::overrides`.
+///
+/// Path consists of field names (as they appear in the document) separated
+/// with dots, where:
+///
+/// - `*` matches exactly one field,
+/// - `**` matches any number of fields (including none).
+///
+/// Flattened structs, arrays, maps and enum variants don't contribute any
+/// segments to the path - e.g. fields of `Vec<Database>` stored under `dbs`
+/// are selected with `dbs.*`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Selector(String);
+
+impl Selector {
+    pub fn new(selector: impl Into<String>) -> Self {
+        Self(selector.into())
+    }
+
+    /// Returns whether this selector matches given path.
+    pub fn matches(&self, path: &[&str]) -> bool {
+        let pattern: Vec<_> = self.0.split('.').collect();
+
+        matches(&pattern, path)
+    }
+}
+
+impl From<&str> for Selector {
+    fn from(selector: &str) -> Self {
+        Self::new(selector)
+    }
+}
+
+impl From<String> for Selector {
+    fn from(selector: String) -> Self {
+        Self::new(selector)
+    }
+}
+
+fn matches(pattern: &[&str], path: &[&str]) -> bool {
+    match (pattern.split_first(), path.split_first()) {
+        (None, None) => true,
+
+        (Some((&"**", pattern_tail)), _) => {
+            matches(pattern_tail, path)
+                || (!path.is_empty() && matches(pattern, &path[1..]))
+        }
+
+        (Some((segment, pattern_tail)), Some((field, path_tail))) => {
+            (*segment == "*" || segment == field)
+                && matches(pattern_tail, path_tail)
+        }
+
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(selector: &str, path: &str) -> bool {
+        let path: Vec<_> = if path.is_empty() {
+            Vec::new()
+        } else {
+            path.split('.').collect()
+        };
+
+        Selector::new(selector).matches(&path)
+    }
+
+    #[test]
+    fn matches_paths() {
+        assert!(target("server", "server"));
+        assert!(!target("server", "server.tls"));
+        assert!(!target("server", "db"));
+
+        assert!(target("server.tls", "server.tls"));
+        assert!(!target("server.tls", "server"));
+
+        assert!(target("server.*", "server.tls"));
+        assert!(target("server.*", "server.port"));
+        assert!(!target("server.*", "server"));
+        assert!(!target("server.*", "server.tls.cert"));
+
+        assert!(target("*.port", "server.port"));
+        assert!(!target("*.port", "port"));
+
+        assert!(target("**.port", "port"));
+        assert!(target("**.port", "server.port"));
+        assert!(target("**.port", "server.admin.port"));
+        assert!(!target("**.port", "server.admin"));
+
+        assert!(target("server.**", "server"));
+        assert!(target("server.**", "server.tls.cert"));
+        assert!(!target("server.**", "db.url"));
+    }
+}
@@ -0,0 +1,102 @@
+use crate::*;
+
+/// Determines how values without any examples should get displayed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum Placeholders {
+    /// Displays made-up values:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     hostname: String,
+    ///     port: u16,
+    ///     verbose: bool,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     placeholders: doku::json::Placeholders::Examples,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "hostname": "string",
+    ///     "port": 123,
+    ///     "verbose": true
+    ///   }
+    /// "#, doc);
+    /// ```
+    Examples,
+
+    /// Displays names of the types, in angle brackets:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     hostname: String,
+    ///     port: u16,
+    ///     verbose: bool,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     placeholders: doku::json::Placeholders::TypeNames,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "hostname": "<string>",
+    ///     "port": <integer>,
+    ///     "verbose": <bool>
+    ///   }
+    /// "#, doc);
+    /// ```
+    TypeNames,
+
+    /// Displays names of the fields, in angle brackets:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     hostname: String,
+    ///     port: u16,
+    ///     verbose: bool,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     placeholders: doku::json::Placeholders::FieldNames,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "hostname": "<hostname>",
+    ///     "port": <port>,
+    ///     "verbose": <verbose>
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// Values that are not placed in any field (e.g. map keys) are displayed
+    /// as with `Placeholders::TypeNames`.
+    FieldNames,
+}
+
+impl Default for Placeholders {
+    fn default() -> Self {
+        Self::Examples
+    }
+}
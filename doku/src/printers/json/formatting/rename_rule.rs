@@ -0,0 +1,162 @@
+use crate::*;
+
+/// Casing applied to field names at print time; used by
+/// `Formatting::rename_all`.
+///
+/// Names get split into words on underscores, dashes and case changes (e.g.
+/// `httpServer` -> `http` + `Server`), so the rule can be applied regardless
+/// of how the fields have been named (or renamed) originally.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum RenameRule {
+    /// `verytasty` (only lowercases the name, keeping underscores and dashes,
+    /// so `very_tasty` stays `very_tasty` - just like in Serde)
+    #[serde(alias = "lowercase")]
+    LowerCase,
+
+    /// `VERYTASTY` (only uppercases the name, keeping underscores and dashes,
+    /// so `very_tasty` becomes `VERY_TASTY` - just like in Serde)
+    #[serde(alias = "UPPERCASE")]
+    UpperCase,
+
+    /// `VeryTasty`
+    PascalCase,
+
+    /// `veryTasty`
+    #[serde(alias = "camelCase")]
+    CamelCase,
+
+    /// `very_tasty`
+    #[serde(alias = "snake_case")]
+    SnakeCase,
+
+    /// `VERY_TASTY`
+    #[serde(alias = "SCREAMING_SNAKE_CASE")]
+    ScreamingSnakeCase,
+
+    /// `very-tasty`
+    #[serde(alias = "kebab-case")]
+    KebabCase,
+
+    /// `VERY-TASTY`
+    #[serde(alias = "SCREAMING-KEBAB-CASE")]
+    ScreamingKebabCase,
+}
+
+impl RenameRule {
+    /// Re-cases given field name according to this rule.
+    pub fn apply(&self, name: &str) -> String {
+        let words = words(name);
+
+        match self {
+            Self::LowerCase => name.to_lowercase(),
+            Self::UpperCase => name.to_uppercase(),
+            Self::PascalCase => pascal_case(&words, false),
+            Self::CamelCase => pascal_case(&words, true),
+            Self::SnakeCase => words.join("_").to_lowercase(),
+            Self::ScreamingSnakeCase => words.join("_").to_uppercase(),
+            Self::KebabCase => words.join("-").to_lowercase(),
+            Self::ScreamingKebabCase => words.join("-").to_uppercase(),
+        }
+    }
+}
+
+/// Splits given name into words, e.g. `httpServer_port` -> `http`, `Server`,
+/// `port`.
+fn words(name: &str) -> Vec<&str> {
+    let chars: Vec<_> = name.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start = 0;
+
+    for (idx, &(offset, ch)) in chars.iter().enumerate() {
+        if ch == '_' || ch == '-' {
+            if start < offset {
+                words.push(&name[start..offset]);
+            }
+
+            start = offset + ch.len_utf8();
+            continue;
+        }
+
+        if !ch.is_uppercase() || start == offset {
+            continue;
+        }
+
+        let prev = chars[idx - 1].1;
+        let next = chars.get(idx + 1).map(|(_, ch)| *ch);
+
+        // Splits `httpServer` into `http` + `Server` and `HTTPServer` into
+        // `HTTP` + `Server`
+        let is_boundary = !prev.is_uppercase()
+            || next.map_or(false, |next| next.is_lowercase());
+
+        if is_boundary {
+            words.push(&name[start..offset]);
+            start = offset;
+        }
+    }
+
+    if start < name.len() {
+        words.push(&name[start..]);
+    }
+
+    words
+}
+
+/// Joins given words into `PascalCase` (or `camelCase`, if
+/// `lowercase_first` is set).
+fn pascal_case(words: &[&str], lowercase_first: bool) -> String {
+    words
+        .iter()
+        .enumerate()
+        .map(|(idx, word)| {
+            let word = word.to_lowercase();
+
+            if idx == 0 && lowercase_first {
+                word
+            } else {
+                capitalize(&word)
+            }
+        })
+        .collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("very_tasty" => vec!["very", "tasty"])]
+    #[test_case("very-tasty" => vec!["very", "tasty"])]
+    #[test_case("veryTasty" => vec!["very", "Tasty"])]
+    #[test_case("VeryTasty" => vec!["Very", "Tasty"])]
+    #[test_case("VERY_TASTY" => vec!["VERY", "TASTY"])]
+    #[test_case("HTTPServer" => vec!["HTTP", "Server"])]
+    #[test_case("http2_port" => vec!["http2", "port"])]
+    #[test_case("_private" => vec!["private"])]
+    #[test_case("a" => vec!["a"])]
+    fn splits_words(name: &str) -> Vec<&str> {
+        words(name)
+    }
+
+    #[test_case(RenameRule::LowerCase => "very_tastyhttpport")]
+    #[test_case(RenameRule::UpperCase => "VERY_TASTYHTTPPORT")]
+    #[test_case(RenameRule::PascalCase => "VeryTastyHttpPort")]
+    #[test_case(RenameRule::CamelCase => "veryTastyHttpPort")]
+    #[test_case(RenameRule::SnakeCase => "very_tasty_http_port")]
+    #[test_case(RenameRule::ScreamingSnakeCase => "VERY_TASTY_HTTP_PORT")]
+    #[test_case(RenameRule::KebabCase => "very-tasty-http-port")]
+    #[test_case(RenameRule::ScreamingKebabCase => "VERY-TASTY-HTTP-PORT")]
+    fn applies_rule(rule: RenameRule) -> String {
+        rule.apply("very_tastyHTTPPort")
+    }
+}
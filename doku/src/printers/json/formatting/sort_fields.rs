@@ -0,0 +1,75 @@
+use crate::*;
+
+/// Determines the order in which fields (and map entries) get displayed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum SortFields {
+    /// Displays fields in the order of their declaration (taking into account
+    /// `#[doku(order = ...)]`):
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     port: u16,
+    ///     address: String,
+    ///     log_level: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     sort_fields: doku::json::SortFields::Declaration,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "port": 123,
+    ///     "address": "string",
+    ///     "log_level": "string"
+    ///   }
+    /// "#, doc);
+    /// ```
+    Declaration,
+
+    /// Displays fields sorted alphabetically by their (possibly renamed)
+    /// names; fields inside sections get sorted within their section:
+    ///
+    /// ```
+    /// use doku::Document;
+    ///
+    /// #[derive(Document)]
+    /// struct Config {
+    ///     port: u16,
+    ///     address: String,
+    ///     log_level: String,
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     sort_fields: doku::json::SortFields::Alphabetical,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "address": "string",
+    ///     "log_level": "string",
+    ///     "port": 123
+    ///   }
+    /// "#, doc);
+    /// ```
+    ///
+    /// Fields of a `#[serde(flatten)]`-ed struct are sorted separately and
+    /// displayed where the flattened field is.
+    Alphabetical,
+}
+
+impl Default for SortFields {
+    fn default() -> Self {
+        Self::Declaration
+    }
+}
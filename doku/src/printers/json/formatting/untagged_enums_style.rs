@@ -0,0 +1,96 @@
+use crate::*;
+
+/// Determines how untagged enums (think `#[serde(untagged)]`) should get
+/// displayed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum UntaggedEnumsStyle {
+    /// Displays untagged enums the same way as all the other enums, i.e.
+    /// according to [`EnumsStyle`]:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize, Document)]
+    /// struct Config {
+    ///     db: Database,
+    /// }
+    ///
+    /// #[derive(Serialize, Document)]
+    /// #[serde(untagged)]
+    /// enum Database {
+    ///     Url { url: String },
+    ///     Path { path: String },
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     untagged_enums_style: doku::json::UntaggedEnumsStyle::Inherited,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     "db": 
+    ///       {
+    ///         "url": "string"
+    ///       }
+    ///       // or
+    ///       {
+    ///         "path": "string"
+    ///       }
+    ///   }
+    /// "#, doc);
+    /// ```
+    Inherited,
+
+    /// Displays the example variant as-is, listing all the other variants as
+    /// commented-out alternatives - this way the document remains a valid
+    /// configuration file:
+    ///
+    /// ```
+    /// use doku::Document;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize, Document)]
+    /// struct Config {
+    ///     db: Database,
+    /// }
+    ///
+    /// #[derive(Serialize, Document)]
+    /// #[serde(untagged)]
+    /// #[doku(example_variant = "Path")]
+    /// enum Database {
+    ///     Url { url: String },
+    ///     Path { path: String },
+    /// }
+    ///
+    /// let fmt = doku::json::Formatting {
+    ///     untagged_enums_style: doku::json::UntaggedEnumsStyle::Alternatives,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let doc = doku::to_json_fmt::<Config>(&fmt);
+    ///
+    /// doku::assert_doc!(r#"
+    ///   {
+    ///     // Alternatively:
+    ///     // - {
+    ///     //     "url": "string"
+    ///     //   }
+    ///     "db": {
+    ///       "path": "string"
+    ///     }
+    ///   }
+    /// "#, doc);
+    /// ```
+    Alternatives,
+}
+
+impl Default for UntaggedEnumsStyle {
+    fn default() -> Self {
+        Self::Inherited
+    }
+}
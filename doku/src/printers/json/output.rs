@@ -22,6 +22,10 @@ pub struct Output {
 
     /// Mapping from line number to indenting level present at that line
     indents: BTreeMap<usize, usize>,
+
+    /// Hints appended to the comments of the first line, one by one - see:
+    /// `Self::take_leading_hints()`
+    leading_hints: Vec<String>,
 }
 
 impl Output {
@@ -35,9 +39,14 @@ impl Output {
             lines: Default::default(),
             comments: Default::default(),
             indents: Default::default(),
+            leading_hints: Default::default(),
         }
     }
 
+    pub fn layout(&self) -> &Layout {
+        &self.fmt.layout
+    }
+
     pub fn write_key_and_separator(&mut self, key: impl ToString) {
         if self.fmt.objects_style.surround_keys_with_quotes {
             self.write_char('"');
@@ -57,6 +66,23 @@ impl Output {
         self.ln();
     }
 
+    /// Finishes a list of elements (e.g. of an array), printing the ellipsis
+    /// (when `more` is true) or the trailing comma (when `trailing` is true),
+    /// depending on `ObjectsStyle`.
+    pub fn write_elements_end(&mut self, more: bool, trailing: bool) {
+        let style = &self.fmt.objects_style;
+
+        if more && style.use_ellipsis {
+            self.write_property_separator_ln();
+            self.write("/* ... */");
+        } else if trailing
+            && style.use_trailing_comma
+            && style.use_comma_as_separator
+        {
+            self.write_char(',');
+        }
+    }
+
     pub fn write(&mut self, str: impl ToString) {
         for ch in str.to_string().chars() {
             self.write_char(ch);
@@ -73,11 +99,15 @@ impl Output {
     }
 
     pub fn writeln_comment(&mut self, comment: impl ToString) {
+        if self.fmt.comments_style.hidden {
+            return;
+        }
+
         let comment = comment.to_string();
 
         for comment in comment.split('\n') {
             let comment = if comment.contains('\t') {
-                comment.replace('\t', &" ".repeat(self.fmt.indent_style.size))
+                comment.replace('\t', &self.fmt.indent_style.render(1))
             } else {
                 comment.to_owned()
             };
@@ -107,6 +137,41 @@ impl Output {
         self.writeln_comment(comment);
     }
 
+    /// Appends given hint (e.g. one of `Messages`) to the current comment,
+    /// separating it from the previous ones with a semicolon.
+    pub fn append_hint(&mut self, hint: impl AsRef<str>) {
+        if self.lines.is_empty() && !self.fmt.comments_style.hidden {
+            self.leading_hints.push(capitalize(hint.as_ref()));
+        }
+
+        self.append_comment(|comment| {
+            if comment.is_empty() {
+                comment.push_str(&capitalize(hint.as_ref()));
+            } else {
+                swrite!(comment, "; {}", hint.as_ref());
+            }
+        });
+    }
+
+    pub fn has_comments(&self) -> bool {
+        !self.comments.is_empty()
+    }
+
+    /// Removes and returns comments associated with the first line.
+    ///
+    /// Useful when the output is going to be embedded after some other text
+    /// (e.g. after an object's key), in which case those comments have to be
+    /// moved above that text.
+    pub fn take_leading_comments(&mut self) -> Vec<String> {
+        self.comments.remove(&0).unwrap_or_default()
+    }
+
+    /// Removes and returns hints (see: `Self::append_hint()`) associated with
+    /// the first line, as separate strings.
+    pub fn take_leading_hints(&mut self) -> Vec<String> {
+        mem::take(&mut self.leading_hints)
+    }
+
     pub fn inc_indent(&mut self) {
         self.indent += 1;
     }
@@ -115,6 +180,33 @@ impl Output {
         self.indent -= 1;
     }
 
+    /// Renders the output as plain lines - with comments placed above their
+    /// lines and tabs used for indentation - so that it can be embedded inside
+    /// a comment.
+    pub fn render_plain(mut self) -> String {
+        if !self.line.is_empty() {
+            self.write_char('\n');
+        }
+
+        let mut result = Vec::new();
+
+        for line in self.lines() {
+            let indent = "\t".repeat(line.level);
+
+            for comment in line.comments {
+                result.push(format!(
+                    "{}{}",
+                    indent,
+                    self.fmt.comments_style.render(comment)
+                ));
+            }
+
+            result.push(format!("{}{}", indent, line.body));
+        }
+
+        result.join("\n")
+    }
+
     pub fn render(mut self) -> String {
         if !self.line.is_empty() {
             self.write_char('\n');
@@ -130,8 +222,8 @@ impl Output {
 
     fn write_char(&mut self, ch: char) {
         match ch {
-            '\t' => {
-                self.write(" ".repeat(self.fmt.indent_style.size));
+            '\t' if !self.fmt.indent_style.use_tabs => {
+                self.write(self.fmt.indent_style.render(1));
             }
 
             '\r' => {
@@ -152,12 +244,21 @@ impl Output {
         }
     }
 
+    /// Returns given comments, wrapped so that - when printed at given column -
+    /// they fit within `CommentsStyle::max_width`.
+    fn fit_comments(&self, comments: &[String], column: usize) -> Vec<String> {
+        self.fmt
+            .comments_style
+            .fit(comments.iter().map(String::as_str), column)
+    }
+
     fn lines(&self) -> impl Iterator<Item = Line<'_>> + '_ {
         debug_assert!(self.line.is_empty());
 
         self.lines.iter().enumerate().map(move |(id, body)| {
-            let indent = self.fmt.indent_style.size
-                * self.indents.get(&id).copied().unwrap_or_default();
+            let level = self.indents.get(&id).copied().unwrap_or_default();
+            let indent = self.fmt.indent_style.size * level;
+            let prefix = self.fmt.indent_style.render(level);
 
             let comments = self
                 .comments
@@ -167,7 +268,9 @@ impl Output {
 
             Line {
                 id,
+                level,
                 indent,
+                prefix,
                 body,
                 comments,
             }
@@ -177,7 +280,16 @@ impl Output {
 
 struct Line<'a> {
     id: usize,
+
+    /// Indenting level (i.e. how deeply nested the line is)
+    level: usize,
+
+    /// Width of the indent, in columns
     indent: usize,
+
+    /// Text of the indent (i.e. spaces or tabs)
+    prefix: String,
+
     body: &'a str,
     comments: &'a [String],
 }
@@ -187,3 +299,12 @@ impl Line<'_> {
         self.indent + self.body.chars().count()
     }
 }
+
+pub(super) fn capitalize(str: &str) -> String {
+    let mut chars = str.chars();
+
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
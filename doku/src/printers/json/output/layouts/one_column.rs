@@ -6,24 +6,24 @@ pub fn render(out: Output) -> String {
     for Line {
         id: line_id,
         indent,
+        prefix,
         body,
         comments,
+        ..
     } in out.lines()
     {
         swrite!(result, if line_id > 0, "\n");
 
-        for comment in comments {
-            swrite!(result, for 0..indent, " ");
+        for comment in out.fit_comments(comments, indent) {
             swrite!(
                 result,
-                "{} {}\n",
-                &out.fmt.comments_style.separator,
-                comment
+                "{}{}\n",
+                prefix,
+                out.fmt.comments_style.render(&comment)
             );
         }
 
-        swrite!(result, for 0..indent, " ");
-        swrite!(result, "{}", body);
+        swrite!(result, "{}{}", prefix, body);
     }
 
     result
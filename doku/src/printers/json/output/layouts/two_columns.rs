@@ -1,47 +1,98 @@
 use super::*;
+use std::collections::BTreeMap;
 
 pub fn render(out: Output, align: bool, spacing: usize) -> String {
     let mut result = String::new();
+    let lines: Vec<_> = out.lines().collect();
+    let style = &out.fmt.columns_style;
 
-    let left_col_max_width =
-        out.lines().map(|line| line.len()).max().unwrap_or(0);
+    let aligned_widths = if align && style.column.is_none() {
+        Some(aligned_widths(&lines, style.align_scope))
+    } else {
+        None
+    };
 
-    for line in out.lines() {
+    for line in &lines {
         swrite!(result, if line.id > 0, "\n");
-        swrite!(result, for 0..line.indent, " ");
-        swrite!(result, "{}", line.body);
+        swrite!(result, "{}{}", line.prefix, line.body);
 
-        if !line.comments.is_empty() {
-            if align {
-                swrite!(result, for line.len()..left_col_max_width, " ");
+        if line.comments.is_empty() {
+            continue;
+        }
+
+        let column = if let Some(column) = style.column {
+            if line.len() + spacing <= column {
+                swrite!(result, for line.len()..column, " ");
+                column
+            } else if let ColumnOverflow::NextLine = style.overflow {
+                swrite!(result, "\n");
+                swrite!(result, for 0..column, " ");
+                column
+            } else {
+                swrite!(result, for 0..spacing, " ");
+                line.len() + spacing
             }
+        } else {
+            let width = aligned_widths
+                .as_ref()
+                .map_or(line.len(), |widths| widths[line.id]);
+
+            swrite!(result, for line.len()..width + spacing, " ");
+            width + spacing
+        };
+
+        let comments = out.fit_comments(line.comments, column);
+
+        for (comment_id, comment) in comments.iter().enumerate() {
+            if comment_id > 0 {
+                swrite!(result, "\n");
+                swrite!(result, for 0..column, " ");
+            }
+
+            swrite!(result, "{}", out.fmt.comments_style.render(comment));
+        }
+    }
 
-            swrite!(result, for 0..spacing, " ");
+    result
+}
+
+/// Returns width of the left column for each of the lines, so that comments of
+/// lines within the same scope end up aligned.
+fn aligned_widths(lines: &[Line<'_>], scope: AlignScope) -> Vec<usize> {
+    // Scope of each line - for `AlignScope::Object`, that's the line's level
+    // together with the line that opens its parent object
+    let mut scopes = Vec::with_capacity(lines.len());
 
-            for (comment_id, comment) in line.comments.iter().enumerate() {
-                if comment_id > 0 {
-                    swrite!(result, "\n");
+    // `parents[level]` is the most recent line at given level
+    let mut parents: Vec<usize> = Vec::new();
+
+    for line in lines {
+        match scope {
+            AlignScope::Document => {
+                scopes.push((0, None));
+            }
 
-                    if align {
-                        swrite!(result, for 0..left_col_max_width, " ");
-                    } else {
-                        swrite!(result, for 0..line.len(), " ");
-                    }
+            AlignScope::Object => {
+                parents.truncate(line.level);
+                scopes.push((line.level, parents.last().copied()));
 
-                    swrite!(result, for 0..spacing, " ");
+                if parents.len() < line.level {
+                    parents.resize(line.level, line.id);
                 }
 
-                swrite!(
-                    result,
-                    "{} {}",
-                    &out.fmt.comments_style.separator,
-                    comment
-                );
+                parents.push(line.id);
             }
         }
     }
 
-    result
+    let mut widths = BTreeMap::new();
+
+    for (line, scope) in lines.iter().zip(&scopes) {
+        let width = widths.entry(*scope).or_insert(0);
+        *width = line.len().max(*width);
+    }
+
+    scopes.iter().map(|scope| widths[scope]).collect()
 }
 
 #[cfg(test)]
@@ -12,20 +12,18 @@ impl Ctxt<'_, '_, '_> {
             return;
         };
 
-        self.out.append_comment(|comment| {
-            if comment.is_empty() {
-                swrite!(comment, "Must");
-            } else {
-                swrite!(comment, "; must");
-            }
+        let messages = &self.fmt.messages;
+
+        let elements = if size == 1 {
+            &messages.element
+        } else {
+            &messages.elements
+        };
 
-            swrite!(
-                comment,
-                " contain exactly {} element{}",
-                size,
-                if size == 1 { "" } else { "s" }
-            );
-        });
+        self.out.append_hint(Messages::fill(
+            &messages.array_size,
+            &[("count", &size), ("elements", elements)],
+        ));
     }
 }
 
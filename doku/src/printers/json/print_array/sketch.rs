@@ -12,24 +12,49 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
         } else if let Some(example) = self.example() {
             let examples: Vec<_> = example.iter().collect();
 
-            for example in &examples {
+            // All the examples get displayed, even if there's more of them than
+            // `elements` - but there's no point in cycling past array's size
+            let mut elements =
+                self.fmt.arrays_style.elements.max(examples.len());
+
+            if let Some(size) = size {
+                elements = elements.min(size.max(examples.len()));
+            }
+
+            for (example_idx, example) in
+                examples.iter().cycle().take(elements).enumerate()
+            {
+                if example_idx > 0 {
+                    self.out.write_property_separator_ln();
+                }
+
                 self.nested()
                     .with_ty(ty)
                     .with_example(Some(*example))
                     .print();
+            }
 
-                self.out.write_property_separator_ln();
+            let more = size.map_or(true, |size| elements < size);
 
-                // TODO if `size.is_some()` and this is the last example, the comma should not be printed
+            self.out.write_elements_end(more, true);
+            self.out.ln();
+        } else {
+            let mut elements = self.fmt.arrays_style.elements.max(1);
+
+            if let Some(size) = size {
+                elements = elements.min(size.max(1));
             }
 
-            if size.map_or(true, |size| examples.len() < size) {
-                self.out.writeln("/* ... */");
+            for element in 0..elements {
+                if element > 0 {
+                    self.out.write_property_separator_ln();
+                }
+
+                self.nested().with_ty(ty).print();
             }
-        } else {
-            self.nested().with_ty(ty).print();
-            self.out.write_property_separator_ln();
-            self.out.writeln("/* ... */");
+
+            self.out.write_elements_end(true, true);
+            self.out.ln();
         }
 
         self.out.dec_indent();
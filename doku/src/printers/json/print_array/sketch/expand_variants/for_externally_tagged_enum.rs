@@ -8,6 +8,7 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
         let variants = if let TypeKind::Enum {
             tag: Tag::External,
             variants,
+            ..
         } = &ty.kind
         {
             variants
@@ -15,27 +16,14 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
             return false;
         };
 
-        let variants: Vec<_> = variants
-            .iter()
-            .filter(|variant| {
-                self.vis
-                    .allows(variant.serializable, variant.deserializable)
-            })
-            .enumerate()
-            .collect();
+        let (variants, _) = self.selectable_variants(variants);
 
-        for (variant_idx, variant) in variants {
+        for (variant_idx, variant) in variants.into_iter().enumerate() {
             if variant_idx > 0 {
                 self.out.write_property_separator_ln();
             }
 
-            let comment = variant.comment.or_else(|| {
-                if variant.id == variant.title {
-                    None
-                } else {
-                    Some(variant.title)
-                }
-            });
+            let comment = self.variant_comment(variant);
 
             match variant.fields {
                 Fields::Named { .. } | Fields::Unnamed { .. } => {
@@ -59,7 +59,7 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
         // As always, this is not really expected to happen in practice, because
         // Serde issues its own error message for this case before us.
         let (tag, variants) =
-            if let TypeKind::Enum { tag, variants } = &tagf.1.ty.kind {
+            if let TypeKind::Enum { tag, variants, .. } = &tagf.1.ty.kind {
                 (*tag, variants)
             } else {
                 panic!(
@@ -0,0 +1,16 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    pub(super) fn print_bytes(&mut self) {
+        self.comment_bytes();
+        self.print_quoted("aGVsbG8gd29ybGQ=", "bytes");
+    }
+
+    fn comment_bytes(&mut self) {
+        if !self.fmt.auto_comments.bytes {
+            return;
+        }
+
+        self.out.append_hint(&self.fmt.messages.bytes);
+    }
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    pub(super) fn print_cfg(&mut self) {
+        if !self.fmt.auto_comments.cfg {
+            return;
+        }
+
+        let cfg = if let Some(cfg) = self.ty.metas.get("cfg") {
+            cfg
+        } else {
+            return;
+        };
+
+        let feature = cfg
+            .strip_prefix("feature")
+            .map(str::trim_start)
+            .and_then(|cfg| cfg.strip_prefix('='))
+            .map(str::trim)
+            .filter(|feature| {
+                feature.len() >= 2
+                    && feature.starts_with('"')
+                    && feature.ends_with('"')
+            });
+
+        let hint = if let Some(feature) = feature {
+            Messages::fill(
+                &self.fmt.messages.cfg_feature,
+                &[("feature", &feature)],
+            )
+        } else {
+            Messages::fill(&self.fmt.messages.cfg, &[("cfg", &cfg)])
+        };
+
+        self.out.append_hint(hint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(cfg: &'static str) -> String {
+        let ty = Type {
+            metas: Metas::default().with("cfg", cfg),
+            ..String::ty()
+        };
+
+        Printer::default().print(&ty)
+    }
+
+    #[test]
+    fn feature() {
+        assert_doc!(
+            r#"
+            // Only with feature "tls"
+            "string"
+            "#,
+            target(r#"feature = "tls""#)
+        );
+    }
+
+    #[test]
+    fn other() {
+        assert_doc!(
+            r#"
+            // Only with cfg(unix)
+            "string"
+            "#,
+            target("unix")
+        );
+
+        assert_doc!(
+            r#"
+            // Only with cfg(features = "tls")
+            "string"
+            "#,
+            target(r#"features = "tls""#)
+        );
+    }
+}
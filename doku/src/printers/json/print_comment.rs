@@ -4,6 +4,8 @@ impl Ctxt<'_, '_, '_> {
     pub(super) fn print_comment(&mut self) {
         if let DocComments::Visible = self.fmt.doc_comments {
             if let Some(comment) = &self.ty.comment {
+                let comment = self.fmt.comments_style.reflow(comment);
+
                 self.out.writeln_comment(comment);
             }
         }
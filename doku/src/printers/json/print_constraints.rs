@@ -0,0 +1,143 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    pub(super) fn print_constraints(&mut self) {
+        for constraint in &self.ty.constraints {
+            match constraint {
+                Constraint::Length { min, max } => {
+                    self.comment_length(*min, *max)
+                }
+                Constraint::Pattern(pattern) => self.comment_pattern(pattern),
+                Constraint::Range { min, max } => {
+                    self.comment_range(*min, *max)
+                }
+                Constraint::MultipleOf(val) => self.comment_multiple_of(val),
+                Constraint::OneOf(vals) => self.comment_one_of(vals),
+            }
+        }
+    }
+
+    fn comment_length(&mut self, min: Option<usize>, max: Option<usize>) {
+        if !self.fmt.auto_comments.length {
+            return;
+        }
+
+        if min.is_none() && max.is_none() {
+            return;
+        }
+
+        let messages = &self.fmt.messages;
+        let noun = |plural| Self::length_noun(messages, self.ty, plural);
+
+        let hint = match (min, max) {
+            (Some(min), Some(max)) if min == max => Messages::fill(
+                &messages.length_exactly,
+                &[("length", &min), ("noun", noun(min != 1))],
+            ),
+
+            (Some(min), Some(max)) => Messages::fill(
+                &messages.length_between,
+                &[("min", &min), ("max", &max), ("noun", noun(true))],
+            ),
+
+            (Some(min), None) => Messages::fill(
+                &messages.length_at_least,
+                &[("min", &min), ("noun", noun(min != 1))],
+            ),
+
+            (None, Some(max)) => Messages::fill(
+                &messages.length_at_most,
+                &[("max", &max), ("noun", noun(max != 1))],
+            ),
+
+            (None, None) => unreachable!(),
+        };
+
+        self.out.append_hint(hint);
+    }
+
+    fn comment_pattern(&mut self, pattern: &str) {
+        if !self.fmt.auto_comments.pattern {
+            return;
+        }
+
+        self.out.append_hint(Messages::fill(
+            &self.fmt.messages.pattern,
+            &[("pattern", &pattern)],
+        ));
+    }
+
+    fn comment_range(&mut self, min: Option<&str>, max: Option<&str>) {
+        if !self.fmt.auto_comments.range {
+            return;
+        }
+
+        if min.is_none() && max.is_none() {
+            return;
+        }
+
+        let messages = &self.fmt.messages;
+
+        let hint = match (min, max) {
+            (Some(min), Some(max)) => Messages::fill(
+                &messages.range_between,
+                &[("min", &min), ("max", &max)],
+            ),
+
+            (Some(min), None) => {
+                Messages::fill(&messages.range_at_least, &[("min", &min)])
+            }
+
+            (None, Some(max)) => {
+                Messages::fill(&messages.range_at_most, &[("max", &max)])
+            }
+
+            (None, None) => unreachable!(),
+        };
+
+        self.out.append_hint(hint);
+    }
+
+    fn comment_multiple_of(&mut self, val: &str) {
+        if !self.fmt.auto_comments.range {
+            return;
+        }
+
+        self.out.append_hint(Messages::fill(
+            &self.fmt.messages.multiple_of,
+            &[("value", &val)],
+        ));
+    }
+
+    fn comment_one_of(&mut self, vals: &[&str]) {
+        if !self.fmt.auto_comments.one_of {
+            return;
+        }
+
+        let vals: Vec<_> =
+            vals.iter().map(|val| format!("{:?}", val)).collect();
+
+        self.out.append_hint(Messages::fill(
+            &self.fmt.messages.one_of,
+            &[("values", &vals.join(", "))],
+        ));
+    }
+
+    /// Returns what the length of given type is measured in - e.g. for
+    /// `Option<String>` that's characters.
+    fn length_noun<'m>(
+        messages: &'m Messages,
+        ty: &Type,
+        plural: bool,
+    ) -> &'m Cow<'static, str> {
+        match (&ty.kind, plural) {
+            (TypeKind::Optional { ty }, _) => {
+                Self::length_noun(messages, ty, plural)
+            }
+            (TypeKind::String, false) => &messages.character,
+            (TypeKind::String, true) => &messages.characters,
+            (_, false) => &messages.element,
+            (_, true) => &messages.elements,
+        }
+    }
+}
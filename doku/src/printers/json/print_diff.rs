@@ -0,0 +1,134 @@
+use super::*;
+
+impl<'ty> Ctxt<'_, 'ty, '_> {
+    /// Marks fields that have been added, removed or changed between
+    /// `old_val` and `val` - see: `Printer::with_old_value()`.
+    pub(super) fn print_changes(&mut self) {
+        if !self.diff || !self.is_field {
+            return;
+        }
+
+        let newtype = is_newtype(self.ty);
+
+        let unwrap = |val: &'ty Value| {
+            if newtype {
+                val.as_struct_unnamed_field(0)
+            } else {
+                Some(val)
+            }
+        };
+
+        let hint = match (self.old_val, self.val) {
+            (None, None) => {
+                return;
+            }
+
+            (None, Some(_)) => {
+                // Everything inside this field has been added as well, so
+                // there's no point in repeating the hint for each of the
+                // nested fields
+                self.old_val = self.val;
+
+                self.fmt.messages.added.to_string()
+            }
+
+            (Some(old), None) => Messages::fill(
+                &self.fmt.messages.removed,
+                &[("value", &Self::value_to_summary(unwrap(old)))],
+            ),
+
+            (Some(old), Some(new)) => {
+                if old == new {
+                    return;
+                }
+
+                // Structs get compared field-by-field
+                if let (Value::Map(_), false) = (new, is_map(self.ty)) {
+                    return;
+                }
+
+                Messages::fill(
+                    &self.fmt.messages.changed,
+                    &[("value", &Self::value_to_summary(unwrap(old)))],
+                )
+            }
+        };
+
+        self.out.append_hint(hint);
+    }
+
+    /// Writes comments listing keys of `val` that don't correspond to any of
+    /// given fields (as separate lines, so that they're indented just like
+    /// the fields) - see: `Printer::with_old_value()`.
+    pub(super) fn print_unknown_keys(
+        &mut self,
+        fields: &'ty [(&'static str, Field)],
+    ) {
+        if !self.diff || self.fmt.comments_style.hidden {
+            return;
+        }
+
+        // Keys of flattened fields are mixed with our own, so there's no
+        // reliable way of telling which ones are unknown
+        if fields.iter().any(|(_, field)| field.flattened) {
+            return;
+        }
+
+        let keys = if let Some(Value::Map(keys)) = self.val {
+            keys
+        } else {
+            return;
+        };
+
+        for (key, _) in keys {
+            let key = if let Value::String(key) = key {
+                key
+            } else {
+                continue;
+            };
+
+            if !fields.iter().any(|(field_name, _)| field_name == key) {
+                let comment = capitalize(&Messages::fill(
+                    &self.fmt.messages.unknown_key,
+                    &[("key", key)],
+                ));
+
+                self.out.writeln(self.fmt.comments_style.render(&comment));
+            }
+        }
+    }
+
+    fn value_to_summary(val: Option<&Value>) -> String {
+        match val {
+            Some(Value::Array(_)) => "[...]".to_string(),
+            Some(Value::Map(_)) => "{...}".to_string(),
+            Some(val) => {
+                Self::value_to_string(val).unwrap_or_else(|| "null".to_string())
+            }
+            None => "null".to_string(),
+        }
+    }
+}
+
+/// Returns whether given type (looking through optionals) is a newtype, whose
+/// values are wrapped in one-element arrays.
+fn is_newtype(ty: &Type) -> bool {
+    match &ty.kind {
+        TypeKind::Optional { ty } => is_newtype(ty),
+        TypeKind::Struct {
+            fields: Fields::Unnamed { fields },
+            transparent: false,
+            ..
+        } => fields.len() == 1,
+        _ => false,
+    }
+}
+
+/// Returns whether given type (looking through optionals) is a map.
+fn is_map(ty: &Type) -> bool {
+    match &ty.kind {
+        TypeKind::Optional { ty } => is_map(ty),
+        TypeKind::Map { .. } => true,
+        _ => false,
+    }
+}
@@ -4,22 +4,351 @@ mod separated;
 use super::*;
 
 impl<'ty> Ctxt<'_, 'ty, '_> {
-    pub(super) fn print_enum(&mut self, tag: Tag, variants: &'ty [Variant]) {
-        let variants: Vec<_> = variants
-            .iter()
-            .filter(|variant| {
-                self.vis
-                    .allows(variant.serializable, variant.deserializable)
+    pub(super) fn print_enum(
+        &mut self,
+        tag: Tag,
+        variants: &'ty [Variant],
+        example_variant: Option<&str>,
+        repr: bool,
+    ) {
+        let (variants, fallback) = self.selectable_variants(variants);
+
+        let example = example_variant
+            .and_then(|title| {
+                variants.iter().find(|variant| variant.title == title)
             })
-            .collect();
+            .or_else(|| variants.first())
+            .copied();
+
+        if repr {
+            self.print_repr_enum(&variants, example);
+            return;
+        }
+
+        if self.try_printing_enum_values(&variants, example, fallback) {
+            return;
+        }
+
+        if tag == Tag::None {
+            if let UntaggedEnumsStyle::Alternatives =
+                self.fmt.untagged_enums_style
+            {
+                self.print_alternative_enum(
+                    Tag::None,
+                    &variants,
+                    example,
+                    fallback,
+                );
+                return;
+            }
+        }
 
         match self.fmt.enums_style {
             EnumsStyle::Commented => {
-                self.print_commented_enum(tag, &variants);
+                self.print_commented_enum(tag, &variants, example, fallback);
+            }
+            EnumsStyle::Expanded => {
+                self.print_alternative_enum(tag, &variants, example, fallback);
             }
             EnumsStyle::Separated => {
+                if let Some(fallback) = fallback {
+                    self.print_enum_fallback(fallback);
+                }
+
                 self.print_separated_enum(tag, &variants);
             }
         }
     }
+
+    /// Prints enum that gets serialized through its variants' discriminants,
+    /// e.g.:
+    ///
+    /// ```json
+    /// // 0 = Debug, 1 = Info
+    /// 0
+    /// ```
+    fn print_repr_enum(
+        &mut self,
+        variants: &[&'ty Variant],
+        example: Option<&'ty Variant>,
+    ) {
+        let legend: Vec<_> = variants
+            .iter()
+            .filter_map(|variant| {
+                variant.discriminant.map(|discriminant| {
+                    format!("{} = {}", discriminant, variant.title)
+                })
+            })
+            .collect();
+
+        if !legend.is_empty() && self.fmt.auto_comments.enum_values {
+            self.out.append_comment(|comment| {
+                if !comment.is_empty() {
+                    swrite!(comment, "; ");
+                }
+
+                swrite!(comment, "{}", legend.join(", "));
+            });
+        }
+
+        // The example variant might not have a discriminant (e.g. when it
+        // refers to a constant), in which case we fall back to the first
+        // variant that has one
+        let value = example
+            .and_then(|variant| variant.discriminant)
+            .or_else(|| {
+                variants.iter().find_map(|variant| variant.discriminant)
+            })
+            .unwrap_or("null");
+
+        self.out.write(value);
+    }
+
+    /// Prints fieldless enum as its example variant, with all the accepted
+    /// values listed in a single-line comment (think
+    /// `#[doku(enum_values_comment)]`):
+    ///
+    /// ```json
+    /// // One of: "Debug", "Info"
+    /// "Debug"
+    /// ```
+    fn try_printing_enum_values(
+        &mut self,
+        variants: &[&'ty Variant],
+        example: Option<&'ty Variant>,
+        fallback: Option<&'ty Variant>,
+    ) -> bool {
+        if !self.ty.enum_values_comment || !self.fmt.auto_comments.enum_values {
+            return false;
+        }
+
+        let example = if let Some(example) = example {
+            example
+        } else {
+            return false;
+        };
+
+        let fieldless = variants
+            .iter()
+            .all(|variant| matches!(variant.fields, Fields::Unit));
+
+        if !fieldless {
+            return false;
+        }
+
+        let values: Vec<_> = variants
+            .iter()
+            .map(|variant| format!(r#""{}""#, variant.id))
+            .collect();
+
+        self.out.append_hint(Messages::fill(
+            &self.fmt.messages.enum_values,
+            &[("values", &values.join(", "))],
+        ));
+
+        if let Some(fallback) = fallback {
+            self.print_enum_fallback(fallback);
+        }
+
+        if let Some(value) = self.first_example() {
+            self.out.write(value);
+        } else {
+            self.out.write(format!(r#""{}""#, example.id));
+        }
+
+        true
+    }
+
+    /// Returns variants that should get printed, with the `#[serde(other)]`
+    /// one (if any) returned separately.
+    ///
+    /// That's because such variant isn't really selectable, so instead of
+    /// listing it among the others, we document it on its own - unless it's
+    /// the only variant there is, in which case there's nothing else to pick
+    /// from.
+    pub(super) fn selectable_variants(
+        &self,
+        variants: &'ty [Variant],
+    ) -> (Vec<&'ty Variant>, Option<&'ty Variant>) {
+        let variants: Vec<_> = variants
+            .iter()
+            .filter(|variant| self.allows_variant(variant))
+            .collect();
+
+        let fallback = variants
+            .iter()
+            .find(|variant| variant.other)
+            .copied()
+            .filter(|_| variants.iter().any(|variant| !variant.other));
+
+        let variants = variants
+            .into_iter()
+            .filter(|variant| fallback.is_none() || !variant.other)
+            .collect();
+
+        (variants, fallback)
+    }
+
+    /// Prints given variant as-is (i.e. with all its fields), as an object
+    /// that includes the variant's tag (if any).
+    pub(super) fn print_variant(&mut self, tag: Tag, variant: &'ty Variant) {
+        match tag {
+            Tag::Adjacent { tag, content } => {
+                self.out.writeln("{");
+                self.out.inc_indent();
+                self.out.write_key_and_separator(tag);
+                self.out.write(format!(r#""{}""#, variant.id));
+                self.out.write_property_separator_ln();
+
+                if let Fields::Named { .. } | Fields::Unnamed { .. } =
+                    variant.fields
+                {
+                    self.out.write_key_and_separator(content);
+                    self.print_fields(&variant.fields, None);
+                    self.out.ln();
+                }
+
+                self.out.dec_indent();
+                self.out.write("}");
+            }
+
+            Tag::Internal { tag } => {
+                if self.flat {
+                    self.print_internally_tagged_variant(tag, variant);
+                } else {
+                    self.out.writeln("{");
+                    self.out.inc_indent();
+                    self.print_internally_tagged_variant(tag, variant);
+                    self.out.ln();
+                    self.out.dec_indent();
+                    self.out.write("}");
+                }
+            }
+
+            Tag::External => match variant.fields {
+                Fields::Named { .. } | Fields::Unnamed { .. } => {
+                    self.out.writeln("{");
+                    self.out.inc_indent();
+                    self.out.write_key_and_separator(variant.id);
+                    self.print_fields(&variant.fields, None);
+                    self.out.ln();
+                    self.out.dec_indent();
+                    self.out.write("}");
+                }
+
+                Fields::Unit => {
+                    self.out.write(format!(r#""{}""#, variant.id));
+                }
+            },
+
+            Tag::None => match variant.fields {
+                Fields::Named { .. } | Fields::Unnamed { .. } => {
+                    self.print_fields(&variant.fields, None);
+                }
+
+                Fields::Unit => {
+                    self.out.write("null");
+                }
+            },
+        }
+    }
+
+    /// Prints internally-tagged variant - that is: its tag and its fields -
+    /// without the surrounding braces, so that it can be either wrapped in an
+    /// object or merged into a parent one (think `#[serde(flatten)]`).
+    fn print_internally_tagged_variant(
+        &mut self,
+        tag: &str,
+        variant: &'ty Variant,
+    ) {
+        self.out.write_key_and_separator(tag);
+        self.out.write(format!(r#""{}""#, variant.id));
+
+        if let Fields::Named { .. } | Fields::Unnamed { .. } = variant.fields {
+            self.out.write_property_separator_ln();
+            self.nested()
+                .with_flat()
+                .print_fields(&variant.fields, None);
+        }
+    }
+
+    /// Returns variant's doc-comment, extended with its aliases, versions and
+    /// its name in the Rust code (if enabled through `AutoComments`).
+    pub(super) fn variant_comment(
+        &self,
+        variant: &Variant,
+    ) -> Option<Cow<'static, str>> {
+        let doc = if let DocComments::Visible = self.fmt.doc_comments {
+            variant.comment
+        } else {
+            None
+        };
+
+        let mut hints = Vec::new();
+
+        if self.fmt.auto_comments.aliases && !variant.aliases.is_empty() {
+            let aliases: Vec<_> = variant
+                .aliases
+                .iter()
+                .map(|alias| format!(r#""{}""#, alias))
+                .collect();
+
+            hints.push(Messages::fill(
+                &self.fmt.messages.aliases,
+                &[("aliases", &aliases.join(", "))],
+            ));
+        }
+
+        if self.fmt.auto_comments.versions {
+            if let Some(since) = variant.since {
+                hints.push(Messages::fill(
+                    &self.fmt.messages.since,
+                    &[("version", &since.trim_start_matches('v'))],
+                ));
+            }
+
+            if let Some(until) = variant.until {
+                hints.push(Messages::fill(
+                    &self.fmt.messages.until,
+                    &[("version", &until.trim_start_matches('v'))],
+                ));
+            }
+        }
+
+        if self.fmt.auto_comments.rust_names && variant.title != variant.id {
+            hints.push(Messages::fill(
+                &self.fmt.messages.rust_name,
+                &[("name", &variant.title)],
+            ));
+        }
+
+        if hints.is_empty() {
+            return doc.map(Cow::Borrowed);
+        }
+
+        let hints = hints.join("; ");
+
+        let comment = match doc {
+            Some(comment) if comment.contains('\n') => {
+                format!("{}\n{}", comment, capitalize(&hints))
+            }
+            Some(comment) => format!("{}; {}", comment, hints),
+            None => capitalize(&hints),
+        };
+
+        Some(Cow::Owned(comment))
+    }
+
+    fn print_enum_fallback(&mut self, variant: &Variant) {
+        if !self.fmt.auto_comments.enum_values {
+            return;
+        }
+
+        let hint = Messages::fill(
+            &self.fmt.messages.enum_fallback,
+            &[("value", &format!(r#""{}""#, variant.id))],
+        );
+
+        self.out.writeln_comment(capitalize(&hint));
+    }
 }
@@ -2,14 +2,76 @@ mod comment;
 mod sketch;
 
 use super::*;
+use std::ptr;
 
-impl Ctxt<'_, '_, '_> {
+impl<'ty> Ctxt<'_, 'ty, '_> {
     pub(super) fn print_commented_enum(
         &mut self,
         tag: Tag,
         variants: &[&Variant],
+        example: Option<&'ty Variant>,
+        fallback: Option<&Variant>,
     ) {
         comment::comment(self, tag, variants);
-        sketch::sketch(self, tag, variants);
+
+        if let Some(fallback) = fallback {
+            self.print_enum_fallback(fallback);
+        }
+
+        // Flattened, internally-tagged enums get merged into the parent
+        // object, so instead of a sketch (which would be an object on its
+        // own), we have to print the actual example variant
+        if let (true, Tag::Internal { tag }, Some(example)) =
+            (self.flat, tag, example)
+        {
+            if self.first_example().is_none() {
+                self.print_internally_tagged_variant(tag, example);
+                return;
+            }
+        }
+
+        sketch::sketch(self, tag, example);
+    }
+
+    /// Prints the example variant as-is, listing all the other variants as
+    /// commented-out alternatives.
+    pub(super) fn print_alternative_enum(
+        &mut self,
+        tag: Tag,
+        variants: &[&'ty Variant],
+        example: Option<&'ty Variant>,
+        fallback: Option<&Variant>,
+    ) {
+        let example = if let Some(example) = example {
+            example
+        } else {
+            self.out.write("null");
+            return;
+        };
+
+        if let Some(comment) = self.variant_comment(example) {
+            self.out.writeln_comment(comment);
+        }
+
+        let alternatives: Vec<_> = variants
+            .iter()
+            .filter(|variant| !ptr::eq(**variant, example))
+            .copied()
+            .collect();
+
+        if !alternatives.is_empty() {
+            comment::alternatives(self, tag, &alternatives);
+        }
+
+        if let Some(fallback) = fallback {
+            self.print_enum_fallback(fallback);
+        }
+
+        if let Some(example) = self.first_example() {
+            self.out.write(example);
+            return;
+        }
+
+        self.print_variant(tag, example);
     }
 }
@@ -18,6 +18,24 @@ pub(super) fn comment(
     }
 }
 
+pub(super) fn alternatives(
+    ctxt: &mut Ctxt<'_, '_, '_>,
+    tag: Tag,
+    variants: &[&Variant],
+) {
+    ctxt.out.append_comment(|comment| {
+        if comment.is_empty() {
+            swrite!(comment, "Alternatively:");
+        } else {
+            swrite!(comment, "; alternatively:");
+        }
+    });
+
+    for variant in variants {
+        comment_variant(ctxt, tag, variant);
+    }
+}
+
 fn comment_variant(ctxt: &mut Ctxt<'_, '_, '_>, tag: Tag, variant: &Variant) {
     let rendered_variant = render_variant(ctxt, tag, variant);
 
@@ -38,13 +56,7 @@ fn comment_variant(ctxt: &mut Ctxt<'_, '_, '_>, tag: Tag, variant: &Variant) {
 
     // ---
 
-    let comment = if let Some(comment) = variant.comment {
-        Some(comment)
-    } else if variant.title != variant.id {
-        Some(variant.title)
-    } else {
-        None
-    };
+    let comment = ctxt.variant_comment(variant);
 
     if let Some(comment) = comment {
         if str.lines().count() == 1 && comment.lines().count() == 1 {
@@ -94,41 +106,43 @@ fn render_variant(
                 )
             }
 
-            fields => format!(
-                "{{\n\t{q}{}{q}: \"{}\"{c}\n\t{q}{}{q}: {}\n}}",
-                tag,
-                variant.id,
-                content,
-                render_variant_fields(ctxt, fields, false, true),
-                c = comma,
-                q = quote
-            ),
+            fields => {
+                let (comments, fields) =
+                    render_variant_fields(ctxt, fields, false, true);
+
+                format!(
+                    "{{\n\t{q}{}{q}: \"{}\"{c}\n{}\t{q}{}{q}: {}\n}}",
+                    tag,
+                    variant.id,
+                    comments,
+                    content,
+                    fields,
+                    c = comma,
+                    q = quote
+                )
+            }
         },
 
         Tag::Internal { tag } => {
-            if let Fields::Named { fields } = &variant.fields {
-                if fields.is_empty() {
-                    format!(
-                        "{{\n\t{q}{}{q}: \"{}\"\n}}",
-                        tag,
-                        variant.id,
-                        q = quote
-                    )
-                } else {
-                    format!(
-                        "{{\n\t{q}{}{q}: \"{}\"{c}\n\t{}\n}}",
-                        tag,
-                        variant.id,
-                        render_variant_fields(
-                            ctxt,
-                            &variant.fields,
-                            true,
-                            true,
-                        ),
-                        c = comma,
-                        q = quote
-                    )
-                }
+            let has_fields = match &variant.fields {
+                Fields::Named { fields } => !fields.is_empty(),
+                Fields::Unnamed { fields } => fields.len() == 1,
+                Fields::Unit => false,
+            };
+
+            if has_fields {
+                let (comments, fields) =
+                    render_variant_fields(ctxt, &variant.fields, true, true);
+
+                format!(
+                    "{{\n\t{q}{}{q}: \"{}\"{c}\n{}\t{}\n}}",
+                    tag,
+                    variant.id,
+                    comments,
+                    fields,
+                    c = comma,
+                    q = quote
+                )
             } else {
                 format!(
                     "{{\n\t{q}{}{q}: \"{}\"\n}}",
@@ -142,24 +156,41 @@ fn render_variant(
         Tag::External => match &variant.fields {
             Fields::Unit => format!("\"{}\"", variant.id),
 
-            fields => format!(
-                "{{\n\t{q}{}{q}: {}\n}}",
-                variant.id,
-                render_variant_fields(ctxt, fields, false, true),
-                q = quote
-            ),
+            fields => {
+                let (comments, fields) =
+                    render_variant_fields(ctxt, fields, false, true);
+
+                format!(
+                    "{{\n{}\t{q}{}{q}: {}\n}}",
+                    comments,
+                    variant.id,
+                    fields,
+                    q = quote
+                )
+            }
         },
 
-        Tag::None => render_variant_fields(ctxt, &variant.fields, false, false),
+        Tag::None => {
+            let (comments, fields) =
+                render_variant_fields(ctxt, &variant.fields, false, false);
+
+            format!("{}{}", comments, fields)
+        }
     }
 }
 
+/// Renders variant's fields, returning a pair of `(comments, fields)`.
+///
+/// Comments associated with the first line (e.g. a doc-comment of newtype
+/// variant's field) are returned separately, since they have to be placed
+/// _above_ the line that contains the rendered fields - otherwise we'd end up
+/// with `"Foo": // comment`.
 fn render_variant_fields(
     ctxt: &Ctxt<'_, '_, '_>,
     fields: &Fields,
     flat: bool,
     indent: bool,
-) -> String {
+) -> (String, String) {
     let fmt = Formatting {
         enums_style: EnumsStyle::Separated,
         layout: Layout::OneColumn,
@@ -179,21 +210,41 @@ fn render_variant_fields(
         ty: &<()>::ty(),
 
         val: Default::default(),
+        partial: Default::default(),
+        old_val: Default::default(),
+        diff: Default::default(),
         vis: ctxt.vis,
+        ver: ctxt.ver,
         fmt: &fmt,
         out: &mut out,
         is_key: Default::default(),
+        is_field: Default::default(),
         parent: Default::default(),
         example: Default::default(),
         flat,
+        definitions: ctxt.definitions,
         depth: Default::default(),
+        level: Default::default(),
+        path: Default::default(),
     };
 
     ctxt.print_fields(fields, None);
 
+    let comments = out
+        .take_leading_comments()
+        .into_iter()
+        .map(|comment| {
+            format!(
+                "{}{}\n",
+                if indent { "\t" } else { "" },
+                fmt.comments_style.render(&comment)
+            )
+        })
+        .collect();
+
     let out = out.render();
 
-    if indent {
+    let fields = if indent {
         out.lines()
             .enumerate()
             .map(|(line_idx, line)| {
@@ -207,5 +258,7 @@ fn render_variant_fields(
             .join("\n")
     } else {
         out
-    }
+    };
+
+    (comments, fields)
 }
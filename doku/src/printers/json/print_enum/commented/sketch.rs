@@ -3,14 +3,14 @@ use super::*;
 pub(super) fn sketch(
     ctxt: &mut Ctxt<'_, '_, '_>,
     tag: Tag,
-    variants: &[&Variant],
+    variant: Option<&Variant>,
 ) {
     if let Some(example) = ctxt.first_example() {
         ctxt.out.write(example);
         return;
     }
 
-    let variant = if let Some(variant) = variants.first() {
+    let variant = if let Some(variant) = variant {
         variant
     } else {
         ctxt.out.write("null");
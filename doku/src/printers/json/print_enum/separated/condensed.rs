@@ -5,7 +5,10 @@ pub(super) fn print(
     tag: Tag,
     variants: &[&Variant],
 ) -> bool {
-    if variants.iter().any(|variant| variant.comment.is_some()) {
+    if variants
+        .iter()
+        .any(|variant| ctxt.variant_comment(variant).is_some())
+    {
         return false;
     }
 
@@ -16,10 +19,17 @@ pub(super) fn print(
         return false;
     }
 
+    // Flattened, internally-tagged enums get their tag merged into the parent
+    // object, so there are no braces to print
+    let braces = !(ctxt.flat && matches!(tag, Tag::Internal { .. }));
+
     match tag {
         Tag::Adjacent { tag, .. } | Tag::Internal { tag } => {
-            ctxt.out.writeln("{");
-            ctxt.out.inc_indent();
+            if braces {
+                ctxt.out.writeln("{");
+                ctxt.out.inc_indent();
+            }
+
             ctxt.out.write_key_and_separator(tag);
         }
 
@@ -42,9 +52,11 @@ pub(super) fn print(
 
     match tag {
         Tag::Adjacent { .. } | Tag::Internal { .. } => {
-            ctxt.out.ln();
-            ctxt.out.dec_indent();
-            ctxt.out.write("}");
+            if braces {
+                ctxt.out.ln();
+                ctxt.out.dec_indent();
+                ctxt.out.write("}");
+            }
         }
 
         Tag::External => {
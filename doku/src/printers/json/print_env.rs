@@ -0,0 +1,20 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    pub(super) fn print_env(&mut self) {
+        if !self.fmt.auto_comments.env {
+            return;
+        }
+
+        let env = if let Some(env) = self.ty.env {
+            env
+        } else {
+            return;
+        };
+
+        self.out.append_hint(Messages::fill(
+            &self.fmt.messages.env,
+            &[("env", &env)],
+        ));
+    }
+}
@@ -14,6 +14,17 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
             return;
         }
 
+        self.print_opaque_fields(fields, variant);
+    }
+
+    /// Like [`Self::print_fields()`], but doesn't unwrap newtypes - i.e. a
+    /// struct with a single unnamed field gets printed as a one-element tuple
+    /// (think `#[doku(opaque)]`).
+    pub(super) fn print_opaque_fields(
+        &mut self,
+        fields: &'ty Fields,
+        variant: Option<&'ty Variant>,
+    ) {
         match fields {
             Fields::Named { fields } => {
                 self.print_named_fields(&fields, variant);
@@ -3,17 +3,42 @@ use super::*;
 impl<'ty> Ctxt<'_, 'ty, '_> {
     pub(super) fn print_named_fields(
         &mut self,
-        fields: &'ty [(&'static str, Field)],
+        all_fields: &'ty [(&'static str, Field)],
         variant: Option<&'ty Variant>,
     ) {
-        let fields: Vec<_> = fields
+        let mut fields: Vec<_> = all_fields
             .iter()
-            .filter(|(_, field)| {
-                self.vis
-                    .allows(field.ty.serializable, field.ty.deserializable)
-            })
+            .filter(|(_, field)| self.allows(&field.ty))
             .collect();
 
+        // Fields without any section go first, followed by the sections in the
+        // order of their first appearance
+        let mut sections = vec![None];
+
+        for (_, field) in &fields {
+            if !sections.contains(&field.section) {
+                sections.push(field.section);
+            }
+        }
+
+        let alphabetical =
+            matches!(self.fmt.sort_fields, SortFields::Alphabetical);
+
+        fields.sort_by_key(|(field_name, field)| {
+            let section = sections
+                .iter()
+                .position(|section| *section == field.section);
+
+            (
+                section,
+                if alphabetical {
+                    Some(*field_name)
+                } else {
+                    None
+                },
+            )
+        });
+
         if !self.flat {
             if fields.is_empty() {
                 self.out.write("{}");
@@ -24,32 +49,210 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
             self.out.inc_indent();
         }
 
+        let commented_out: Vec<_> = fields
+            .iter()
+            .map(|(field_name, field)| self.is_commented_out(field_name, field))
+            .collect();
+
+        let last_printed_field_id = commented_out
+            .iter()
+            .rposition(|commented_out| !commented_out);
+
+        let mut section = None;
+        let mut needs_ln = false;
+
         for (field_id, (field_name, field)) in fields.into_iter().enumerate() {
-            self.print_named_field(field_id, field_name, field, variant);
+            let mut starts_section = false;
+
+            if field.section != section {
+                section = field.section;
+
+                if let Some(section) = section {
+                    if needs_ln {
+                        self.out.ln();
+                        needs_ln = false;
+                    }
+
+                    self.print_section(field_id, section);
+                    starts_section = true;
+                }
+            }
+
+            if field_id > 0 && !starts_section && self.needs_blank_line(field) {
+                if needs_ln {
+                    self.out.ln();
+                    needs_ln = false;
+                }
+
+                self.out.ln();
+            }
+
+            if commented_out[field_id] {
+                if needs_ln {
+                    self.out.ln();
+                    needs_ln = false;
+                }
+
+                self.print_commented_out_field(field_name, field);
+                continue;
+            }
+
+            self.print_named_field(field_name, field, variant);
+
+            if Some(field_id) < last_printed_field_id {
+                self.out.write_property_separator_ln();
+            } else {
+                needs_ln = true;
+            }
         }
 
         if !self.flat {
-            self.out.ln();
+            if needs_ln {
+                self.out.ln();
+            }
+
+            self.print_unknown_keys(all_fields);
             self.out.dec_indent();
             self.out.write("}");
         }
     }
 
-    fn print_named_field(
+    fn print_section(&mut self, field_id: usize, section: &str) {
+        if field_id > 0 {
+            self.out.ln();
+        }
+
+        self.out.writeln_comment(format!("-- {} --", section));
+    }
+
+    /// Returns whether there should be a blank line before given field - see:
+    /// `Formatting::blank_lines`.
+    fn needs_blank_line(&self, field: &Field) -> bool {
+        match self.fmt.blank_lines {
+            BlankLines::Never => false,
+            BlankLines::BeforeCommentedFields => {
+                field.ty.comment.is_some()
+                    && matches!(self.fmt.doc_comments, DocComments::Visible)
+                    && !self.fmt.comments_style.hidden
+            }
+            BlankLines::BetweenFields => true,
+        }
+    }
+
+    /// Returns whether given field should get printed as a comment - see:
+    /// `OptionalsStyle::CommentedOut`.
+    fn is_commented_out(&self, field_name: &'ty str, field: &Field) -> bool {
+        // Flattened structs leave the last line for their parent to finish,
+        // so there's no good place to put the comment in there
+        if self.flat || field.flattened || field.ty.tag.is_some() {
+            return false;
+        }
+
+        let field_val = self
+            .val
+            .and_then(|val| val.as_struct_named_field(field_name));
+
+        match field.ty.optionality() {
+            Optionality::Required => {
+                return false;
+            }
+
+            // Values that are actually present (e.g. coming from
+            // `Printer::with_values()`) are printed as they are
+            Optionality::Optional => {
+                if let Some(field_val) = field_val {
+                    if !matches!(field_val, Value::None) {
+                        return false;
+                    }
+                }
+            }
+
+            // Omitting such field is equivalent to providing its default
+            // value, so the value (if present) gets printed inside the comment
+            Optionality::Defaulted => (),
+        }
+
+        let mut path = self.path.clone();
+
+        path.push(field_name);
+
+        matches!(
+            self.fmt_of(&field.ty, Some(&path)).optionals_style,
+            OptionalsStyle::CommentedOut
+        )
+    }
+
+    fn print_commented_out_field(
         &mut self,
-        field_id: usize,
-        field_name: &str,
+        field_name: &'ty str,
         field: &'ty Field,
-        variant: Option<&'ty Variant>,
     ) {
-        if field_id > 0 {
-            self.out.write_property_separator_ln();
+        if self.fmt.comments_style.hidden {
+            return;
+        }
+
+        let fmt = Formatting {
+            layout: Layout::OneColumn,
+            ..self.fmt.clone()
+        };
+
+        let mut out = Output::new(&fmt);
+
+        {
+            let mut ctxt = self.detached(&fmt, &mut out);
+
+            let field_val = ctxt
+                .val
+                .and_then(|val| val.as_struct_named_field(field_name));
+
+            let old_field_val = ctxt
+                .old_val
+                .and_then(|val| val.as_struct_named_field(field_name));
+
+            ctxt.out.write_key_and_separator(ctxt.field_key(field_name));
+            ctxt.nested()
+                .with_ty(&field.ty)
+                .with_val(field_val)
+                .with_old_val(old_field_val)
+                .with_field_name(field_name)
+                .set_is_field()
+                .inside_object()
+                .print();
+        }
+
+        for comment in out.take_leading_comments() {
+            self.out.writeln_comment(comment);
+        }
+
+        for line in out.render_plain().split('\n') {
+            self.out.writeln(self.fmt.comments_style.render(line));
+        }
+    }
+
+    /// Returns field's name as it should be displayed; see:
+    /// `Formatting::rename_all`.
+    fn field_key(&self, field_name: &'ty str) -> Cow<'ty, str> {
+        if let Some(rule) = self.fmt.rename_all {
+            Cow::Owned(rule.apply(field_name))
+        } else {
+            Cow::Borrowed(field_name)
         }
+    }
 
+    fn print_named_field(
+        &mut self,
+        field_name: &'ty str,
+        field: &'ty Field,
+        variant: Option<&'ty Variant>,
+    ) {
         let field_val = self
             .val
             .and_then(|val| val.as_struct_named_field(field_name));
 
+        let old_field_val = self
+            .old_val
+            .and_then(|val| val.as_struct_named_field(field_name));
+
         if let Some(tag) = field.ty.tag {
             let variant = variant.expect(
                 "Invalid internal state: At this point we should be expanding \
@@ -59,21 +262,33 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
             self.out.write_key_and_separator(tag);
             self.out.write(format!(r#""{}""#, variant.id));
             self.out.write_property_separator_ln();
-            self.out.write_key_and_separator(field_name);
+            self.out.write_key_and_separator(self.field_key(field_name));
             self.print_fields(&variant.fields, None);
 
             return;
         }
 
         if field.flattened {
+            // Flattened fields are serialized straight into their parent
+            // object, so that's where their values are
+            let (val, old_val) = (self.val, self.old_val);
+
             self.nested()
                 .with_ty(&field.ty)
-                .with_val(field_val)
+                .with_val(val)
+                .with_old_val(old_val)
                 .with_flat()
                 .print();
         } else {
-            self.out.write_key_and_separator(field_name);
-            self.nested().with_ty(&field.ty).with_val(field_val).print();
+            self.out.write_key_and_separator(self.field_key(field_name));
+            self.nested()
+                .with_ty(&field.ty)
+                .with_val(field_val)
+                .with_old_val(old_field_val)
+                .with_field_name(field_name)
+                .set_is_field()
+                .inside_object()
+                .print();
         }
     }
 }
@@ -18,13 +18,28 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
         };
 
         let field_val = self.val.and_then(|val| val.as_struct_unnamed_field(0));
+
+        let old_field_val =
+            self.old_val.and_then(|val| val.as_struct_unnamed_field(0));
         let field_example = self.example();
 
-        self.nested()
+        // Newtypes are serialized just like their inner types, so if we're
+        // supposed to print flat, the inner type has to be printed flat too
+        // (think of an internally-tagged enum with `Foo(SomeStruct)` variant)
+        let flat = self.flat;
+
+        let mut ctxt = self
+            .nested()
             .with_ty(&field.ty)
             .with_val(field_val)
-            .with_example(field_example)
-            .print();
+            .with_old_val(old_field_val)
+            .with_example(field_example);
+
+        if flat {
+            ctxt = ctxt.with_flat();
+        }
+
+        ctxt.print();
 
         true
     }
@@ -4,29 +4,53 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
     pub(super) fn print_unnamed_fields(&mut self, fields: &'ty [Field]) {
         let fields: Vec<_> = fields
             .iter()
-            .filter(|field| {
-                self.vis
-                    .allows(field.ty.serializable, field.ty.deserializable)
-            })
+            .filter(|field| self.allows(&field.ty))
             .collect();
 
-        if !self.flat {
+        // Comments are attached to lines, so if any of the fields is commented,
+        // we have to print each field in a separate line - otherwise all of the
+        // comments would get squashed together above the entire array.
+        let multiline = !self.flat
+            && !fields.is_empty()
+            && matches!(self.fmt.doc_comments, DocComments::Visible)
+            && fields.iter().any(|field| field.ty.comment.is_some());
+
+        if multiline {
+            self.out.writeln("[");
+            self.out.inc_indent();
+        } else if !self.flat {
             self.out.write("[");
         }
 
         for (field_id, field) in fields.iter().enumerate() {
             if field_id > 0 {
-                self.out.write(", ");
+                if multiline {
+                    self.out.write_property_separator_ln();
+                } else {
+                    self.out.write(", ");
+                }
             }
 
             let field_val = self
                 .val
                 .and_then(|val| val.as_struct_unnamed_field(field_id));
 
-            self.nested().with_ty(&field.ty).with_val(field_val).print();
+            let old_field_val = self
+                .old_val
+                .and_then(|val| val.as_struct_unnamed_field(field_id));
+
+            self.nested()
+                .with_ty(&field.ty)
+                .with_val(field_val)
+                .with_old_val(old_field_val)
+                .print();
         }
 
-        if !self.flat {
+        if multiline {
+            self.out.ln();
+            self.out.dec_indent();
+            self.out.write("]");
+        } else if !self.flat {
             self.out.write("]");
         }
     }
@@ -0,0 +1,71 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    pub(super) fn print_any(&mut self) {
+        self.comment_format(&self.fmt.messages.any_json);
+        self.print_unquoted("{ /* arbitrary JSON */ }", "any");
+    }
+
+    pub(super) fn print_decimal(&mut self, digits: Option<u8>) {
+        if let Some(digits) = digits {
+            self.comment_format(&Messages::fill(
+                &self.fmt.messages.decimal_digits,
+                &[("digits", &digits)],
+            ));
+        } else {
+            self.comment_format(&self.fmt.messages.decimal);
+        }
+
+        self.print_quoted("123.45", "decimal");
+    }
+
+    pub(super) fn print_ip_addr(&mut self, version: Option<u8>) {
+        let messages = &self.fmt.messages;
+
+        let (hint, placeholder) = match version {
+            Some(4) => (&messages.ipv4_addr, "127.0.0.1"),
+            Some(6) => (&messages.ipv6_addr, "::1"),
+            _ => (&messages.ip_addr, "127.0.0.1"),
+        };
+
+        self.comment_format(hint);
+        self.print_quoted(placeholder, "ip-address");
+    }
+
+    pub(super) fn print_socket_addr(&mut self, version: Option<u8>) {
+        let messages = &self.fmt.messages;
+
+        let (hint, placeholder) = match version {
+            Some(4) => (&messages.socket_addr_v4, "127.0.0.1:8080"),
+            Some(6) => (&messages.socket_addr_v6, "[::1]:8080"),
+            _ => (&messages.socket_addr, "127.0.0.1:8080"),
+        };
+
+        self.comment_format(hint);
+        self.print_quoted(placeholder, "socket-address");
+    }
+
+    pub(super) fn print_path(&mut self) {
+        self.comment_format(&self.fmt.messages.path);
+        self.print_quoted("/path/to/file", "path");
+    }
+
+    pub(super) fn print_url(&mut self) {
+        self.comment_format(&self.fmt.messages.url);
+        self.print_quoted("https://example.com", "url");
+    }
+
+    pub(super) fn print_uuid(&mut self) {
+        self.comment_format(&self.fmt.messages.uuid);
+        self.print_quoted("00000000-0000-0000-0000-000000000000", "uuid");
+    }
+
+    /// Prints a hint about the format of the value, e.g. `IPv4 address`.
+    pub(super) fn comment_format(&mut self, hint: &str) {
+        if !self.fmt.auto_comments.formats {
+            return;
+        }
+
+        self.out.append_hint(hint);
+    }
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+impl<'ty> Ctxt<'_, 'ty, '_> {
+    /// Prints an object (or a tuple) using given function, squashing it into a
+    /// single line if it fits within `Formatting::auto_inline_under`; returns
+    /// whether the object got printed.
+    pub(super) fn print_inlined(
+        &mut self,
+        print: impl for<'a, 'b> FnOnce(&mut Ctxt<'a, 'ty, 'b>),
+    ) -> bool {
+        let max_width = if let Some(max_width) = self.fmt.auto_inline_under {
+            max_width
+        } else {
+            return false;
+        };
+
+        // Flattened structs are a part of their parent object; and without
+        // commas there would be nothing separating the inlined fields
+        if self.flat || !self.fmt.objects_style.use_comma_as_separator {
+            return false;
+        }
+
+        // Nested objects get squashed together with this one, so there's no
+        // point in trying to inline them separately
+        let fmt = Formatting {
+            auto_inline_under: None,
+            layout: Layout::OneColumn,
+            ..self.fmt.clone()
+        };
+
+        let mut out = Output::new(&fmt);
+
+        print(&mut self.detached(&fmt, &mut out));
+
+        // Comments can't be squashed into a single line
+        if out.has_comments() {
+            return false;
+        }
+
+        let mut inlined = String::new();
+
+        for line in out.render_plain().split('\n') {
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            // Commented-out fields (see: `OptionalsStyle::CommentedOut`)
+            if line.starts_with(&fmt.comments_style.separator) {
+                return false;
+            }
+
+            let needs_space = !matches!(
+                (inlined.chars().last(), line.chars().next()),
+                (None, _) | (Some('['), _) | (_, Some(']'))
+            );
+
+            if needs_space {
+                inlined.push(' ');
+            }
+
+            inlined.push_str(line);
+        }
+
+        if inlined.chars().count() > max_width {
+            return false;
+        }
+
+        self.out.write(inlined);
+        true
+    }
+}
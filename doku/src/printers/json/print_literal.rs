@@ -0,0 +1,40 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    /// Prints a literal example verbatim.
+    ///
+    /// Multi-line literals usually come from indented raw strings, so - to keep
+    /// the document aligned - we strip the surrounding empty lines and the
+    /// indentation shared by all of the lines.
+    pub(super) fn print_literal(&mut self, literal: &str) {
+        let lines: Vec<_> = literal
+            .lines()
+            .skip_while(|line| line.trim().is_empty())
+            .collect();
+
+        let lines = {
+            let len = lines
+                .iter()
+                .rposition(|line| !line.trim().is_empty())
+                .map_or(0, |idx| idx + 1);
+
+            &lines[..len]
+        };
+
+        let indent = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or_default();
+
+        for (line_id, line) in lines.iter().enumerate() {
+            if line_id > 0 {
+                self.out.ln();
+            }
+
+            self.out
+                .write(line.get(indent..).unwrap_or_default().trim_end());
+        }
+    }
+}
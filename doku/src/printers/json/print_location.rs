@@ -0,0 +1,32 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    pub(super) fn print_location(&mut self) {
+        if !self.fmt.auto_comments.location {
+            return;
+        }
+
+        let location = if let Some(location) = self.ty.location {
+            location
+        } else {
+            return;
+        };
+
+        // Newtypes get unwrapped into their inner fields, in which case only
+        // the outer field's location is relevant
+        if let Some(TypeKind::Struct {
+            fields: Fields::Unnamed { fields },
+            ..
+        }) = self.parent.map(|parent| &parent.kind)
+        {
+            if fields.len() == 1 {
+                return;
+            }
+        }
+
+        self.out.append_hint(Messages::fill(
+            &self.fmt.messages.location,
+            &[("location", &location)],
+        ));
+    }
+}
@@ -10,11 +10,36 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
         if let Some(example) = self.first_example() {
             self.out.write(example);
         } else {
-            self.nested().with_ty(key).set_is_key().print();
-            self.out.write(": ");
-            self.nested().with_ty(value).print();
-            self.out.write_property_separator_ln();
-            self.out.write("/* ... */");
+            let mut key_examples: Vec<_> =
+                (0..self.fmt.maps_style.entries.max(1))
+                    .map(|entry| {
+                        key.example
+                            .and_then(|example| example.iter().nth(entry))
+                    })
+                    .collect();
+
+            if let SortFields::Alphabetical = self.fmt.sort_fields {
+                key_examples
+                    .sort_by_key(|example| example.and_then(Example::first));
+            }
+
+            for (entry, key_example) in key_examples.into_iter().enumerate() {
+                if entry > 0 {
+                    self.out.write_property_separator_ln();
+                }
+
+                self.nested()
+                    .with_ty(key)
+                    .with_example(key_example)
+                    .set_is_key()
+                    .print();
+
+                self.out.write(": ");
+                self.nested().with_ty(value).inside_object().print();
+            }
+
+            // When flattened, the separator (if any) is printed by the parent
+            self.out.write_elements_end(true, !self.flat);
         }
 
         if !self.flat {
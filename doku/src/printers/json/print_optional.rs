@@ -3,7 +3,32 @@ use super::*;
 impl<'ty> Ctxt<'_, 'ty, '_> {
     pub(super) fn print_optional(&mut self, ty: &'ty Type) {
         self.comment_optional();
-        self.sketch_optional(ty);
+
+        if self.prints_as_null() {
+            self.sketch_null(ty);
+        } else {
+            self.sketch_optional(ty);
+        }
+    }
+
+    fn prints_as_null(&self) -> bool {
+        // Values that are actually present (e.g. coming from
+        // `Printer::with_values()`) are printed as they are
+        if let Some(val) = self.val {
+            if !matches!(val, Value::None) {
+                return false;
+            }
+        }
+
+        match self.fmt.optionals_style {
+            OptionalsStyle::Value => false,
+            OptionalsStyle::Null => true,
+
+            // Optional fields get commented-out by `print_named_fields()`, so
+            // if we've got here as a field, we're rendering the commented-out
+            // version
+            OptionalsStyle::CommentedOut => !self.is_field,
+        }
     }
 
     fn comment_optional(&mut self) {
@@ -20,13 +45,57 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
             return;
         }
 
-        self.out.append_comment(|comment| {
-            if comment.is_empty() {
-                swrite!(comment, "Optional");
-            } else {
-                swrite!(comment, "; optional");
+        self.out.append_hint(&self.fmt.messages.optional);
+    }
+
+    /// Prints hints for fields that can be omitted thanks to a default value
+    /// (e.g. `#[serde(default)]`) and for fields that are required; see also:
+    /// `Type::optionality()`.
+    pub(super) fn print_optionality(&mut self) {
+        if !self.is_field {
+            return;
+        }
+
+        match self.ty.optionality() {
+            Optionality::Required => {
+                if !self.fmt.auto_comments.required {
+                    return;
+                }
+
+                self.out.append_hint(&self.fmt.messages.required);
             }
-        });
+
+            Optionality::Defaulted => {
+                if !self.fmt.auto_comments.defaults {
+                    return;
+                }
+
+                // When values are printed as comments, the default value is
+                // going to be printed anyway
+                let default = if let ValuesStyle::Field = self.fmt.values_style
+                {
+                    self.val.and_then(Self::value_to_string)
+                } else {
+                    None
+                };
+
+                let messages = &self.fmt.messages;
+
+                let hint = if let Some(default) = default {
+                    Messages::fill(
+                        &messages.defaults_to,
+                        &[("value", &default)],
+                    )
+                } else {
+                    messages.has_default.to_string()
+                };
+
+                self.out.append_hint(hint);
+            }
+
+            // Handled by `print_optional()`
+            Optionality::Optional => (),
+        }
     }
 
     fn sketch_optional(&mut self, ty: &'ty Type) {
@@ -34,6 +103,51 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
 
         self.nested().with_ty(ty).with_example(example).print();
     }
+
+    fn sketch_null(&mut self, ty: &'ty Type) {
+        let fmt = Formatting {
+            layout: Layout::OneColumn,
+            optionals_style: OptionalsStyle::Value,
+            ..self.fmt.clone()
+        };
+
+        let mut out = Output::new(&fmt);
+        let example = self.example();
+
+        self.detached(&fmt, &mut out)
+            .with_ty(ty)
+            .with_example(example)
+            .print();
+
+        let comments = out.take_leading_comments();
+        let shape = out.render_plain();
+
+        if comments.is_empty() && !shape.contains('\n') {
+            self.out.append_comment(|comment| {
+                if comment.is_empty() {
+                    swrite!(comment, "E.g. {}", shape);
+                } else {
+                    swrite!(comment, "; e.g. {}", shape);
+                }
+            });
+        } else {
+            self.out.append_comment(|comment| {
+                if comment.is_empty() {
+                    swrite!(comment, "E.g.:");
+                } else {
+                    swrite!(comment, "; e.g.:");
+                }
+            });
+
+            for comment in comments {
+                self.out.writeln_comment(comment);
+            }
+
+            self.out.writeln_comment(shape);
+        }
+
+        self.out.write("null");
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,106 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    /// Prints a reference (e.g. `<Database>`) in place of a type that's not
+    /// supposed to be inlined, registering that type so that its definition
+    /// can be printed later; returns whether the reference has been printed.
+    pub(super) fn print_reference(&mut self) -> bool {
+        // Root types (including the definitions themselves) and flattened
+        // types have to be printed in place
+        if self.ty.inline || self.flat || self.parent.is_none() {
+            return false;
+        }
+
+        let name = if let Some(name) = self.ty.name {
+            name
+        } else {
+            return false;
+        };
+
+        let title = type_title(name);
+
+        self.out.append_comment(|comment| {
+            if comment.is_empty() {
+                swrite!(comment, "See section \"{}\"", title);
+            } else {
+                swrite!(comment, "; see section \"{}\"", title);
+            }
+        });
+
+        self.out.write(format!("<{}>", title));
+
+        let mut definitions = self.definitions.borrow_mut();
+
+        if !definitions.iter().any(|def| def.name == Some(name)) {
+            definitions.push(self.ty.clone());
+        }
+
+        true
+    }
+}
+
+impl Ctxt<'_, '_, '_> {
+    /// Prints a reference to a recursive type (e.g. `<Node>`), registering
+    /// that type so that its definition can be printed later - unless it's
+    /// the root type, which is already printed as the document itself.
+    pub(super) fn print_recursive_reference(
+        &mut self,
+        name: &'static str,
+        ty: fn() -> Type,
+    ) {
+        let title = type_title(name);
+        let mut definitions = self.definitions.borrow_mut();
+        let is_root = definitions[0].name == Some(name);
+
+        self.out.append_comment(|comment| {
+            if is_root {
+                if comment.is_empty() {
+                    swrite!(comment, "Recursive reference to \"{}\"", title);
+                } else {
+                    swrite!(comment, "; recursive reference to \"{}\"", title);
+                }
+            } else if comment.is_empty() {
+                swrite!(comment, "See section \"{}\"", title);
+            } else {
+                swrite!(comment, "; see section \"{}\"", title);
+            }
+        });
+
+        self.out.write(format!("<{}>", title));
+
+        if !definitions.iter().any(|def| def.name == Some(name)) {
+            definitions.push(ty());
+        }
+    }
+}
+
+/// Converts a fully-qualified type name into a human-readable one, e.g.:
+///
+/// ```text
+/// my_crate::config::Wrapper<my_crate::config::Database>
+/// ```
+///
+/// ... becomes:
+///
+/// ```text
+/// Wrapper<Database>
+/// ```
+pub(super) fn type_title(name: &str) -> String {
+    let mut title = String::new();
+    let mut segment = String::new();
+
+    for ch in name.chars() {
+        if ch.is_alphanumeric() || ch == '_' {
+            segment.push(ch);
+        } else if ch == ':' {
+            segment.clear();
+        } else {
+            title.push_str(&segment);
+            title.push(ch);
+            segment.clear();
+        }
+    }
+
+    title.push_str(&segment);
+    title
+}
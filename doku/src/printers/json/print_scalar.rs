@@ -2,19 +2,108 @@ use super::*;
 
 impl Ctxt<'_, '_, '_> {
     pub(super) fn print_bool(&mut self) {
-        self.print_scalar(self.first_example().unwrap_or("true"));
+        self.print_unquoted("true", "bool");
     }
 
-    pub(super) fn print_float(&mut self) {
-        self.print_scalar(self.first_example().unwrap_or("123.45"));
+    pub(super) fn print_float(&mut self, bits: u8) {
+        self.comment_float_precision(bits);
+        self.print_unquoted("123.45", "float");
     }
 
-    pub(super) fn print_integer(&mut self) {
-        self.print_scalar(self.first_example().unwrap_or("123"));
+    fn comment_float_precision(&mut self, bits: u8) {
+        if !self.fmt.auto_comments.float_precision {
+            return;
+        }
+
+        let hint = match bits {
+            32 => &self.fmt.messages.float32,
+            64 => &self.fmt.messages.float64,
+            _ => return,
+        };
+
+        self.out.append_hint(hint);
+    }
+
+    pub(super) fn print_integer(&mut self, bits: u8, signed: bool) {
+        self.comment_integer_range(bits, signed);
+        self.print_unquoted("123", "integer");
+    }
+
+    fn comment_integer_range(&mut self, bits: u8, signed: bool) {
+        if !self.fmt.auto_comments.integer_range || bits == 0 {
+            return;
+        }
+
+        let (min, max) = if signed {
+            if bits >= 128 {
+                (i128::MIN.to_string(), i128::MAX.to_string())
+            } else {
+                let max = (1i128 << (bits - 1)) - 1;
+                ((-max - 1).to_string(), max.to_string())
+            }
+        } else if bits >= 128 {
+            ("0".to_string(), u128::MAX.to_string())
+        } else {
+            ("0".to_string(), ((1u128 << bits) - 1).to_string())
+        };
+
+        let message = if signed {
+            &self.fmt.messages.signed_integer
+        } else {
+            &self.fmt.messages.unsigned_integer
+        };
+
+        self.out.append_hint(Messages::fill(
+            message,
+            &[("bits", &bits), ("min", &min), ("max", &max)],
+        ));
     }
 
     pub(super) fn print_string(&mut self) {
-        let first_example = self.first_example().unwrap_or("string");
+        self.print_quoted("string", "string");
+    }
+
+    pub(super) fn print_date(&mut self) {
+        self.comment_format(&self.fmt.messages.date);
+        self.print_quoted("2023-01-01", "date");
+    }
+
+    pub(super) fn print_datetime(&mut self, offset: bool) {
+        if offset {
+            self.comment_format(&self.fmt.messages.date_time);
+            self.print_quoted("2023-01-01T00:00:00Z", "datetime");
+        } else {
+            self.comment_format(&self.fmt.messages.naive_date_time);
+            self.print_quoted("2023-01-01T00:00:00", "datetime");
+        }
+    }
+
+    pub(super) fn print_duration(&mut self) {
+        match self.fmt.durations_style {
+            DurationsStyle::Humantime => {
+                self.print_quoted("30s", "duration");
+            }
+
+            DurationsStyle::Seconds => {
+                self.comment_format(&self.fmt.messages.duration_seconds);
+                self.print_unquoted("30", "duration");
+            }
+        }
+    }
+
+    pub(super) fn print_time(&mut self) {
+        self.comment_format(&self.fmt.messages.time);
+        self.print_quoted("12:00:00", "time");
+    }
+
+    /// Prints a value that's surrounded with quotes (e.g. a string); `default`
+    /// and `kind` are used to build the placeholder, if there are no examples -
+    /// see: `Formatting::placeholders`.
+    pub(super) fn print_quoted(&mut self, default: &str, kind: &str) {
+        let default = self.placeholder(default, kind);
+        let first_example = self.first_example_text();
+        let first_example = first_example.as_deref().unwrap_or(&default);
+
         if !self.is_key || self.fmt.objects_style.surround_keys_with_quotes {
             self.print_scalar(&format!("\"{}\"", first_example));
         } else {
@@ -22,6 +111,31 @@ impl Ctxt<'_, '_, '_> {
         }
     }
 
+    /// Prints a value that's not surrounded with quotes (e.g. a number),
+    /// unless its example is an `Example::String`.
+    pub(super) fn print_unquoted(&mut self, default: &str, kind: &str) {
+        let default = self.placeholder(default, kind);
+        let first_example = self.first_example();
+        self.print_scalar(first_example.as_deref().unwrap_or(&default));
+    }
+
+    fn placeholder<'a>(&self, default: &'a str, kind: &str) -> Cow<'a, str> {
+        let name = match self.fmt.placeholders {
+            Placeholders::Examples => return Cow::Borrowed(default),
+            Placeholders::TypeNames => kind,
+            Placeholders::FieldNames => {
+                // Keys are named after their maps, so they'd be misleading
+                if self.is_key {
+                    kind
+                } else {
+                    self.path.last().copied().unwrap_or(kind)
+                }
+            }
+        };
+
+        Cow::Owned(format!("<{}>", name))
+    }
+
     fn print_scalar(&mut self, val: &str) {
         self.comment_scalar();
         self.sketch_scalar(val);
@@ -0,0 +1,18 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    /// Prints a redacted placeholder in place of a sensitive value, ignoring
+    /// both its examples and its actual value (if any).
+    pub(super) fn print_sensitive(&mut self) {
+        self.comment_sensitive();
+        self.out.write(r#""<secret>""#);
+    }
+
+    fn comment_sensitive(&mut self) {
+        if !self.fmt.auto_comments.sensitive {
+            return;
+        }
+
+        self.out.append_hint(&self.fmt.messages.sensitive);
+    }
+}
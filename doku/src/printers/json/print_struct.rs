@@ -5,13 +5,72 @@ impl<'ty> Ctxt<'_, 'ty, '_> {
         &mut self,
         fields: &'ty Fields,
         transparent: bool,
+        deny_unknown_fields: bool,
+        opaque: bool,
         variant: Option<&'ty Variant>,
     ) {
         if transparent {
             self.print_transparent_struct(fields);
+        } else if self.is_folded(fields) {
+            self.print_folded_struct();
         } else {
-            self.print_fields(fields, variant);
+            if deny_unknown_fields
+                && !self.flat
+                && !matches!(fields, Fields::Unit)
+            {
+                self.print_unknown_fields_comment();
+            }
+
+            if opaque {
+                self.print_opaque_fields(fields, variant);
+            } else if !self
+                .print_inlined(|ctxt| ctxt.print_fields(fields, variant))
+            {
+                self.print_fields(fields, variant);
+            }
+        }
+    }
+
+    /// Returns whether given struct is nested deeper than
+    /// `Formatting::max_depth` and so should get folded.
+    fn is_folded(&self, fields: &Fields) -> bool {
+        let max_depth = if let Some(max_depth) = self.fmt.max_depth {
+            max_depth
+        } else {
+            return false;
+        };
+
+        // Flattened structs are a part of their parent object
+        if self.flat || self.level < max_depth {
+            return false;
         }
+
+        matches!(fields, Fields::Named { fields } if !fields.is_empty())
+    }
+
+    /// Prints struct as `{ ... }`, with a comment pointing at its type.
+    fn print_folded_struct(&mut self) {
+        if let Some(name) = self.ty.name {
+            let title = print_reference::type_title(name);
+
+            self.out.append_comment(|comment| {
+                if comment.is_empty() {
+                    swrite!(comment, "See type \"{}\"", title);
+                } else {
+                    swrite!(comment, "; see type \"{}\"", title);
+                }
+            });
+        }
+
+        self.out.write("{ ... }");
+    }
+
+    fn print_unknown_fields_comment(&mut self) {
+        if !self.fmt.auto_comments.unknown_fields {
+            return;
+        }
+
+        self.out.append_hint(&self.fmt.messages.unknown_fields);
     }
 
     fn print_transparent_struct(&mut self, fields: &'ty Fields) {
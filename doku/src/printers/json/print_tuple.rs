@@ -2,18 +2,22 @@ use super::*;
 
 impl<'ty> Ctxt<'_, 'ty, '_> {
     pub(super) fn print_tuple(&mut self, fields: &'ty [Type]) {
-        let fields: Vec<_> = fields
-            .iter()
-            .filter(|field| {
-                self.vis.allows(field.serializable, field.deserializable)
-            })
-            .collect();
+        let fields: Vec<_> =
+            fields.iter().filter(|field| self.allows(field)).collect();
 
         if fields.is_empty() {
             self.out.write("[]");
             return;
         }
 
+        if self.print_inlined(|ctxt| ctxt.print_tuple_fields(&fields)) {
+            return;
+        }
+
+        self.print_tuple_fields(&fields);
+    }
+
+    fn print_tuple_fields(&mut self, fields: &[&'ty Type]) {
         self.out.writeln("[");
         self.out.inc_indent();
 
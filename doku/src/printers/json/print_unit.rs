@@ -0,0 +1,20 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    pub(super) fn print_unit(&mut self) {
+        if !self.fmt.auto_comments.unit {
+            return;
+        }
+
+        let unit = if let Some(unit) = self.ty.unit {
+            unit
+        } else {
+            return;
+        };
+
+        self.out.append_hint(Messages::fill(
+            &self.fmt.messages.unit,
+            &[("unit", &unit)],
+        ));
+    }
+}
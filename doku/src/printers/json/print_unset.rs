@@ -0,0 +1,17 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    /// Marks fields missing from a partial value - see:
+    /// `Printer::with_partial_value()`.
+    pub(super) fn print_unset(&mut self) {
+        if !self.partial || !self.is_field || self.val.is_some() {
+            return;
+        }
+
+        self.out.append_hint(&self.fmt.messages.unset);
+
+        // Everything inside this field is unset as well, so there's no point
+        // in repeating the hint for each of the nested fields
+        self.partial = false;
+    }
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+impl Ctxt<'_, '_, '_> {
+    pub(super) fn print_versions(&mut self) {
+        if !self.fmt.auto_comments.versions {
+            return;
+        }
+
+        let (since, until) = (self.ty.since, self.ty.until);
+
+        if since.is_none() && until.is_none() {
+            return;
+        }
+
+        if let Some(since) = since {
+            self.out.append_hint(Messages::fill(
+                &self.fmt.messages.since,
+                &[("version", &since.trim_start_matches('v'))],
+            ));
+        }
+
+        if let Some(until) = until {
+            self.out.append_hint(Messages::fill(
+                &self.fmt.messages.until,
+                &[("version", &until.trim_start_matches('v'))],
+            ));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+
+use doku::Document;
+use std::collections::BTreeMap;
+use test_case::test_case;
+
+#[derive(Document)]
+struct Config {
+    /// Address to listen at
+    addr: String,
+
+    server: Option<Server>,
+
+    #[doku(flatten)]
+    tls: Tls,
+
+    upstreams: BTreeMap<String, Upstream>,
+
+    user: User,
+
+    #[doku(rename = "node")]
+    root: Node,
+}
+
+#[derive(Document)]
+struct Server {
+    /// Port to listen at
+    port: u16,
+}
+
+#[derive(Document)]
+struct Tls {
+    /// Path to the certificate
+    tls_cert: String,
+}
+
+#[derive(Document)]
+struct Upstream {
+    /// Upstream's URL
+    url: String,
+}
+
+#[derive(Document)]
+struct User(Credentials);
+
+#[derive(Document)]
+struct Credentials {
+    /// User's login
+    login: String,
+}
+
+#[derive(Document)]
+struct Node {
+    /// Node's name
+    name: String,
+
+    children: Vec<Node>,
+
+    parent: Option<Box<Node>>,
+}
+
+fn target(path: &str) -> Option<&'static str> {
+    Config::ty().at(path).map(|ty| ty.comment.unwrap_or("-"))
+}
+
+#[test_case("" => Some("-") ; "root")]
+#[test_case("addr" => Some("Address to listen at") ; "field")]
+#[test_case("server.port" => Some("Port to listen at") ; "through optional")]
+#[test_case("tls_cert" => Some("Path to the certificate") ; "flattened")]
+#[test_case("tls" => None ; "flattened, by name")]
+#[test_case("upstreams.backend.url" => Some("Upstream's URL") ; "through map")]
+#[test_case("user.login" => Some("User's login") ; "through newtype")]
+#[test_case("node.name" => Some("Node's name") ; "renamed")]
+#[test_case("root" => None ; "renamed, by original name")]
+#[test_case("node.parent.parent.name" => Some("Node's name") ; "recursive")]
+#[test_case("addr.len" => None ; "through scalar")]
+#[test_case("server.host" => None ; "unknown")]
+fn at(path: &str) -> Option<&'static str> {
+    target(path)
+}
@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+
+use doku::Document;
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+
+#[derive(Document)]
+struct Config {
+    /// Address to listen at
+    #[doku(env = "APP_ADDR")]
+    addr: String,
+
+    upstream: Upstream,
+
+    #[doku(flatten)]
+    tls: Tls,
+
+    timeouts: BTreeMap<String, Timeout>,
+
+    #[doku(sensitive)]
+    token: String,
+
+    #[doc(hidden)]
+    debug: bool,
+
+    plain: String,
+}
+
+#[derive(Document)]
+struct Upstream(SocketAddr);
+
+#[derive(Document)]
+struct Tls {
+    /// Path to the certificate
+    #[doku(since = "1.2")]
+    tls_cert: Option<String>,
+}
+
+#[derive(Document)]
+struct Timeout {
+    #[doku(unit = "seconds", max = 60)]
+    secs: u32,
+}
+
+#[test]
+fn comments() {
+    let actual: Vec<_> = doku::comments::<Config>()
+        .into_iter()
+        .map(|(path, info)| {
+            format!(
+                "{} = {:?} {:?}",
+                path,
+                info.comment.unwrap_or_default(),
+                info.hints,
+            )
+        })
+        .collect();
+
+    let expected = vec![
+        r#"addr = "Address to listen at" ["Env: APP_ADDR"]"#,
+        r#"tls_cert = "Path to the certificate" ["Since v1.2", "Optional"]"#,
+        r#"timeouts.*.secs = "" ["In seconds", "Must be at most 60"]"#,
+        r#"token = "" ["Sensitive — do not commit"]"#,
+    ];
+
+    assert_eq!(expected, actual);
+}
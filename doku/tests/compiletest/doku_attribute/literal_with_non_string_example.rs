@@ -0,0 +1,11 @@
+use doku::Document;
+
+#[derive(Document)]
+struct Config {
+    #[doku(example = 8080, literal)]
+    port: u16,
+}
+
+fn main() {
+    println!("{}", doku::to_json::<Config>());
+}
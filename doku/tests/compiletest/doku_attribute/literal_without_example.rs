@@ -0,0 +1,11 @@
+use doku::Document;
+
+#[derive(Document)]
+struct Person {
+    #[doku(example = "\"alan\"", example = "\"ada\"", literal)]
+    name: String,
+}
+
+fn main() {
+    println!("{}", doku::to_json::<Person>());
+}
@@ -0,0 +1,11 @@
+use doku::Document;
+
+#[derive(Document)]
+struct Foo {
+    #[doku(min = "one")]
+    bar: u32,
+}
+
+fn main() {
+    println!("{}", doku::to_json::<Foo>());
+}
@@ -0,0 +1,14 @@
+use doku::Document;
+
+const BASE: isize = 100;
+
+#[derive(Document)]
+#[doku(repr, example_variant = "Bar")]
+enum Foo {
+    Foo = 1,
+    Bar = BASE,
+}
+
+fn main() {
+    println!("{}", doku::to_json::<Foo>());
+}
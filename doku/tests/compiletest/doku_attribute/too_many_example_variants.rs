@@ -0,0 +1,14 @@
+use doku::Document;
+
+#[derive(Document)]
+enum Foo {
+    #[doku(example)]
+    Bar,
+
+    #[doku(example)]
+    Zar,
+}
+
+fn main() {
+    println!("{}", doku::to_json::<Foo>());
+}
@@ -0,0 +1,11 @@
+use doku::Document;
+
+#[derive(Document)]
+#[doku(wrap = "outer.inner", wrap_comment = "a", wrap_comment = "b", wrap_comment = "c")]
+struct Person {
+    name: String,
+}
+
+fn main() {
+    println!("{}", doku::to_json::<Person>());
+}
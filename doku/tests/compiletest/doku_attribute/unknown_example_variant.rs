@@ -0,0 +1,11 @@
+use doku::Document;
+
+#[derive(Document)]
+#[doku(example_variant = "Baz")]
+enum Foo {
+    Bar,
+}
+
+fn main() {
+    println!("{}", doku::to_json::<Foo>());
+}
@@ -0,0 +1,11 @@
+use doku::Document;
+
+#[derive(Document)]
+struct Person {
+    #[doku(fmt(enums_style = "hidden"))]
+    name: String,
+}
+
+fn main() {
+    println!("{}", doku::to_json::<Person>());
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "deserialize")]
+#![allow(dead_code)]
+
+use doku::{Document, Type, TypeKind, TypeRegistry};
+use serde::Deserialize;
+
+#[derive(Deserialize, Document)]
+#[serde(tag = "type")]
+enum Config {
+    /// Listens at given address
+    #[serde(alias = "tcp")]
+    Server {
+        /// Address to listen at
+        #[doku(example = "localhost:8080", example = "[::1]:8080")]
+        #[doku(meta("x-team = infra"))]
+        addr: String,
+
+        #[doku(min = 1, max = 16)]
+        workers: Option<u8>,
+    },
+
+    Peer {
+        /// Peer's "name"
+        /// (must be unique)
+        name: String,
+    },
+}
+
+#[derive(Deserialize, Document)]
+struct Node {
+    name: String,
+    children: Vec<Node>,
+}
+
+#[test]
+fn ty() {
+    let expected = Config::ty();
+    let actual =
+        Type::from_json(&serde_json::to_string(&expected).unwrap()).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&expected).unwrap(),
+        serde_json::to_value(&actual).unwrap(),
+    );
+
+    assert_eq!(
+        doku::to_json::<Config>(),
+        doku::json::Printer::default().print(&actual)
+    );
+}
+
+#[test]
+fn references() {
+    let registry = TypeRegistry::from_json(
+        &serde_json::to_string(&TypeRegistry::of::<Node>()).unwrap(),
+    )
+    .unwrap();
+    let node = registry.get(std::any::type_name::<Node>()).unwrap();
+
+    let children = node.at("children").unwrap();
+
+    let children = if let TypeKind::Array { ty, .. } = children.kind {
+        ty
+    } else {
+        panic!("expected an array, got: {:?}", children.kind);
+    };
+
+    if let TypeKind::Reference { name, ty } = &children.kind {
+        assert_eq!(std::any::type_name::<Node>(), *name);
+        assert!(matches!(ty().kind, TypeKind::Any));
+    } else {
+        panic!("expected a reference, got: {:?}", children.kind);
+    }
+}
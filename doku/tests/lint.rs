@@ -0,0 +1,189 @@
+#![allow(dead_code)]
+
+use doku::{Document, Value};
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+use test_case::test_case;
+
+#[derive(Document)]
+struct Config {
+    /// Address to listen at
+    addr: String,
+
+    port: Option<u16>,
+
+    db: Db,
+
+    #[doku(until = "2.0")]
+    legacy: Option<bool>,
+
+    workers: Vec<Worker>,
+
+    labels: BTreeMap<String, u32>,
+
+    user: User,
+
+    #[doku(flatten)]
+    tls: Tls,
+
+    mode: Mode,
+
+    auth: Option<Auth>,
+
+    storage: Option<Storage>,
+
+    level: Option<Level>,
+
+    token: Token,
+
+    #[doku(flatten)]
+    limits: Option<Limits>,
+
+    pair: Option<(u16, String)>,
+
+    point: Option<Point>,
+}
+
+#[derive(Deserialize, Document)]
+struct Db {
+    /// Database's URL
+    #[serde(alias = "uri")]
+    url: String,
+}
+
+#[derive(Document)]
+struct Worker {
+    name: String,
+}
+
+#[derive(Document)]
+struct User(String);
+
+#[derive(Document)]
+struct Tls {
+    tls_cert: Option<String>,
+}
+
+#[derive(Document)]
+enum Mode {
+    Fast,
+
+    /// Slower, but safer
+    #[doku(until = "3.0")]
+    Safe,
+
+    Custom {
+        threads: usize,
+    },
+}
+
+#[derive(Deserialize, Document)]
+#[serde(tag = "type")]
+enum Auth {
+    Token { token: String },
+    None,
+}
+
+#[derive(Deserialize, Document)]
+#[serde(tag = "t", content = "c")]
+enum Storage {
+    Disk(String),
+}
+
+#[derive(Document)]
+#[doku(repr)]
+enum Level {
+    Low = 1,
+    High = 2,
+}
+
+#[derive(Deserialize, Document)]
+#[serde(transparent)]
+struct Token {
+    value: String,
+}
+
+#[derive(Document)]
+struct Limits {
+    max_conns: Option<u32>,
+}
+
+#[derive(Document)]
+struct Point(i32, i32);
+
+fn target(val: serde_json::Value) -> Vec<String> {
+    doku::lint::<Config>(&Value::from(&val))
+        .into_iter()
+        .map(|lint| lint.to_string())
+        .collect()
+}
+
+fn valid() -> serde_json::Value {
+    json!({
+        "addr": "localhost",
+        "db": { "url": "postgres://localhost" },
+        "workers": [{ "name": "a" }],
+        "labels": { "env": 1 },
+        "user": "root",
+        "tls_cert": "cert.pem",
+        "mode": "Fast",
+        "auth": { "type": "Token", "token": "secret" },
+        "storage": { "t": "Disk", "c": "/tmp" },
+        "level": 2,
+        "token": "secret",
+        "max_conns": 16,
+    })
+}
+
+fn with(key: &str, val: serde_json::Value) -> serde_json::Value {
+    let mut config = valid();
+
+    config[key] = val;
+    config
+}
+
+#[test]
+fn valid_config() {
+    assert_eq!(Vec::<String>::new(), target(valid()));
+}
+
+#[test_case("addr", json!(1) => vec!["addr: expected a string, found an integer"] ; "scalar")]
+#[test_case("port", json!(null) => Vec::<String>::new() ; "optional, null")]
+#[test_case("port", json!(true) => vec!["port: expected an integer, found a boolean"] ; "optional, present")]
+#[test_case("db", json!({ "url": 1, "pool": 4 }) => vec!["db.pool: unknown key", "db.url: expected a string, found an integer"] ; "nested struct")]
+#[test_case("db", json!({ "uri": "postgres://localhost" }) => Vec::<String>::new() ; "field alias")]
+#[test_case("legacy", json!(true) => vec!["legacy: deprecated, removed in v2.0"] ; "deprecated field")]
+#[test_case("workers", json!([{ "name": "a" }, { "nam": "b" }]) => vec!["workers[1].nam: unknown key"] ; "array")]
+#[test_case("labels", json!({ "env": "prod" }) => vec!["labels.env: expected an integer, found a string"] ; "map")]
+#[test_case("user", json!(["root"]) => Vec::<String>::new() ; "newtype, wrapped")]
+#[test_case("user", json!(1) => vec!["user: expected a string, found an integer"] ; "newtype")]
+#[test_case("tls_cert", json!(1) => vec!["tls_cert: expected a string, found an integer"] ; "flattened")]
+#[test_case("tls", json!({}) => vec!["tls: unknown key"] ; "flattened, by name")]
+#[test_case("mode", json!("Slow") => vec!["mode: unknown variant `Slow`, expected one of: `Fast`, `Safe`, `Custom`"] ; "enum, unknown variant")]
+#[test_case("mode", json!("Safe") => vec!["mode: deprecated, removed in v3.0"] ; "enum, deprecated variant")]
+#[test_case("mode", json!({ "Custom": { "threads": "4" } }) => vec!["mode.Custom.threads: expected an integer, found a string"] ; "enum, externally tagged")]
+#[test_case("mode", json!(1) => vec!["mode: expected a string, found an integer"] ; "enum, not a string")]
+#[test_case("auth", json!({ "type": "None" }) => Vec::<String>::new() ; "enum, internally tagged, unit")]
+#[test_case("auth", json!({ "type": "Token", "token": 1, "x": 1 }) => vec!["auth.token: expected a string, found an integer", "auth.x: unknown key"] ; "enum, internally tagged")]
+#[test_case("auth", json!({ "token": "secret" }) => vec!["auth.type: expected a string, found nothing"] ; "enum, internally tagged, without tag")]
+#[test_case("storage", json!({ "t": "Disk", "c": 1 }) => vec!["storage.c: expected a string, found an integer"] ; "enum, adjacently tagged")]
+#[test_case("level", json!(3) => vec!["level: unknown variant `3`, expected one of: `1`, `2`"] ; "enum, repr")]
+#[test_case("token", json!(1) => vec!["token: expected a string, found an integer"] ; "transparent")]
+#[test_case("max_conns", json!("16") => vec!["max_conns: expected an integer, found a string"] ; "flattened, optional")]
+#[test_case("pair", json!([1, "a"]) => Vec::<String>::new() ; "tuple")]
+#[test_case("pair", json!([1]) => vec!["pair: expected 2 elements, found 1"] ; "tuple, too short")]
+#[test_case("pair", json!([1, "a", 2]) => vec!["pair: expected 2 elements, found 3"] ; "tuple, too long")]
+#[test_case("point", json!([1, "2", 3]) => vec!["point: expected 2 elements, found 3", "point[1]: expected an integer, found a string"] ; "tuple struct")]
+fn lints(key: &str, val: serde_json::Value) -> Vec<String> {
+    target(with(key, val))
+}
+
+#[test]
+fn comments() {
+    let lints =
+        doku::lint::<Config>(&Value::from(&with("db", json!({ "url": 1 }))));
+
+    assert_eq!(1, lints.len());
+    assert_eq!(Some("Database's URL"), lints[0].comment);
+}
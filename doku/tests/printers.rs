@@ -6,6 +6,10 @@ mod array;
 #[path = "printers/attribute/mod.rs"]
 mod attribute;
 
+#[allow(dead_code)]
+#[path = "printers/bytes/mod.rs"]
+mod bytes;
+
 #[allow(dead_code)]
 #[path = "printers/enum/mod.rs"]
 mod r#enum;
@@ -76,10 +80,25 @@ mod prelude {
             }
         };
 
+        (@assert diff($ty:ty, $old:tt, $new:tt)) => {{
+            let old = doku::Value::from(&serde_json::json!($old));
+            let new = doku::Value::from(&serde_json::json!($new));
+
+            doku::diff::<$ty>(&old, &new)
+        }};
+
         (@assert to_json($ty:ty)) => {{
             doku::to_json::<$ty>()
         }};
 
+        (@assert to_json_at($ty:ty, $path:literal)) => {{
+            doku::to_json_at::<$ty>($path).unwrap()
+        }};
+
+        (@assert to_json_with($ty:ty, $transform:expr)) => {{
+            doku::to_json_fmt_with::<$ty, _>(&Default::default(), $transform)
+        }};
+
         (@assert to_json_without_comma($ty:ty)) => {{
             printer_test!(@assert to_json_without_comma($ty, {}))
         }};
@@ -111,6 +130,10 @@ mod prelude {
             doku::to_json_fmt::<$ty>(&fmt)
         }};
 
+        (@assert to_json_preset($ty:ty, $preset:ident)) => {{
+            doku::to_json_fmt::<$ty>(&doku::json::Formatting::$preset())
+        }};
+
         (@assert to_json_fmt_val($ty:ty, $fmt:tt)) => {{
             let fmt = serde_json::json!($fmt);
             let fmt = serde_json::from_value(fmt).expect("Given formatting is not valid");
@@ -118,6 +141,23 @@ mod prelude {
             doku::to_json_fmt_val(&fmt, &<$ty>::default())
         }};
 
+        (@assert to_json_partial_val($ty:ty, $val:tt)) => {{
+            doku::to_json_partial_val::<$ty, _>(&serde_json::json!($val))
+        }};
+
+        (@assert to_json_fmt_partial_val($ty:ty, $fmt:tt, $val:tt)) => {{
+            let fmt = serde_json::json!($fmt);
+            let fmt = serde_json::from_value(fmt).expect("Given formatting is not valid");
+
+            doku::to_json_fmt_partial_val::<$ty, _>(&fmt, &serde_json::json!($val))
+        }};
+
+        (@assert to_json_ver($ty:ty, $ver:literal)) => {{
+            doku::json::Printer::default()
+                .with_version($ver)
+                .print(&<$ty>::ty())
+        }};
+
         (@assert to_json_val($ty:ty)) => {{
             doku::to_json_val(&<$ty>::default())
         }};
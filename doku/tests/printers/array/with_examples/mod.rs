@@ -24,4 +24,8 @@ struct Ty {
 
 printer_test! {
     "output.json" => to_json(Ty),
+
+    "output.elements-3.json" => to_json_fmt(Ty, {
+        "arrays_style": { "elements": 3 },
+    }),
 }
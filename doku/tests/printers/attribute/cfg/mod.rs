@@ -0,0 +1 @@
+mod on_field;
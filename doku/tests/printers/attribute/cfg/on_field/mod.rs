@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    f1: String,
+
+    /// Some comment
+    #[cfg(test)]
+    f2: String,
+
+    #[cfg(not(test))]
+    f3: String,
+
+    #[cfg(test)]
+    #[cfg(any(unix, not(unix)))]
+    f4: String,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    /// Port to listen on
+    port: u16,
+
+    /// Internal token, used only by our test suite
+    #[doc(hidden)]
+    debug_token: String,
+
+    level: Level,
+}
+
+#[derive(Serialize, Document)]
+enum Level {
+    Info,
+    Warn,
+
+    #[doc(hidden)]
+    Trace,
+}
+
+printer_test! {
+    "output.hidden.json" => to_json(Ty),
+    "output.visible.json" => to_json_fmt(Ty, {
+        "doc_hidden": "Visible",
+    }),
+}
@@ -0,0 +1,3 @@
+mod on_container;
+mod on_field;
+mod on_variant;
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+
+trait Storage {
+    type Config;
+}
+
+struct Postgres;
+
+impl Storage for Postgres {
+    type Config = PostgresConfig;
+}
+
+#[derive(Document)]
+struct PostgresConfig {
+    url: String,
+}
+
+// The explicit bound replaces the inferred ones (here: `S::Config: Document`)
+#[derive(Document)]
+#[doku(bound = "S::Config: Document")]
+struct Ty<S: Storage> {
+    storage: S::Config,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty<Postgres>),
+}
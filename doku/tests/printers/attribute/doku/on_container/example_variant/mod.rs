@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    engine: Engine,
+    payload: Payload,
+}
+
+#[derive(Serialize, Document)]
+#[serde(rename_all = "snake_case")]
+#[doku(example_variant = "Postgres")]
+enum Engine {
+    Memory,
+    Postgres { url: String },
+}
+
+#[derive(Serialize, Document)]
+#[serde(untagged)]
+#[doku(example_variant = "Text")]
+enum Payload {
+    /// Binary payload
+    Binary {
+        bytes: Vec<u8>,
+    },
+
+    /// Text payload
+    Text {
+        text: String,
+    },
+
+    Empty,
+}
+
+printer_test! {
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented",
+    }),
+
+    "output.alternatives.json" => to_json_fmt(Ty, {
+        "untagged_enums_style": "Alternatives",
+    }),
+}
@@ -0,0 +1,5 @@
+mod bound;
+mod example_variant;
+mod rename_all_fields;
+mod sort;
+mod wrap;
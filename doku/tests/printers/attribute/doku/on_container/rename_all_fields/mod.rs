@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+#[doku(rename_all_fields = "camelCase")]
+enum Ty {
+    /// Fields should be camelCase
+    Foo { a_field_with_many_words: String },
+
+    /// Fields should be SCREAMING_SNAKE_CASE, since variant's own rule takes
+    /// precedence
+    #[doku(rename_all = "SCREAMING_SNAKE_CASE")]
+    Bar { a_field_with_many_words: String },
+
+    /// Field should be renamed to foo
+    Zar {
+        #[doku(rename = "foo")]
+        a_field_with_many_words: String,
+    },
+}
+
+printer_test! {
+    "output.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented",
+    }),
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+#[doku(sort = "alphabetical")]
+struct Ty {
+    zulu: String,
+
+    #[doku(rename = "mike")]
+    alpha: String,
+
+    charlie: String,
+
+    #[doku(order = 1)]
+    yankee: String,
+
+    bravo: String,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+#[doku(wrap = "config")]
+struct Single {
+    f1: String,
+}
+
+#[derive(Document)]
+#[doku(
+    wrap = "app.server.http",
+    wrap_comment = "Application's settings",
+    wrap_comment = "Server's settings"
+)]
+struct Multiple {
+    f1: String,
+}
+
+printer_test! {
+    "output.single.json" => to_json(Single),
+    "output.multiple.json" => to_json(Multiple),
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+/// Doesn't implement `Document`
+struct Plugins;
+
+#[derive(Document)]
+struct Ty {
+    /// Settings passed as-is to the plugins
+    #[doku(any)]
+    plugins: Plugins,
+
+    #[doku(any, example = "[1, 2, 3]")]
+    extra: Plugins,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
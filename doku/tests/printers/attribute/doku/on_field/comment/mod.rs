@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Developer-facing comment
+    #[doku(comment = "User-facing comment")]
+    f1: String,
+
+    #[doku(comment = "Comment without a doc-comment")]
+    f2: String,
+
+    /// Hidden comment
+    #[doku(comment = "")]
+    f3: String,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Address to listen on
+    #[doku(doc_append = "See: https://example.com/docs/listen")]
+    f1: String,
+
+    /// Maximum number of connections
+    #[doku(doc_append = "Warning: values above 1000 are not supported")]
+    #[doku(doc_append = "See: https://example.com/docs/connections")]
+    f2: usize,
+
+    #[doku(doc_append = "Line without a doc-comment")]
+    f3: String,
+
+    /// Developer-facing comment
+    #[doku(comment = "User-facing comment", doc_append = "Appended line")]
+    f4: String,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Without the attribute
+    f1: Level,
+
+    /// With the attribute
+    #[doku(enum_values_comment)]
+    f2: Level,
+
+    #[doku(enum_values_comment, example = "\"warn\"")]
+    f3: Level,
+
+    #[doku(enum_values_comment)]
+    f4: WithFallback,
+
+    /// Not applicable, since the enum has fields
+    #[doku(enum_values_comment)]
+    f5: WithFields,
+}
+
+#[derive(Document)]
+#[doku(rename_all = "lowercase")]
+enum Level {
+    Debug,
+    Info,
+    Warn,
+}
+
+#[derive(Serialize, Document)]
+enum WithFallback {
+    Foo,
+    Bar,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Document)]
+enum WithFields {
+    Foo,
+    Bar(String),
+}
+
+printer_test! {
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented"
+    }),
+    "output.separated.json" => to_json_fmt(Ty, {
+        "enums_style": "Separated"
+    }),
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    #[doku(env = "APP_HOST")]
+    host: String,
+
+    /// Port to listen at
+    #[doku(env = "APP_PORT")]
+    port: Option<u16>,
+
+    /// Request timeout
+    ///
+    /// (zero disables it)
+    #[doku(env = "APP_TIMEOUT", unit = "seconds")]
+    timeout: usize,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.without-hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "env": false }
+    }),
+}
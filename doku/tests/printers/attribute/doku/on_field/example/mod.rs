@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    #[doku(example = "8080")]
+    f1: u16,
+
+    #[doku(example = 8080)]
+    f2: u16,
+
+    #[doku(example = 1.5)]
+    f3: f32,
+
+    #[doku(example = false)]
+    f4: bool,
+
+    #[doku(example = 8080)]
+    f5: String,
+
+    #[doku(example = 1, example = 2.5)]
+    f6: Vec<f32>,
+
+    #[doku(example = true)]
+    f7: Option<bool>,
+
+    f8: Name,
+}
+
+struct Name;
+
+impl Document for Name {
+    fn ty() -> doku::Type {
+        doku::Type {
+            example: Some(doku::Example::String("foo")),
+            ..doku::Type::from(doku::TypeKind::Any)
+        }
+    }
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// TLS configuration
+    #[doku(
+        example_file = "tests/printers/attribute/doku/on_field/example_file/example.json"
+    )]
+    tls: Tls,
+}
+
+#[derive(Document)]
+struct Tls {
+    cert: String,
+    key: String,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    #[doku(fmt(enums_style = "commented", doc_comments = "hidden"))]
+    f1: Enum,
+
+    #[doku(fmt(enums_style = "commented"))]
+    f2: Struct,
+
+    #[doku(fmt(doc_comments = "hidden"))]
+    f3: Struct,
+
+    #[doku(fmt(untagged_enums_style = "alternatives"))]
+    f4: Untagged,
+
+    #[doku(fmt(inline))]
+    f5: Point,
+
+    #[doku(fmt(layout = "two-columns"))]
+    f6: Struct,
+}
+
+#[derive(Document)]
+enum Enum {
+    /// This is `Foo`
+    Foo,
+
+    /// This is `Bar`
+    Bar,
+}
+
+#[derive(Document)]
+#[doku(untagged)]
+enum Untagged {
+    Foo(String),
+    Bar(usize),
+}
+
+#[derive(Document)]
+struct Struct {
+    /// This is `f1`
+    f1: String,
+
+    /// This is `f2`
+    f2: Enum,
+}
+
+#[derive(Document)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Document)]
+struct Outer {
+    #[doku(fmt(layout = "one-column"))]
+    f1: Struct,
+
+    /// This is `f2`
+    f2: Point,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.two-columns.json" => to_json_fmt(Outer, {
+        "layout": { "TwoColumns": { "align": true, "spacing": 1 } }
+    }),
+}
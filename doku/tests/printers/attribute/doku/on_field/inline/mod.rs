@@ -0,0 +1,43 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Primary database
+    #[doku(inline = false)]
+    primary: Database,
+
+    /// Replica database
+    #[doku(inline = false)]
+    replica: Database,
+
+    /// Not referenced, since it's inlined by default
+    backup: Database,
+
+    cache: Cache,
+}
+
+/// Database connection settings
+#[derive(Document)]
+struct Database {
+    #[doku(example = "postgres://localhost")]
+    url: String,
+    pool: Pool,
+}
+
+#[derive(Document)]
+#[doku(inline = false)]
+struct Pool {
+    size: usize,
+}
+
+#[derive(Document)]
+#[doku(inline = false)]
+struct Cache {
+    /// Inlined, since inline fields override the container's setting
+    #[doku(inline = true)]
+    pool: Pool,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
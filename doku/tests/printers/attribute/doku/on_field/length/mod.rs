@@ -0,0 +1,34 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    #[doku(min_length = 1)]
+    f1: String,
+
+    #[doku(max_length = 1)]
+    f2: String,
+
+    /// Some comment
+    #[doku(min_length = 3, max_length = 64)]
+    f3: Option<String>,
+
+    #[doku(min_length = 1, max_length = 8)]
+    f4: Vec<usize>,
+
+    #[doku(min_length = 2)]
+    #[doku(pattern = "^[a-z]+$")]
+    f5: String,
+
+    #[doku(min_length = 1, max_length = 1)]
+    f6: String,
+
+    #[doku(min_length = 4, max_length = 4)]
+    f7: Vec<usize>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.without-hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "length": false }
+    }),
+}
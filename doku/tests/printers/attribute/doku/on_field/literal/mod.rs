@@ -0,0 +1,39 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Inline literal
+    #[doku(example = r#"[ "aaa", "bbb" ]"#, literal)]
+    f1: Vec<String>,
+
+    /// Multi-line literal
+    #[doku(
+        example = r#"
+            {
+                "cert": "/etc/tls/cert.pem",
+                "key": "/etc/tls/key.pem"
+            }
+        "#,
+        literal
+    )]
+    f2: Tls,
+
+    /// Nested multi-line literal
+    f3: Nested,
+}
+
+#[derive(Document)]
+struct Tls {
+    cert: String,
+    key: String,
+}
+
+#[derive(Document)]
+struct Nested {
+    #[doku(literal_example = "[\n  1,\n  2\n]")]
+    f1: Vec<usize>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
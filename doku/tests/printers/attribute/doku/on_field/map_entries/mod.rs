@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Document)]
+struct Ty {
+    f1: BTreeMap<String, usize>,
+
+    #[doku(map_entries = 2)]
+    f2: BTreeMap<String, usize>,
+
+    #[doku(map_entries = 3, key_example = "my-service")]
+    f3: BTreeMap<String, Service>,
+}
+
+#[derive(Document)]
+struct Service {
+    port: usize,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Document)]
+struct Ty {
+    #[doku(key_example = "my-service")]
+    f1: HashMap<String, usize>,
+
+    #[doku(value_example = "8080")]
+    f2: BTreeMap<String, usize>,
+
+    #[doku(key_example = "my-service", value_example = "8080")]
+    f3: HashMap<String, usize>,
+
+    #[doku(key_example = "my-service")]
+    f4: HashMap<String, Service>,
+
+    #[doku(key_example = "my-service", value_example = "8080")]
+    f5: Option<HashMap<String, usize>>,
+}
+
+#[derive(Document)]
+struct Service {
+    port: usize,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
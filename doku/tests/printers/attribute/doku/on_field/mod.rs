@@ -0,0 +1,21 @@
+mod any;
+mod comment;
+mod doc_append;
+mod enum_values_comment;
+mod env;
+mod example;
+mod example_file;
+mod fmt;
+mod inline;
+mod length;
+mod literal;
+mod map_entries;
+mod map_examples;
+mod one_of;
+mod order;
+mod pattern;
+mod range;
+mod section;
+mod sensitive;
+mod since_until;
+mod unit;
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    /// Logging level
+    #[doku(one_of = "debug", one_of = "info", one_of = "warn")]
+    level: String,
+
+    #[doku(one_of = "a", one_of = "b", example = "b", max_length = 1)]
+    choice: Option<String>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.without_comments.json" => to_json_fmt(Ty, {
+        "auto_comments": { "one_of": false }
+    }),
+}
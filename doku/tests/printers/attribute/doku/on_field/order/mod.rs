@@ -0,0 +1,18 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    f1: String,
+
+    #[doku(order = 2)]
+    f2: String,
+
+    f3: String,
+
+    #[doku(order = 1)]
+    f4: String,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    #[doku(pattern = "^[a-z0-9-]+$")]
+    id: String,
+
+    /// Service's name
+    #[doku(pattern = "^[A-Z]")]
+    name: Option<String>,
+
+    /// Service's aliases
+    ///
+    /// (used for lookups)
+    #[doku(pattern = "^[a-z]+$")]
+    aliases: Vec<String>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.without-hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "pattern": false }
+    }),
+}
@@ -0,0 +1,27 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    /// Port to listen at
+    #[doku(min = 1024, max = 65535)]
+    port: u16,
+
+    #[doku(min = 1)]
+    workers: usize,
+
+    #[doku(max = 0.75)]
+    ratio: f32,
+
+    #[doku(min = "-10", max = "10", multiple_of = 2)]
+    offset: i32,
+
+    #[doku(min = 1)]
+    timeout: Option<u32>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.without_comments.json" => to_json_fmt(Ty, {
+        "auto_comments": { "range": false }
+    }),
+}
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Name of the service
+    name: String,
+
+    /// Address to listen on
+    #[doku(section = "Networking")]
+    listen: String,
+
+    /// Path to the database
+    #[doku(section = "Storage")]
+    database: String,
+
+    /// Port to listen on
+    #[doku(section = "Networking")]
+    port: u16,
+
+    /// Whether to print debug messages
+    debug: bool,
+
+    #[doku(section = "Storage")]
+    cache: usize,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.two-columns.json" => to_json_fmt(Ty, {
+        "layout": { "TwoColumns": { "align": true, "spacing": 1 }}
+    }),
+}
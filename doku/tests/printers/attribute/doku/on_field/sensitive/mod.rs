@@ -0,0 +1,35 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    user: String,
+
+    /// User's password
+    #[doku(sensitive, example = "hunter2")]
+    password: String,
+
+    #[doku(sensitive)]
+    token: Option<String>,
+
+    #[doku(sensitive)]
+    keys: Vec<String>,
+}
+
+impl Default for Ty {
+    fn default() -> Self {
+        Self {
+            user: "root".to_string(),
+            password: "correct horse battery staple".to_string(),
+            token: Some("abcdef".to_string()),
+            keys: vec!["foo".to_string(), "bar".to_string()],
+        }
+    }
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.val.json" => to_json_val(Ty),
+    "output.without-hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "sensitive": false }
+    }),
+}
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    host: String,
+
+    /// Port to listen at
+    #[doku(since = "2.0")]
+    port: u16,
+
+    #[doku(until = "2.0")]
+    address: String,
+
+    #[doku(since = "1.5", until = "v3")]
+    proxy: Option<String>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.v1.json" => to_json_ver(Ty, "1.0"),
+    "output.v2.json" => to_json_ver(Ty, "2.0"),
+    "output.v3.json" => to_json_ver(Ty, "3.0"),
+}
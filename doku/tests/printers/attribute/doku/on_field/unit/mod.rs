@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    #[doku(unit = "seconds")]
+    timeout: usize,
+
+    /// Size of the cache
+    #[doku(unit = "MiB", example = "512")]
+    cache_size: Option<usize>,
+
+    /// Retry delays
+    #[doku(unit = "milliseconds", max_length = 5)]
+    delays: Vec<usize>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.without-hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "unit": false }
+    }),
+}
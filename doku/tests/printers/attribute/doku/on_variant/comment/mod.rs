@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    #[doku(meta(r#"fmt.enums_style = "Commented""#))]
+    engine: Engine,
+}
+
+#[derive(Document)]
+enum Engine {
+    /// Developer-facing comment
+    #[doku(comment = "PostgreSQL database")]
+    PostgreSQL,
+
+    #[doku(comment = "MySQL database")]
+    MySQL,
+
+    /// Hidden comment
+    #[doku(comment = "")]
+    Sqlite,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
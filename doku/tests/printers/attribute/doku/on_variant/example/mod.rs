@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    engine: Engine,
+    payload: Payload,
+}
+
+#[derive(Serialize, Document)]
+#[serde(rename_all = "snake_case")]
+enum Engine {
+    Memory,
+
+    #[doku(example)]
+    Postgres {
+        url: String,
+    },
+}
+
+#[derive(Serialize, Document)]
+#[serde(tag = "kind")]
+enum Payload {
+    /// Binary payload
+    Binary {
+        bytes: Vec<u8>,
+    },
+
+    /// Text payload
+    #[doku(example)]
+    Text {
+        text: String,
+    },
+
+    Empty,
+}
+
+printer_test! {
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented",
+    }),
+}
@@ -0,0 +1,4 @@
+mod comment;
+mod example;
+mod since_until;
+mod skip;
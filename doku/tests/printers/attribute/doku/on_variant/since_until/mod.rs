@@ -0,0 +1,33 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    engine: Engine,
+    engines: Vec<Engine>,
+}
+
+#[derive(Document)]
+enum Engine {
+    PostgreSQL,
+
+    #[doku(since = "2.0")]
+    MySQL,
+
+    #[doku(until = "2.0")]
+    Sqlite,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.v1.json" => to_json_ver(Ty, "1.0"),
+    "output.v2.json" => to_json_ver(Ty, "2.0"),
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented",
+    }),
+    "output.expanded.json" => to_json_fmt(Ty, {
+        "enums_style": "Expanded",
+    }),
+    "output.without_versions.json" => to_json_fmt(Ty, {
+        "auto_comments": { "versions": false },
+    }),
+}
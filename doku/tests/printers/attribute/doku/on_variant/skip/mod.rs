@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    tagged: Tagged,
+    untagged: Untagged,
+}
+
+#[derive(Serialize, Document)]
+#[serde(tag = "kind")]
+enum Tagged {
+    Foo,
+
+    #[doku(skip)]
+    Bar {
+        internal: NotDocumented,
+    },
+
+    // doku(skip = false) overrides serde(skip)
+    #[serde(skip)]
+    #[doku(skip = false)]
+    Zar,
+}
+
+#[derive(Serialize, Document)]
+#[serde(untagged)]
+enum Untagged {
+    #[doku(skip)]
+    Foo(NotDocumented),
+
+    Bar(String),
+}
+
+#[derive(Serialize)]
+struct NotDocumented;
+
+printer_test! {
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented",
+    }),
+
+    "output.separated.json" => to_json_fmt(Ty, {
+        "enums_style": "Separated",
+    }),
+}
@@ -1 +1,4 @@
+mod cfg;
+mod doc_hidden;
+mod doku;
 mod serde;
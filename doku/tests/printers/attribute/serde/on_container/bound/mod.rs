@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use serde::de::DeserializeOwned;
+
+trait Storage {
+    type Config;
+}
+
+struct Postgres;
+
+impl Storage for Postgres {
+    type Config = PostgresConfig;
+}
+
+#[derive(Serialize, Deserialize, Document)]
+struct PostgresConfig {
+    url: String,
+}
+
+// `#[serde(bound)]` doesn't replace the inferred bounds (here:
+// `S::Config: Document`)
+#[derive(Serialize, Document)]
+#[serde(bound = "S::Config: Serialize")]
+struct Ty<S: Storage> {
+    storage: S::Config,
+}
+
+#[derive(Serialize, Document)]
+#[serde(bound(serialize = "S::Config: Serialize"))]
+enum Ty2<S: Storage> {
+    Storage(S::Config),
+}
+
+#[derive(Deserialize, Document)]
+#[serde(bound(deserialize = "T: DeserializeOwned"))]
+struct Ty3<T> {
+    inner: T,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty<Postgres>),
+    "output.enum.json" => to_json(Ty2<Postgres>),
+    "output.deserialize.json" => to_json(Ty3<PostgresConfig>),
+}
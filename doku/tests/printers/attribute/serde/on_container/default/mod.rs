@@ -8,4 +8,7 @@ struct Ty {
 
 printer_test! {
     "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "defaults": true }
+    }),
 }
@@ -4,6 +4,32 @@ use crate::prelude::*;
 #[serde(deny_unknown_fields)]
 struct Ty;
 
+#[derive(Serialize, Document)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    /// Database's configuration
+    db: Database,
+
+    #[serde(flatten)]
+    http: Http,
+}
+
+#[derive(Serialize, Document)]
+#[serde(deny_unknown_fields)]
+struct Database {
+    url: String,
+}
+
+#[derive(Serialize, Document)]
+#[serde(deny_unknown_fields)]
+struct Http {
+    port: u16,
+}
+
 printer_test! {
     "output.json" => to_json(Ty),
+    "output.nested.json" => to_json(Config),
+    "output.nested.with_hints.json" => to_json_fmt(Config, {
+        "auto_comments": { "unknown_fields": true }
+    }),
 }
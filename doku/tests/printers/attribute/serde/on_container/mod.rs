@@ -1,3 +1,4 @@
+mod bound;
 mod default;
 mod deny_unknown_fields;
 mod krate;
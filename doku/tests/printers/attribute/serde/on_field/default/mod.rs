@@ -6,6 +6,44 @@ struct Ty {
     foo: bool,
 }
 
+#[derive(Serialize, Document)]
+struct TyWithHints {
+    #[serde(default)]
+    foo: bool,
+
+    /// Number of workers
+    #[serde(default = "default_workers")]
+    workers: usize,
+
+    bar: Option<String>,
+    zar: String,
+}
+
+fn default_workers() -> usize {
+    4
+}
+
+impl Default for TyWithHints {
+    fn default() -> Self {
+        Self {
+            foo: false,
+            workers: default_workers(),
+            bar: None,
+            zar: "zar".into(),
+        }
+    }
+}
+
 printer_test! {
     "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(TyWithHints, {
+        "auto_comments": { "defaults": true }
+    }),
+    "output.with_hints.required.json" => to_json_fmt(TyWithHints, {
+        "auto_comments": { "defaults": true, "required": true }
+    }),
+    "output.with_hints.with_value.json" => to_json_fmt_val(TyWithHints, {
+        "auto_comments": { "defaults": true }
+    }),
+    "output.without_hints.json" => to_json(TyWithHints),
 }
@@ -6,6 +6,33 @@ enum Ty {
     Foo,
 }
 
+#[derive(Deserialize, Document)]
+struct Config {
+    protocol: Protocol,
+}
+
+#[derive(Deserialize, Document)]
+#[serde(rename_all = "lowercase")]
+enum Protocol {
+    /// HTTP/1.1
+    #[serde(alias = "http1")]
+    Http11,
+
+    /// HTTP/2
+    #[serde(alias = "h2", alias = "http-2")]
+    Http2,
+
+    #[serde(rename = "grpc")]
+    Grpc { addr: String },
+}
+
 printer_test! {
     "output.json" => to_json(Ty),
+    "output.config.json" => to_json(Config),
+    "output.config.commented.json" => to_json_fmt(Config, {
+        "enums_style": "Commented",
+    }),
+    "output.config.rust_names.json" => to_json_fmt(Config, {
+        "auto_comments": { "aliases": false, "rust_names": true },
+    }),
 }
@@ -6,6 +6,28 @@ enum Ty {
     Foo,
 }
 
+#[derive(Serialize, Document)]
+struct Config {
+    engine: Engine,
+}
+
+#[derive(Serialize, Document)]
+enum Engine {
+    Memory,
+    Postgres,
+
+    #[serde(other)]
+    Unknown,
+}
+
 printer_test! {
     "output.json" => to_json(Ty),
+
+    "output.commented.json" => to_json_fmt(Config, {
+        "enums_style": "Commented",
+    }),
+
+    "output.separated.json" => to_json_fmt(Config, {
+        "enums_style": "Separated",
+    }),
 }
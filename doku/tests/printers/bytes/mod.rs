@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    vec: Vec<u8>,
+    array: [u8; 32],
+
+    /// Opted in
+    #[doku(bytes)]
+    opted_in: Vec<u8>,
+
+    #[doku(bytes)]
+    key: [u8; 32],
+
+    #[doku(bytes)]
+    boxed: Box<[u8]>,
+
+    #[doku(bytes)]
+    optional: Option<[u8; 32]>,
+
+    #[doku(bytes, example = "3q2+7w==")]
+    with_example: Vec<u8>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+
+    "output.with-hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "bytes": true },
+    }),
+}
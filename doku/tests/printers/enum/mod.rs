@@ -1,4 +1,7 @@
 mod nested;
 mod of_tag;
 mod with_comments;
+mod with_discriminants;
 mod with_generics;
+mod with_generics_and_tag;
+mod with_variant_comments;
@@ -64,6 +64,14 @@ printer_test! {
          "enums_style": "Separated",
     }),
 
+    "output.with-user-comments.expanded.json" => to_json_fmt(WithUserComments, {
+         "enums_style": "Expanded",
+    }),
+
+    "output.with-user-comments-wrapped.expanded.json" => to_json_fmt(WithUserCommentsWrapped, {
+         "enums_style": "Expanded",
+    }),
+
     "output.without-key-quotes.with-user-comments-wrapped.commented.json" => to_json_without_key_quotes(WithUserCommentsWrapped, {
           "enums_style": "Commented",
      }),
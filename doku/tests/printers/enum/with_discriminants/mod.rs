@@ -0,0 +1,51 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Serialized through names
+    f1: Level,
+
+    /// Serialized through discriminants
+    f2: ReprLevel,
+
+    f3: Implicit,
+
+    /// Example variant's discriminant is unknown
+    f4: Computed,
+}
+
+#[derive(Document)]
+enum Level {
+    Debug = 0,
+    Info = 1,
+}
+
+#[derive(Document)]
+#[doku(repr, example_variant = "Warn")]
+enum ReprLevel {
+    Debug = -1,
+    Info = 10,
+    Warn,
+    Error,
+}
+
+#[derive(Document)]
+#[doku(repr)]
+enum Implicit {
+    Foo,
+    Bar,
+}
+
+const BASE: isize = 100;
+
+#[derive(Document)]
+#[doku(repr)]
+enum Computed {
+    Foo = BASE,
+    Bar = 5,
+    Baz,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+#[serde(tag = "t", content = "c")]
+enum Adjacent<A, B> {
+    Left(A),
+    Right(B),
+    Both { left: A, right: B },
+}
+
+#[derive(Serialize, Document)]
+#[serde(tag = "t")]
+enum Internal<A, B> {
+    Left { value: A },
+    Right { value: B },
+}
+
+#[derive(Serialize, Document)]
+#[serde(tag = "kind")]
+enum InternalNewtype<A> {
+    Wrapped(A),
+}
+
+#[derive(Serialize, Document)]
+struct Payload {
+    a: String,
+}
+
+#[derive(Serialize, Document)]
+struct Config<A, B> {
+    adjacent: Adjacent<A, B>,
+    internal: Internal<B, A>,
+    either: Option<Vec<Adjacent<B, A>>>,
+}
+
+printer_test! {
+    "output.adjacent.json" => to_json(Adjacent<String, u32>),
+    "output.internal.json" => to_json(Internal<String, u32>),
+    "output.internal_newtype.json" => to_json(InternalNewtype<Payload>),
+    "output.internal_newtype.commented.json" => to_json_fmt(InternalNewtype<Payload>, {
+        "enums_style": "Commented",
+    }),
+    "output.config.json" => to_json(Config<String, bool>),
+}
@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Engine
+    engine: Engine,
+}
+
+#[derive(Document)]
+enum Engine {
+    /// In-memory database
+    Memory,
+
+    /// PostgreSQL database
+    Postgres(
+        /// Connection string
+        String,
+    ),
+
+    /// Redis instance
+    Redis(
+        /// Host name
+        String,
+        /// Port number
+        u16,
+    ),
+
+    /// SQLite database
+    Sqlite {
+        /// Path to the database file
+        path: String,
+    },
+}
+
+printer_test! {
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented",
+    }),
+    "output.separated.json" => to_json_fmt(Ty, {
+        "enums_style": "Separated",
+    }),
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use arrayvec::{ArrayString, ArrayVec};
+
+#[derive(Document)]
+struct Ty {
+    /// Addresses to listen at
+    addrs: ArrayVec<String, 4>,
+
+    name: ArrayString<16>,
+
+    #[doku(max_length = 8)]
+    tag: ArrayString<16>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
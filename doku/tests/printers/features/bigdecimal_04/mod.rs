@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use bigdecimal::BigDecimal;
+
+#[derive(Document)]
+struct Ty {
+    amount: BigDecimal,
+
+    /// Fee charged per transaction
+    #[doku(example = "0.25")]
+    fee: Option<BigDecimal>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
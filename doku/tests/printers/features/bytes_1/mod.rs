@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use ::bytes::{Bytes, BytesMut};
+
+#[derive(Document)]
+struct Ty {
+    payload: Bytes,
+
+    /// Opted in
+    #[doku(bytes)]
+    buffer: BytesMut,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
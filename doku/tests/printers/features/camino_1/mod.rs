@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use camino::Utf8PathBuf;
+
+#[derive(Document)]
+struct Ty {
+    data_dir: Utf8PathBuf,
+
+    /// Where to write logs to
+    #[doku(example = "/var/log/app.log")]
+    log_file: Option<Utf8PathBuf>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
@@ -1,11 +1,20 @@
 use crate::prelude::*;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
 #[derive(Document)]
 struct Ty {
     dt: DateTime<Utc>,
+    ndt: NaiveDateTime,
+    nd: NaiveDate,
+    nt: NaiveTime,
+
+    #[doku(example = "2020-02-29")]
+    nd_with_example: NaiveDate,
 }
 
 printer_test! {
     "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
 }
@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Config {
+    /// Address to listen at
+    addr: String,
+
+    /// Request timeout, in seconds
+    timeout: Option<f32>,
+
+    mode: Mode,
+    workers: Vec<Worker>,
+}
+
+#[derive(Document)]
+enum Mode {
+    Fast,
+    Safe,
+}
+
+#[derive(Document)]
+struct Worker {
+    name: String,
+    enabled: bool,
+}
+
+printer_test! {
+    "output.json" => to_json_fmt(Config, {
+        "colors": {},
+    }),
+
+    "output.hash.json" => to_json_fmt(Config, {
+        "colors": {
+            "keys": "Yellow",
+            "values": "BrightCyan",
+            "comments": "Magenta",
+        },
+        "comments_style": { "separator": "#" },
+        "optionals_style": "CommentedOut",
+    }),
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use either::Either;
+
+#[derive(Document)]
+struct Ty {
+    /// Port to listen at, or path to a Unix socket
+    listen: Either<u16, String>,
+
+    upstream: Option<Either<Upstream, String>>,
+}
+
+#[derive(Document)]
+struct Upstream {
+    host: String,
+    port: u16,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Addresses to listen at
+    addrs: heapless::Vec<String, 4>,
+
+    name: heapless::String<16>,
+
+    #[doku(max_length = 8)]
+    tag: heapless::String<16>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
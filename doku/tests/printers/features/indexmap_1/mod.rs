@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use indexmap::{IndexMap, IndexSet};
+
+#[derive(Document)]
+struct Ty {
+    /// Upstreams, tried in order
+    upstreams: IndexMap<String, Upstream>,
+
+    tags: IndexSet<String>,
+}
+
+#[derive(Document)]
+struct Upstream {
+    #[doku(example = "http://localhost:8080")]
+    url: String,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
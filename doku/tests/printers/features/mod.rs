@@ -1,7 +1,58 @@
 mod std;
 
+#[cfg(feature = "arrayvec-07")]
+mod arrayvec_07;
+
+#[cfg(feature = "bigdecimal-04")]
+mod bigdecimal_04;
+
+#[cfg(feature = "bytes-1")]
+mod bytes_1;
+
+#[cfg(feature = "camino-1")]
+mod camino_1;
+
 #[cfg(feature = "chrono-04")]
 mod chrono_04;
 
+#[cfg(feature = "color")]
+mod color;
+
+#[cfg(feature = "either-1")]
+mod either_1;
+
+#[cfg(feature = "heapless-07")]
+mod heapless_07;
+
+#[cfg(feature = "indexmap-1")]
+mod indexmap_1;
+
+#[cfg(feature = "ordered-float-4")]
+mod ordered_float_4;
+
+#[cfg(feature = "provenance")]
+mod provenance;
+
+#[cfg(feature = "rust-decimal-1")]
+mod rust_decimal_1;
+
+#[cfg(feature = "secrecy-08")]
+mod secrecy_08;
+
+#[cfg(feature = "semver-1")]
+mod semver_1;
+
+#[cfg(feature = "serde-json-1")]
+mod serde_json_1;
+
+#[cfg(feature = "smallvec-1")]
+mod smallvec_1;
+
+#[cfg(feature = "time-03")]
+mod time_03;
+
 #[cfg(feature = "url-2")]
 mod url_2;
+
+#[cfg(feature = "uuid-1")]
+mod uuid_1;
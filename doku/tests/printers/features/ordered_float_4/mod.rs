@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use ordered_float::{NotNan, OrderedFloat};
+
+#[derive(Document)]
+struct Ty {
+    weight: OrderedFloat<f64>,
+
+    /// Fraction of requests to sample
+    #[doku(example = "0.1")]
+    sample_rate: NotNan<f32>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Port to listen at
+    port: u16,
+
+    #[doku(min = 1)]
+    workers: usize,
+
+    db: Db,
+}
+
+#[derive(Document)]
+struct Db(String);
+
+printer_test! {
+    "output.json" => to_json_fmt(Ty, {
+        "auto_comments": { "location": true }
+    }),
+    "output.without_location.json" => to_json(Ty),
+}
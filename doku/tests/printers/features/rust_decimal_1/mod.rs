@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use rust_decimal::Decimal;
+
+#[derive(Document)]
+struct Ty {
+    amount: Decimal,
+
+    /// Fee charged per transaction
+    #[doku(example = "0.25")]
+    fee: Option<Decimal>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
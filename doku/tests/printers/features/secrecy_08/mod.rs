@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use secrecy::{Secret, SecretString};
+
+#[derive(Document)]
+struct Ty {
+    /// Database's password
+    password: SecretString,
+
+    #[doku(example = "hunter2")]
+    api_key: Option<SecretString>,
+
+    pin: Secret<u32>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
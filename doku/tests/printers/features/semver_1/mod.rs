@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use semver::{Version, VersionReq};
+
+#[derive(Document)]
+struct Ty {
+    version: Version,
+
+    /// Versions of the plugin API this config is compatible with
+    api: VersionReq,
+
+    #[doku(example = "0.1.0-beta.1")]
+    min_version: Option<Version>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use serde_json::{Map, Number, Value};
+
+#[derive(Document)]
+struct Ty {
+    value: Value,
+
+    /// Settings passed as-is to the plugins
+    plugins: Option<Value>,
+
+    #[doku(example = r#"{ "level": "debug" }"#)]
+    logging: Value,
+
+    /// Labels attached to every metric
+    labels: Map<String, Value>,
+
+    threshold: Number,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
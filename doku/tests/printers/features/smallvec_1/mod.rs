@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use smallvec::SmallVec;
+
+#[derive(Document)]
+struct Ty {
+    /// Addresses to listen at
+    addrs: SmallVec<[String; 4]>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use std::ops::Bound;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    /// Lower bound of the accepted ports
+    min_port: Bound<u16>,
+
+    max_port: Bound<u16>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented"
+    }),
+}
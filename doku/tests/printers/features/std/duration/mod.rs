@@ -4,6 +4,10 @@ use std::time::Duration;
 #[derive(Document)]
 struct Ty {
     tt: Duration,
+
+    /// Serialized through e.g. `humantime-serde`
+    #[doku(duration)]
+    tt_humantime: Duration,
 }
 
 printer_test! {
@@ -1 +1,7 @@
+mod bound;
 mod duration;
+mod net;
+mod path;
+mod result;
+mod system_time;
+mod wrappers;
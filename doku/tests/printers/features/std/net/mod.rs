@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use std::net::{
+    IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
+};
+
+#[derive(Document)]
+struct Ty {
+    ip: IpAddr,
+    ipv4: Ipv4Addr,
+    ipv6: Ipv6Addr,
+    socket: SocketAddr,
+    socket_v4: SocketAddrV4,
+    socket_v6: SocketAddrV6,
+
+    /// Address to bind to
+    #[doku(example = "0.0.0.0:3000")]
+    bind: SocketAddr,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use std::path::{Path, PathBuf};
+
+#[derive(Document)]
+struct Ty<'a> {
+    path_buf: PathBuf,
+    path: &'a Path,
+    opt: Option<PathBuf>,
+
+    /// Where to store the logs
+    #[doku(example = "/var/log/app.log")]
+    log_file: PathBuf,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    /// Outcome of the last run
+    last_run: Result<u32, String>,
+
+    previous_runs: Vec<Result<u32, String>>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented"
+    }),
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use std::time::SystemTime;
+
+#[derive(Document)]
+struct Ty {
+    tt: SystemTime,
+
+    /// Serialized through e.g. `humantime-serde`
+    #[doku(timestamp)]
+    tt_humantime: Option<SystemTime>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
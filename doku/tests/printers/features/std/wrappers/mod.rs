@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
+
+#[derive(Document)]
+struct Ty<'a> {
+    cow_str: Cow<'a, str>,
+    cow_slice: Cow<'a, [u16]>,
+    rc: Rc<str>,
+    arc: Arc<[String]>,
+    mutex: Mutex<bool>,
+    rw_lock: RwLock<Vec<String>>,
+    cell: Cell<u32>,
+
+    /// Peers, shared between the workers
+    #[doku(example = "127.0.0.1:8080")]
+    ref_cell: RefCell<Box<[String]>>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,21 @@
+use crate::prelude::*;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+#[derive(Document)]
+struct Ty {
+    odt: OffsetDateTime,
+    pdt: PrimitiveDateTime,
+    d: Date,
+    t: Time,
+    dur: Duration,
+
+    #[doku(example = "2020-02-29")]
+    d_with_example: Date,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
@@ -4,8 +4,15 @@ use url::Url;
 #[derive(Document)]
 struct Ty {
     url: Url,
+
+    /// Upstream server
+    #[doku(example = "https://api.example.com/v1")]
+    upstream: Url,
 }
 
 printer_test! {
     "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
 }
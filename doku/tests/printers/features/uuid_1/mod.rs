@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use uuid::Uuid;
+
+#[derive(Document)]
+struct Ty {
+    id: Uuid,
+
+    /// Tenant's identifier
+    #[doku(example = "67e55044-10b1-426f-9247-bb680e5fe0c8")]
+    tenant_id: Option<Uuid>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.with_hints.json" => to_json_fmt(Ty, {
+        "auto_comments": { "formats": true }
+    }),
+}
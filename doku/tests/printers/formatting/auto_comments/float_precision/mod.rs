@@ -0,0 +1,16 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Sampling ratio
+    ratio: f32,
+    threshold: f64,
+    opt: Option<f64>,
+}
+
+printer_test! {
+    "output.default.json" => to_json(Ty),
+    "output.enabled.json" => to_json_fmt(Ty, {
+        "auto_comments": { "float_precision": true }
+    }),
+}
@@ -0,0 +1,20 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Port to listen at
+    port: u16,
+    offset: i8,
+    max_connections: u32,
+    balance: i64,
+    id: u128,
+    delta: i128,
+    opt: Option<u8>,
+}
+
+printer_test! {
+    "output.default.json" => to_json(Ty),
+    "output.enabled.json" => to_json_fmt(Ty, {
+        "auto_comments": { "integer_range": true }
+    }),
+}
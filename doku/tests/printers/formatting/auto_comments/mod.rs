@@ -0,0 +1,2 @@
+mod float_precision;
+mod integer_range;
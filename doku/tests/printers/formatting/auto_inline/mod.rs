@@ -0,0 +1,51 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Shape {
+    origin: Point,
+
+    /// Shape's size
+    size: (u32, u32),
+
+    /// Rotation center
+    pivot: Option<Point>,
+
+    styles: Styles,
+
+    labeled: Labeled,
+}
+
+#[derive(Serialize, Document)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Serialize, Document)]
+struct Styles {
+    background_color: String,
+    border_color: String,
+}
+
+#[derive(Serialize, Document)]
+struct Labeled {
+    /// Label's text
+    text: String,
+}
+
+printer_test! {
+    "output.disabled.json" => to_json(Shape),
+
+    "output.under-30.json" => to_json_fmt(Shape, {
+        "auto_inline_under": 30,
+    }),
+
+    "output.under-30.commented-out.json" => to_json_fmt(Shape, {
+        "auto_inline_under": 30,
+        "optionals_style": "CommentedOut",
+    }),
+
+    "output.under-100.json" => to_json_fmt(Shape, {
+        "auto_inline_under": 100,
+    }),
+}
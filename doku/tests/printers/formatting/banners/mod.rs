@@ -0,0 +1,37 @@
+use crate::prelude::*;
+
+/// Application's configuration
+#[derive(Document)]
+struct Config {
+    /// Database's configuration
+    db: Database,
+}
+
+#[derive(Document)]
+#[doku(inline = false)]
+struct Database {
+    url: String,
+}
+
+printer_test! {
+    "output.json" => to_json_fmt(Config, {
+        "header": "DO NOT EDIT\nGenerated from {type}; see {unknown}",
+        "footer": "vim: ft=jsonc",
+    }),
+
+    "output.hash.json" => to_json_fmt(Config, {
+        "comments_style": {
+            "separator": "#",
+        },
+        "doc_header": "Visible",
+        "header": "Generated from {type}",
+    }),
+
+    "output.hidden.json" => to_json_fmt(Config, {
+        "comments_style": {
+            "hidden": true,
+        },
+        "header": "DO NOT EDIT",
+        "footer": "vim: ft=jsonc",
+    }),
+}
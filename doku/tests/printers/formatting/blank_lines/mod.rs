@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Config {
+    /// Address to listen at
+    addr: String,
+
+    port: u16,
+
+    /// Request timeout
+    timeout: Option<u32>,
+
+    /// Database's URL
+    #[doku(section = "Database")]
+    db_url: String,
+
+    #[doku(section = "Database")]
+    db_pool: usize,
+
+    /// Workers to spawn
+    workers: Vec<Worker>,
+}
+
+#[derive(Serialize, Document)]
+struct Worker {
+    name: String,
+
+    /// Whether the worker is enabled
+    enabled: bool,
+}
+
+printer_test! {
+    "output.never.json" => to_json_fmt(Config, {
+        "blank_lines": "Never",
+    }),
+
+    "output.before-commented-fields.json" => to_json_fmt(Config, {
+        "blank_lines": "BeforeCommentedFields",
+    }),
+
+    "output.between-fields.json" => to_json_fmt(Config, {
+        "blank_lines": "BetweenFields",
+    }),
+
+    "output.between-fields.commented-out.json" => to_json_fmt(Config, {
+        "blank_lines": "BetweenFields",
+        "optionals_style": "CommentedOut",
+    }),
+}
@@ -6,6 +6,32 @@ struct Person {
     name: String,
 }
 
+/// Server's configuration; can be overridden with environment variables
+/// prefixed with `APP_`.
+#[derive(Document)]
+struct Server {
+    /// Address to listen at; supports both IPv4 and IPv6 addresses, with an
+    /// optional port.
+    ///
+    /// Examples:
+    ///
+    /// - 127.0.0.1
+    /// - [::1]:8080, which listens on the loopback interface only and is thus
+    ///   not reachable from the outside
+    ///
+    /// ```
+    /// addr = "0.0.0.0:80"
+    /// ```
+    addr: String,
+
+    /// Steps performed during the startup:
+    /// 1. connecting to the database,
+    /// 2. running the migrations (unless disabled with `--no-migrations`).
+    ///
+    ///     $ server --no-migrations
+    steps: Vec<String>,
+}
+
 printer_test! {
     "output.default_separator_one_column.json" => to_json_fmt(Person, {
     }),
@@ -22,4 +48,38 @@ printer_test! {
         "comments_style": { "separator": "#" },
         "layout": { "TwoColumns": { "align": false, "spacing": 2 }}
    }),
+
+   "output.width.json" => to_json_fmt(Server, {
+        "comments_style": { "separator": "//", "width": 40 },
+        "doc_header": "Visible",
+   }),
+
+   "output.width_none.json" => to_json_fmt(Server, {
+        "doc_header": "Visible",
+   }),
+
+   "output.block.json" => to_json_fmt(Server, {
+        "comments_style": { "separator": "/*", "suffix": "*/" },
+        "doc_header": "Visible",
+   }),
+
+   "output.block_two_columns.json" => to_json_fmt(Person, {
+        "comments_style": { "separator": "/*", "suffix": "*/" },
+        "layout": { "TwoColumns": { "align": false, "spacing": 2 }}
+   }),
+
+   "output.hidden.json" => to_json_fmt(Server, {
+        "comments_style": { "hidden": true },
+        "doc_header": "Visible",
+   }),
+
+   "output.max_width_one_column.json" => to_json_fmt(Server, {
+        "comments_style": { "separator": "//", "max_width": 50 },
+        "doc_header": "Visible",
+   }),
+
+   "output.max_width_two_columns.json" => to_json_fmt(Server, {
+        "comments_style": { "separator": "//", "max_width": 60 },
+        "layout": { "TwoColumns": { "align": true, "spacing": 1 }}
+   }),
 }
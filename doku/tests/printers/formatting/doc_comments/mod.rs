@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Config {
+    /// Port to listen at
+    port: Option<u16>,
+
+    /// Logging level
+    #[doku(enum_values_comment)]
+    level: Level,
+}
+
+#[derive(Document)]
+enum Level {
+    /// Only errors
+    Error,
+
+    /// Everything
+    Debug,
+}
+
+printer_test! {
+    "output.commented.all.json" => to_json_fmt(Config, {
+        "enums_style": "Commented",
+    }),
+
+    "output.commented.doc_only.json" => to_json_fmt(Config, {
+        "auto_comments": { "enum_values": false, "optional": false },
+        "enums_style": "Commented",
+    }),
+
+    "output.commented.auto_only.json" => to_json_fmt(Config, {
+        "doc_comments": "Hidden",
+        "enums_style": "Commented",
+    }),
+
+    "output.separated.auto_only.json" => to_json_fmt(Config, {
+        "doc_comments": "Hidden",
+        "enums_style": "Separated",
+    }),
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+/// Application's configuration
+///
+/// Can be overridden with `APP_*` environment variables.
+#[derive(Document)]
+struct Config {
+    /// Address to listen on
+    addr: String,
+    db: Database,
+}
+
+/// Database's configuration (shouldn't appear in the header)
+#[derive(Document)]
+struct Database {
+    url: String,
+}
+
+printer_test! {
+    "output.hidden.json" => to_json(Config),
+
+    "output.visible_one_column.json" => to_json_fmt(Config, {
+        "doc_header": "Visible",
+    }),
+
+    "output.visible_two_columns.json" => to_json_fmt(Config, {
+        "doc_header": "Visible",
+        "comments_style": { "separator": "#" },
+        "layout": { "TwoColumns": { "align": true, "spacing": 1 }}
+    }),
+}
@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use std::time::{Duration, SystemTime};
+
+#[derive(Document)]
+struct Config {
+    /// How long to wait for the upstream
+    #[doku(duration)]
+    timeout: Duration,
+
+    #[doku(duration)]
+    interval: Option<Duration>,
+
+    #[doku(duration, fmt(durations_style = "humantime"))]
+    grace_period: Duration,
+
+    /// When the configuration has been generated
+    #[doku(timestamp)]
+    generated_at: SystemTime,
+}
+
+printer_test! {
+    "output.humantime.json" => to_json_fmt(Config, {
+        "durations_style": "Humantime",
+    }),
+
+    "output.seconds.json" => to_json_fmt(Config, {
+        "durations_style": "Seconds",
+    }),
+
+    "output.seconds.with_hints.json" => to_json_fmt(Config, {
+        "durations_style": "Seconds",
+        "auto_comments": { "formats": true },
+    }),
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Config {
+    /// Servers to listen at
+    servers: Vec<Server>,
+}
+
+#[derive(Document)]
+struct Server {
+    /// Address to listen at
+    addr: String,
+
+    /// Port to listen at
+    port: u16,
+}
+
+printer_test! {
+    "output.size.json" => to_json_fmt(Config, {
+        "indent_style": { "size": 4 },
+    }),
+
+    "output.tabs_one_column.json" => to_json_fmt(Config, {
+        "indent_style": { "size": 4, "use_tabs": true },
+    }),
+
+    "output.tabs_two_columns.json" => to_json_fmt(Config, {
+        "indent_style": { "size": 4, "use_tabs": true },
+        "layout": { "TwoColumns": { "align": true, "spacing": 1 }}
+    }),
+}
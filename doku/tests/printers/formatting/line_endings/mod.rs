@@ -0,0 +1,36 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Config {
+    /// Address to listen at
+    addr: String,
+
+    /// Workers to spawn
+    workers: Vec<Worker>,
+}
+
+#[derive(Document)]
+struct Worker {
+    name: String,
+}
+
+printer_test! {
+    "output.crlf.json" => to_json_fmt(Config, {
+        "line_endings": {
+            "newline": "CrLf",
+        },
+    }),
+
+    "output.final-newline.json" => to_json_fmt(Config, {
+        "line_endings": {
+            "final_newline": true,
+        },
+    }),
+
+    "output.crlf.final-newline.json" => to_json_fmt(Config, {
+        "line_endings": {
+            "newline": "CrLf",
+            "final_newline": true,
+        },
+    }),
+}
@@ -0,0 +1,53 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Document)]
+struct Config {
+    /// Database's configuration
+    db: Database,
+
+    /// Additional databases
+    replicas: Vec<Database>,
+
+    services: BTreeMap<String, Service>,
+
+    #[serde(flatten)]
+    server: Server,
+}
+
+#[derive(Serialize, Document)]
+struct Database {
+    url: String,
+    pool: Pool,
+}
+
+#[derive(Serialize, Document)]
+struct Pool {
+    size: usize,
+}
+
+#[derive(Serialize, Document)]
+struct Service {
+    enabled: bool,
+}
+
+#[derive(Serialize, Document)]
+struct Server {
+    port: u16,
+    tls: Tls,
+}
+
+#[derive(Serialize, Document)]
+struct Tls {
+    cert: String,
+}
+
+printer_test! {
+    "output.1.json" => to_json_fmt(Config, {
+        "max_depth": 1,
+    }),
+
+    "output.2.json" => to_json_fmt(Config, {
+        "max_depth": 2,
+    }),
+}
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Deserialize, Document)]
+struct Config {
+    /// Address to listen at
+    #[doku(env = "ADDR")]
+    addr: String,
+
+    /// Request timeout
+    #[doku(unit = "seconds")]
+    timeout: Option<f32>,
+
+    #[serde(default)]
+    workers: [u16; 1],
+
+    /// Name of the instance
+    #[doku(min_length = 1, max_length = 32)]
+    name: String,
+}
+
+printer_test! {
+    "output.json" => to_json(Config),
+
+    "output.pl.json" => to_json_fmt(Config, {
+        "auto_comments": {
+            "defaults": true,
+            "float_precision": true,
+            "integer_range": true,
+            "required": true,
+        },
+        "messages": {
+            "array_size": "musi zawierać dokładnie {count} {elements}",
+            "characters": "znaków",
+            "element": "element",
+            "env": "zmienna środowiskowa: {env}",
+            "float32": "liczba zmiennoprzecinkowa (32-bitowa)",
+            "has_default": "opcjonalne, ma wartość domyślną",
+            "length_between": "musi zawierać od {min} do {max} {noun}",
+            "optional": "opcjonalne",
+            "required": "wymagane",
+            "unit": "w {unit}",
+            "unsigned_integer": "{bits}-bitowa liczba całkowita bez znaku ({min}..={max})",
+        },
+    }),
+}
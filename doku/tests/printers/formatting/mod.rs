@@ -1 +1,19 @@
+mod auto_comments;
+mod auto_inline;
+mod banners;
+mod blank_lines;
 mod comments_style;
+mod doc_comments;
+mod doc_header;
+mod durations_style;
+mod indent_style;
+mod line_endings;
+mod max_depth;
+mod messages;
+mod objects_style;
+mod optionals_style;
+mod overrides;
+mod placeholders;
+mod presets;
+mod rename_all;
+mod sort_fields;
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Document)]
+struct Config {
+    servers: Vec<String>,
+
+    #[doku(example = "a", example = "b")]
+    pair: [String; 2],
+
+    services: BTreeMap<String, u16>,
+
+    #[serde(flatten)]
+    extra: BTreeMap<String, String>,
+}
+
+printer_test! {
+    "output.default.json" => to_json_fmt(Config, {
+    }),
+
+    "output.no_ellipsis.json" => to_json_fmt(Config, {
+        "objects_style": { "use_ellipsis": false },
+    }),
+
+    "output.no_trailing_comma.json" => to_json_fmt(Config, {
+        "objects_style": { "use_trailing_comma": false },
+    }),
+
+    "output.no_ellipsis_no_trailing_comma.json" => to_json_fmt(Config, {
+        "objects_style": { "use_ellipsis": false, "use_trailing_comma": false },
+    }),
+}
@@ -0,0 +1,74 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Config {
+    /// Proxy to connect through
+    proxy: Option<String>,
+
+    /// Port to listen at
+    port: u16,
+
+    /// Request timeout, in seconds
+    #[serde(default = "default_timeout")]
+    timeout: u32,
+
+    /// Database to use
+    #[doku(section = "Database")]
+    db: Option<Database>,
+
+    /// Number of workers
+    workers: Option<usize>,
+
+    /// Fallback addresses
+    fallbacks: Vec<Option<String>>,
+
+    /// Log level
+    #[doku(fmt(optionals_style = "commented-out"))]
+    log_level: Option<String>,
+}
+
+#[derive(Serialize, Document)]
+struct Database {
+    /// Connection string
+    url: String,
+
+    /// Size of the connection pool
+    pool_size: Option<usize>,
+}
+
+fn default_timeout() -> u32 {
+    30
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            proxy: None,
+            port: 80,
+            timeout: default_timeout(),
+            db: Some(Database {
+                url: "postgres://localhost".into(),
+                pool_size: None,
+            }),
+            workers: None,
+            fallbacks: Default::default(),
+            log_level: Some("info".into()),
+        }
+    }
+}
+
+printer_test! {
+    "output.value.json" => to_json(Config),
+
+    "output.null.json" => to_json_fmt(Config, {
+        "optionals_style": "Null",
+    }),
+
+    "output.commented-out.json" => to_json_fmt(Config, {
+        "optionals_style": "CommentedOut",
+    }),
+
+    "output.commented-out.with_values.json" => to_json_fmt_val(Config, {
+        "optionals_style": "CommentedOut",
+    }),
+}
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Config {
+    /// Server's configuration
+    server: Server,
+
+    /// Additional servers
+    replicas: Vec<Server>,
+}
+
+#[derive(Document)]
+struct Server {
+    /// Port to listen at
+    port: u16,
+
+    /// Proxy to connect through
+    proxy: Option<String>,
+
+    /// TLS' configuration
+    tls: Tls,
+}
+
+#[derive(Document)]
+struct Tls {
+    /// Path to the certificate
+    cert: String,
+
+    mode: TlsMode,
+}
+
+#[derive(Document)]
+enum TlsMode {
+    Strict,
+    Permissive,
+}
+
+printer_test! {
+    "output.field.json" => to_json_fmt(Config, {
+        "overrides": [
+            ["server.tls", { "doc_comments": "Hidden" }],
+        ],
+    }),
+
+    "output.wildcard.json" => to_json_fmt(Config, {
+        "overrides": [
+            ["*.proxy", { "optionals_style": "Null" }],
+            ["**.mode", { "enums_style": "Commented" }],
+        ],
+    }),
+
+    "output.last-wins.json" => to_json_fmt(Config, {
+        "overrides": [
+            ["server.**", { "doc_comments": "Hidden" }],
+            ["server.tls", { "sort_fields": "Alphabetical" }],
+        ],
+    }),
+}
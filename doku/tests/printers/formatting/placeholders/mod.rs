@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+
+#[derive(Document)]
+struct Config {
+    hostname: String,
+    port: u16,
+    ratio: f32,
+    verbose: bool,
+    addr: SocketAddr,
+    allowed_ips: Vec<IpAddr>,
+    log_path: Option<PathBuf>,
+    limits: BTreeMap<String, usize>,
+
+    #[doku(example = "admin")]
+    user: String,
+}
+
+printer_test! {
+    "output.examples.json" => to_json_fmt(Config, {
+        "placeholders": "Examples",
+    }),
+
+    "output.type-names.json" => to_json_fmt(Config, {
+        "placeholders": "TypeNames",
+    }),
+
+    "output.field-names.json" => to_json_fmt(Config, {
+        "placeholders": "FieldNames",
+    }),
+}
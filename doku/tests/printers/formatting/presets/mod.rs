@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+/// Application's configuration
+#[derive(Serialize, Deserialize, Document)]
+struct Config {
+    /// Address to listen at
+    addr: String,
+
+    /// Request timeout
+    #[doku(unit = "seconds")]
+    timeout: Option<f32>,
+
+    /// Workers to spawn
+    workers: Vec<Worker>,
+
+    /// Additional labels
+    labels: BTreeMap<String, String>,
+
+    mode: Mode,
+}
+
+#[derive(Serialize, Deserialize, Document)]
+struct Worker {
+    /// Worker's name
+    name: String,
+
+    #[serde(default)]
+    enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Document)]
+#[serde(rename_all = "lowercase")]
+enum Mode {
+    Fast,
+    Safe,
+}
+
+printer_test! {
+    "output.compact.json" => to_json_preset(Config, compact),
+    "output.verbose.json" => to_json_preset(Config, verbose),
+    "output.strict-json.json" => to_json_preset(Config, strict_json),
+}
@@ -0,0 +1,52 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Document)]
+#[serde(rename_all = "camelCase")]
+struct Config {
+    /// Address to listen at
+    listen_addr: String,
+
+    #[serde(flatten)]
+    tls_config: TlsConfig,
+
+    upstream_servers: Vec<UpstreamServer>,
+
+    extra_headers: BTreeMap<String, String>,
+
+    load_balancing: LoadBalancing,
+}
+
+#[derive(Serialize, Document)]
+struct TlsConfig {
+    cert_path: String,
+}
+
+#[derive(Serialize, Document)]
+struct UpstreamServer {
+    #[serde(rename = "HTTPPort")]
+    http_port: u16,
+}
+
+#[derive(Serialize, Document)]
+#[serde(tag = "strategyKind")]
+enum LoadBalancing {
+    RoundRobin,
+    SlowStart { warm_up_secs: u32 },
+}
+
+printer_test! {
+    "output.json" => to_json(Config),
+
+    "output.snake_case.json" => to_json_fmt(Config, {
+        "rename_all": "SnakeCase",
+    }),
+
+    "output.kebab-case.json" => to_json_fmt(Config, {
+        "rename_all": "kebab-case",
+    }),
+
+    "output.SCREAMING_SNAKE_CASE.json" => to_json_fmt(Config, {
+        "rename_all": "SCREAMING_SNAKE_CASE",
+    }),
+}
@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Document)]
+struct Config {
+    port: u16,
+
+    #[doku(section = "Database")]
+    db_user: String,
+
+    #[doku(section = "Database")]
+    db_password: String,
+
+    address: String,
+
+    services: BTreeMap<ServiceName, Service>,
+}
+
+struct ServiceName;
+
+impl Document for ServiceName {
+    fn ty() -> doku::Type {
+        doku::Type {
+            example: Some(doku::Example::Compound(&[
+                "redis", "postgres", "http",
+            ])),
+            ..doku::Type::from(doku::TypeKind::String)
+        }
+    }
+}
+
+#[derive(Document)]
+struct Service {
+    url: String,
+    enabled: bool,
+}
+
+printer_test! {
+    "output.declaration.json" => to_json_fmt(Config, {
+        "maps_style": { "entries": 3 },
+    }),
+
+    "output.alphabetical.json" => to_json_fmt(Config, {
+        "maps_style": { "entries": 3 },
+        "sort_fields": "Alphabetical",
+    }),
+}
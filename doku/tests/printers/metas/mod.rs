@@ -1,3 +1,4 @@
 mod fmt;
 mod fmt_enums_style;
 mod multiple;
+mod structured;
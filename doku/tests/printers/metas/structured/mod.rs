@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    #[doku(meta(key = "fmt.doc_comments", value = r#""Hidden""#))]
+    #[doku(meta(key = "fmt.auto_comments.optional", value = false))]
+    #[doku(meta(key = "x-team", value = "infra"))]
+    foo: Foo,
+}
+
+#[derive(Document)]
+struct Foo {
+    /// This is `Bar`
+    bar: Option<String>,
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Document)]
+struct Ty {
+    /// Server's configuration
+    server: Option<Server>,
+
+    upstreams: BTreeMap<String, Upstream>,
+}
+
+/// Accepts HTTP connections
+#[derive(Document)]
+struct Server {
+    /// Port to listen at
+    #[doku(example = "8080")]
+    port: u16,
+
+    tls: Tls,
+}
+
+#[derive(Document)]
+struct Tls {
+    /// Path to the certificate
+    cert: String,
+}
+
+/// Where requests get forwarded to
+#[derive(Document)]
+enum Upstream {
+    /// Forwards over HTTP
+    Http { url: String },
+
+    /// Forwards over a Unix socket
+    Unix { path: String },
+}
+
+printer_test! {
+    "output.server.json" => to_json_at(Ty, "server"),
+    "output.server.port.json" => to_json_at(Ty, "server.port"),
+    "output.server.tls.cert.json" => to_json_at(Ty, "server.tls.cert"),
+    "output.upstreams.json" => to_json_at(Ty, "upstreams"),
+    "output.upstreams.foo.json" => to_json_at(Ty, "upstreams.foo"),
+}
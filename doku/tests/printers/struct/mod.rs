@@ -1,11 +1,21 @@
+mod at_path;
 mod of_fields;
+mod of_newtype;
 mod of_transparent;
+mod recursive;
+mod with_builder;
 mod with_comments;
+mod with_diff;
 mod with_examples;
+mod with_flattened_enum;
 mod with_flattened_field;
 mod with_flattened_transparent_field;
 mod with_generics;
+mod with_generics_and_associated_type;
 mod with_generics_and_trait;
 mod with_literal_examples;
 mod with_multiline_comment;
 mod with_optional_field;
+mod with_partial_value;
+mod with_transform;
+mod with_unnamed_comments;
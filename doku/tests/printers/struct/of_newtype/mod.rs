@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Plain newtype
+    f1: Port,
+
+    /// Transparent newtype
+    f2: Host,
+
+    /// Opaque newtype
+    f3: Opaque,
+}
+
+#[derive(Document)]
+struct Port(#[doku(example = "8080")] u16);
+
+#[derive(Serialize, Document)]
+#[serde(transparent)]
+struct Host(#[doku(example = "localhost")] String);
+
+#[derive(Document)]
+#[doku(opaque)]
+struct Opaque(u16);
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    /// Routing table
+    routes: Route,
+
+    /// Filter applied to each request
+    filter: Option<Filter>,
+}
+
+#[derive(Serialize, Document)]
+struct Route {
+    path: String,
+
+    /// Nested routes
+    children: Vec<Route>,
+}
+
+#[derive(Serialize, Document)]
+#[serde(rename_all = "snake_case")]
+enum Filter {
+    Path(String),
+    Not(Box<Filter>),
+    All(Vec<Filter>),
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+    "output.root.json" => to_json(Route),
+}
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+use doku::{Constraint, Type, Variant};
+
+struct Ty;
+
+impl Document for Ty {
+    fn ty() -> Type {
+        Type::struct_()
+            .field(
+                "addr",
+                Type::socket_addr()
+                    .comment("Address to listen at")
+                    .env("APP_ADDR")
+                    .example("127.0.0.1:8080"),
+            )
+            .field(
+                "workers",
+                Type::integer()
+                    .constraint(Constraint::Range {
+                        min: Some("1"),
+                        max: None,
+                    })
+                    .defaulted(),
+            )
+            .field(
+                "upstreams",
+                Type::array(Type::url().example("https://example.com")),
+            )
+            .field(
+                "labels",
+                Type::map(Type::string(), Type::string()).since("1.2"),
+            )
+            .field(
+                "mode",
+                Type::enum_()
+                    .variant(Variant::new("fast").comment("Go fast"))
+                    .variant(Variant::new("slow")),
+            )
+            .flattened_field(
+                "extra",
+                Type::struct_()
+                    .field("debug", Type::bool().hidden())
+                    .field("token", Type::string().sensitive()),
+            )
+    }
+}
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
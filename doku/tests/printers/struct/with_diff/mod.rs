@@ -0,0 +1,95 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+#[derive(Document)]
+struct Ty {
+    /// Address to listen at
+    addr: String,
+
+    port: u16,
+
+    timeout: Option<u32>,
+
+    db: Db,
+
+    tls: Option<Tls>,
+
+    user: User,
+
+    workers: Vec<String>,
+
+    labels: BTreeMap<String, String>,
+}
+
+#[derive(Document)]
+struct Db {
+    url: String,
+    pool: usize,
+}
+
+#[derive(Document)]
+struct Tls {
+    cert: String,
+    key: String,
+}
+
+#[derive(Document)]
+struct User(String);
+
+#[derive(Document)]
+struct Flattened {
+    name: String,
+
+    #[doku(flatten)]
+    db: Db,
+}
+
+printer_test! {
+    "output.same.json" => diff(Ty, {
+        "addr": "localhost",
+        "port": 80,
+        "db": { "url": "postgres://localhost", "pool": 4 },
+        "user": ["root"],
+        "workers": ["a"],
+        "labels": { "env": "dev" },
+    }, {
+        "addr": "localhost",
+        "port": 80,
+        "db": { "url": "postgres://localhost", "pool": 4 },
+        "user": ["root"],
+        "workers": ["a"],
+        "labels": { "env": "dev" },
+    }),
+
+    "output.changed.json" => diff(Ty, {
+        "addr": "localhost",
+        "port": 80,
+        "timeout": 30,
+        "db": { "url": "postgres://localhost", "pool": 4 },
+        "user": ["root"],
+        "workers": ["a"],
+        "labels": { "env": "dev" },
+        "legacy": true,
+    }, {
+        "addr": "0.0.0.0",
+        "port": 80,
+        "db": { "url": "postgres://localhost", "pool": 8 },
+        "tls": { "cert": "cert.pem", "key": "key.pem" },
+        "user": ["admin"],
+        "workers": ["a", "b"],
+        "labels": { "env": "prod" },
+        "legacy": true,
+        "extra": 1,
+    }),
+
+    "output.flattened.json" => diff(Flattened, {
+        "name": "a",
+        "url": "postgres://localhost",
+        "pool": 4,
+    }, {
+        "name": "b",
+        "url": "postgres://localhost",
+        "pool": 8,
+        "extra": 1,
+    }),
+}
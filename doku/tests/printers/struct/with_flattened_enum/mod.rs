@@ -0,0 +1,40 @@
+use crate::prelude::*;
+
+#[derive(Serialize, Document)]
+struct Ty {
+    name: String,
+
+    #[serde(flatten)]
+    storage: Storage,
+
+    port: u16,
+}
+
+#[derive(Serialize, Document)]
+#[serde(tag = "kind")]
+enum Storage {
+    /// In-memory storage
+    Memory,
+
+    /// PostgreSQL storage
+    Postgres {
+        /// Connection string
+        url: String,
+    },
+
+    File(FileStorage),
+}
+
+#[derive(Serialize, Document)]
+struct FileStorage {
+    path: String,
+}
+
+printer_test! {
+    "output.commented.json" => to_json_fmt(Ty, {
+        "enums_style": "Commented",
+    }),
+    "output.separated.json" => to_json_fmt(Ty, {
+        "enums_style": "Separated",
+    }),
+}
@@ -0,0 +1,42 @@
+use crate::prelude::*;
+
+trait Backend {
+    type Options;
+    type Credentials;
+}
+
+struct Postgres;
+
+impl Backend for Postgres {
+    type Options = PostgresOptions;
+    type Credentials = PostgresCredentials;
+}
+
+#[derive(Document)]
+struct PostgresOptions {
+    url: String,
+}
+
+#[derive(Document)]
+struct PostgresCredentials {
+    user: String,
+}
+
+// `Postgres` doesn't implement `Document`, but it doesn't have to - the fields
+// refer only to its associated types
+#[derive(Document)]
+struct Ty<T: Backend> {
+    opts: T::Options,
+    credentials: Option<<T as Backend>::Credentials>,
+}
+
+#[derive(Document)]
+enum Replica<T: Backend> {
+    Primary(T::Options),
+    Replica { opts: T::Options },
+}
+
+printer_test! {
+    "output.struct.json" => to_json(Ty<Postgres>),
+    "output.enum.json" => to_json(Replica<Postgres>),
+}
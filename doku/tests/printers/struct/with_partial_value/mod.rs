@@ -0,0 +1,52 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Address to listen at
+    #[doku(example = "127.0.0.1")]
+    addr: String,
+
+    /// Port to listen at
+    port: u16,
+
+    /// Request timeout
+    timeout: Option<u32>,
+
+    db: Db,
+
+    #[doku(flatten)]
+    tls: Tls,
+}
+
+#[derive(Document)]
+struct Db {
+    /// Database's URL
+    url: String,
+
+    pool: usize,
+}
+
+#[derive(Document)]
+struct Tls {
+    tls_cert: String,
+    tls_key: String,
+}
+
+printer_test! {
+    "output.empty.json" => to_json_partial_val(Ty, {}),
+
+    "output.partial.json" => to_json_partial_val(Ty, {
+        "port": 8080,
+        "timeout": null,
+        "db": {
+            "pool": 4,
+        },
+        "tls_key": "key.pem",
+    }),
+
+    "output.partial.commented-out.json" => to_json_fmt_partial_val(Ty, {
+        "optionals_style": "CommentedOut",
+    }, {
+        "port": 8080,
+    }),
+}
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+use doku::{Type, TypeKind};
+
+#[derive(Document)]
+struct Ty {
+    /// Address to listen at
+    addr: String,
+
+    tenant: Tenant,
+
+    workers: Vec<Worker>,
+
+    storage: Storage,
+}
+
+#[derive(Document)]
+struct Tenant {
+    id: String,
+}
+
+#[derive(Document)]
+struct Worker {
+    name: String,
+    tenant_id: Option<String>,
+}
+
+#[derive(Document)]
+enum Storage {
+    Disk { path: String, tenant_id: String },
+}
+
+fn drop_tenants(ty: &mut Type) {
+    if let Some(fields) = ty.named_fields_mut() {
+        fields.retain(|(name, _)| !name.starts_with("tenant"));
+    }
+
+    if let TypeKind::Enum { variants, .. } = &mut ty.kind {
+        for variant in variants {
+            if let doku::Fields::Named { fields } = &mut variant.fields {
+                fields.retain(|(name, _)| !name.starts_with("tenant"));
+            }
+        }
+    }
+}
+
+fn rename_addr(ty: &mut Type) {
+    if let Some(fields) = ty.named_fields_mut() {
+        for (name, _) in fields {
+            if *name == "addr" {
+                *name = "address";
+            }
+        }
+    }
+}
+
+fn inject_examples(ty: &mut Type) {
+    if let TypeKind::String = ty.kind {
+        ty.example = Some("example".into());
+    }
+}
+
+printer_test! {
+    "output.identity.json" => to_json_with(Ty, |_| ()),
+    "output.drop.json" => to_json_with(Ty, drop_tenants),
+    "output.rename.json" => to_json_with(Ty, rename_addr),
+    "output.examples.json" => to_json_with(Ty, inject_examples),
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+
+#[derive(Document)]
+struct Ty {
+    /// Listen address
+    addr: Addr,
+}
+
+#[derive(Document)]
+struct Addr(
+    /// Host name
+    String,
+    /// Port number
+    u16,
+);
+
+printer_test! {
+    "output.json" => to_json(Ty),
+}
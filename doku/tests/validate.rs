@@ -0,0 +1,152 @@
+#![allow(dead_code)]
+
+use doku::Document;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use test_case::test_case;
+
+#[derive(Clone, Serialize, Document)]
+struct Config {
+    /// Port to listen at
+    #[doku(min = 1024, max = 65535)]
+    port: u16,
+
+    #[doku(min = "0.5")]
+    ratio: f32,
+
+    #[doku(multiple_of = 4)]
+    batch: usize,
+
+    #[doku(multiple_of = 0.1)]
+    step: f64,
+
+    #[doku(multiple_of = 0.1)]
+    step_f32: f32,
+
+    #[doku(min_length = 1, max_length = 8)]
+    name: String,
+
+    #[doku(pattern = "^[a-z]+$")]
+    slug: String,
+
+    #[doku(one_of = "debug", one_of = "info")]
+    level: String,
+
+    #[doku(min_length = 1)]
+    workers: Vec<Worker>,
+
+    timeout: Option<Timeout>,
+
+    user: User,
+
+    labels: BTreeMap<String, Label>,
+
+    #[serde(flatten)]
+    db: Db,
+}
+
+#[derive(Clone, Serialize, Document)]
+struct Worker {
+    #[doku(max = 8)]
+    threads: u8,
+}
+
+#[derive(Clone, Serialize, Document)]
+struct Timeout(#[doku(max = 60)] u32);
+
+#[derive(Clone, Serialize, Document)]
+struct User(#[doku(min_length = 3)] String);
+
+#[derive(Clone, Serialize, Document)]
+struct Label(#[doku(max_length = 4)] String);
+
+#[derive(Clone, Serialize, Document)]
+struct Db {
+    /// Database's pool size
+    #[doku(min = 1)]
+    db_pool: usize,
+}
+
+fn valid() -> Config {
+    Config {
+        port: 8080,
+        ratio: 0.5,
+        batch: 16,
+        step: 0.3,
+        step_f32: 0.3,
+        name: "server".into(),
+        slug: "server".into(),
+        level: "info".into(),
+        workers: vec![Worker { threads: 4 }],
+        timeout: Some(Timeout(30)),
+        user: User("root".into()),
+        labels: vec![("env".to_string(), Label("prod".into()))]
+            .into_iter()
+            .collect(),
+        db: Db { db_pool: 4 },
+    }
+}
+
+fn target(config: Config) -> Vec<String> {
+    doku::validate(&config)
+        .into_iter()
+        .map(|violation| violation.to_string())
+        .collect()
+}
+
+#[test]
+fn valid_config() {
+    assert_eq!(Vec::<String>::new(), target(valid()));
+}
+
+#[test_case(|c| c.port = 80 => vec!["port: must be between 1024 and 65535"] ; "range, integer")]
+#[test_case(|c| c.ratio = 0.25 => vec!["ratio: must be at least 0.5"] ; "range, float")]
+#[test_case(|c| c.batch = 6 => vec!["batch: must be a multiple of 4"] ; "multiple of")]
+#[test_case(|c| c.step = 0.7 => Vec::<String>::new() ; "multiple of, float")]
+#[test_case(|c| c.step = 0.35 => vec!["step: must be a multiple of 0.1"] ; "multiple of, float, invalid")]
+#[test_case(|c| c.step_f32 = 1.9 => Vec::<String>::new() ; "multiple of, f32")]
+#[test_case(|c| c.name = "".into() => vec!["name: length must be between 1 and 8"] ; "length, too short")]
+#[test_case(|c| c.name = "ąęółźżćń".into() => Vec::<String>::new() ; "length, counts characters")]
+#[test_case(|c| c.name = "server-01".into() => vec!["name: length must be between 1 and 8"] ; "length, too long")]
+#[test_case(|c| c.level = "trace".into() => vec!["level: must be one of: debug, info"] ; "one of")]
+#[test_case(|c| c.workers = vec![] => vec!["workers: length must be at least 1"] ; "array, length")]
+#[test_case(|c| c.workers.push(Worker { threads: 16 }) => vec!["workers[1].threads: must be at most 8"] ; "array, items")]
+#[test_case(|c| c.timeout = Some(Timeout(90)) => vec!["timeout: must be at most 60"] ; "optional newtype")]
+#[test_case(|c| c.timeout = None => Vec::<String>::new() ; "optional, none")]
+#[test_case(|c| c.user = User("me".into()) => vec!["user: length must be at least 3"] ; "newtype")]
+#[test_case(|c| { c.labels.insert("team".into(), Label("backend".into())); } => vec!["labels.team: length must be at most 4"] ; "map")]
+#[test_case(|c| c.db.db_pool = 0 => vec!["db_pool: must be at least 1"] ; "flattened")]
+fn violations(f: fn(&mut Config)) -> Vec<String> {
+    let mut config = valid();
+
+    f(&mut config);
+    target(config)
+}
+
+#[cfg(feature = "regex-1")]
+#[test]
+fn pattern() {
+    let mut config = valid();
+
+    config.slug = "Server".into();
+
+    assert_eq!(vec!["slug: must match pattern: ^[a-z]+$"], target(config));
+}
+
+#[test]
+fn comments() {
+    let mut config = valid();
+
+    config.port = 1;
+    config.db.db_pool = 0;
+
+    let comments: Vec<_> = doku::validate(&config)
+        .into_iter()
+        .map(|violation| violation.comment)
+        .collect();
+
+    assert_eq!(
+        vec![Some("Port to listen at"), Some("Database's pool size")],
+        comments
+    );
+}